@@ -0,0 +1,8 @@
+// The reference solutions of "src/exercises.rs" (see "Exercises" in the README).
+
+use std::future::Future;
+
+pub async fn full_name(first: impl Future<Output = String>, last: impl Future<Output = String>) -> String {
+    let (first, last) = tokio::join!(first, last);
+    format!("{} {}", first, last)
+}
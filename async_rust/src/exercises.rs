@@ -0,0 +1,12 @@
+// The exercises of "async_rust", checked with "cargo run -p main -- check async_rust::full_name" (see "Exercises" in the README).
+
+#![allow(unused_variables)]
+
+use std::future::Future;
+
+// Wait for both futures and return the first and last name separated by a space.
+// The two futures must run at the same time, not one after the other, so awaiting them one by one is not enough.
+// Hint: "tokio::join!()" waits for several futures at once.
+pub async fn full_name(first: impl Future<Output = String>, last: impl Future<Output = String>) -> String {
+    todo!()
+}
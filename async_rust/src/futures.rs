@@ -2,6 +2,7 @@
 // Rust has a twist in it, such that it provides built-in basics to write async programming, but it needs what is called a "Runtime" provided by 3rd-parties to utilize the full experience.
 // There are many types of runtimes.
 
+use std::io::{self, Write};
 use std::time::Duration;
 
 // These imports need to be used.
@@ -17,12 +18,13 @@ async fn get_name() -> String {
 }
 
 
-pub fn futures_testing() {
+pub fn futures_testing_to(out: &mut dyn Write) -> io::Result<()> {
     // The following function call will not return an error; however, the printing will.
     // This is because any function called that is "async" needs to be awaited.
     let _name = get_name();
+    // compile_fail: E0277
     /*
-    println!("Hello, {}" , _name);
+    writeln!(out, "Hello, {}" , _name)?;
     */
     
     // To solve this, the "futures" crate needs to be installed. This is where the first dependency of the course will be installed.
@@ -33,36 +35,53 @@ pub fn futures_testing() {
     // The "block_on()" function will await, and unwrap the data coming to reveal the return type of the function called: "String" in this case.
     // To use the "block_on()", the import above at the beginning of the file needs to be done.
     let name_2 = block_on(get_name());
-    println!("Hello, {}" , name_2);
+    writeln!(out, "Hello, {}" , name_2)?;
+
+    Ok(())
+}
+
+pub fn futures_testing() {
+    futures_testing_to(&mut io::stdout()).expect("failed to write to stdout");
 }
 
 // Since Async returns a "Future", then the function can return a "Future".
 // async functions do not need to use "async fn".
 // This also means that the "async" can be removed from the function.
-fn get_name_future() -> impl Future<Output = String>{
+// Writing to "out" can fail, which is why the "Future" is wrapped in an "io::Result".
+// "use<>" says that the "Future" does not borrow "out", so the caller can keep writing to "out" until the "Future" is awaited.
+fn get_name_future(out: &mut dyn Write) -> io::Result<impl Future<Output = String> + use<>> {
     // Async code inside an async block does not run right away.
     // It is run when the function is awaited (the ".await" is called).
     // Any code before or after the async block is actually run immediately when the function is called, not when the function is awaited.
-    println!("print: before async creation");
+    writeln!(out, "print: before async creation")?;
 
     // An async block can be used.
     let async_block = async {
-        println!("print: before async execution");
         sleep(Duration::from_secs(1)).await;
-        println!("print: after async execution");
         "1 second passed".to_string()
     };
 
-    println!("print: After async creation");
+    writeln!(out, "print: After async creation")?;
 
     // The async block is called.
-    async_block
+    Ok(async_block)
 }
 
-pub async fn futures_future_testing(){
+pub async fn futures_future_testing_to(out: &mut dyn Write) -> io::Result<()> {
+    // Both lines of "get_name_future()" are written by this call, before anything inside the async block has run.
+    let text_future = get_name_future(out)?;
+
     // await needs to be called here and then the "String" will be unwrapped from the "Future".
     // The call changes from "block_on()" to ".await".
-    let text_future = get_name_future().await;
-    println!("{}" , text_future)
+    // The async block only runs now, and waits for its 1 second before the "String" is returned.
+    writeln!(out, "print: before async execution")?;
+    let text = text_future.await;
+    writeln!(out, "print: after async execution")?;
+    writeln!(out, "{}" , text)?;
+
+    Ok(())
+}
 
-}
\ No newline at end of file
+pub async fn futures_future_testing() {
+    futures_future_testing_to(&mut io::stdout()).await.expect("failed to write to stdout");
+}
@@ -1,2 +1,6 @@
 pub mod futures;
-pub mod tokio;
\ No newline at end of file
+pub mod tokio;
+
+// The exercises for this lesson (see "Exercises" in the README).
+#[cfg_attr(feature = "solution", path = "../solutions/exercises.rs")]
+pub mod exercises;
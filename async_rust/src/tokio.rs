@@ -1,3 +1,5 @@
+use std::io::{self, Write};
+
 // These imports need to be used.
 // They will be discussed below.
 use tokio::runtime::Runtime;
@@ -23,17 +25,27 @@ impl TokioLib {
         }
     }
 
-    pub fn run_async_task(&self) {
+    pub fn run_async_task_to(&self, out: &mut dyn Write) -> io::Result<()> {
         self.rt.block_on(async {
             // This async block runs inside Tokio, isolated from the rest of the app.
             // A ".await" can be called here to await the sleep till it's done.
             sleep(Duration::from_secs(1)).await;
             let call_1 = "Khan".to_string();
-            println!("Processed: {}", call_1);
+            writeln!(out, "Processed: {}", call_1)?;
 
             sleep(Duration::from_secs(1)).await;
             let call_2 = "Khalily".to_string();
-            println!("Processed: {}", call_2)
+            writeln!(out, "Processed: {}", call_2)
         })
     }
+
+    pub fn run_async_task(&self) {
+        self.run_async_task_to(&mut io::stdout()).expect("failed to write to stdout");
+    }
+
+    // Any "Future" can also be handed to the runtime, which will block until the future is done and return its output.
+    // This is how async functions that rely on tokio (such as "futures_future_testing" with its tokio "sleep") can be run from a regular function.
+    pub fn run_future<F: Future>(&self, future: F) -> F::Output {
+        self.rt.block_on(future)
+    }
 }
\ No newline at end of file
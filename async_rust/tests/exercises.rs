@@ -0,0 +1,36 @@
+// The tests of the exercises in "src/exercises.rs" (see "Exercises" in the README).
+
+mod full_name {
+    use std::time::Duration;
+
+    use async_rust::exercises::full_name;
+    use tokio::sync::oneshot;
+    use tokio::time::timeout;
+
+    #[tokio::test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    async fn joins_the_names() {
+        let name = full_name(async { "Khan".to_string() }, async { "Khalili".to_string() }).await;
+        assert_eq!(name, "Khan Khalili");
+    }
+
+    // The first name only arrives after the last name has been asked for, so awaiting the futures one by one never finishes.
+    #[tokio::test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    async fn waits_for_both_names_at_the_same_time() {
+        let (sender, receiver) = oneshot::channel();
+        let first = async move {
+            receiver.await.unwrap();
+            "Khan".to_string()
+        };
+        let last = async move {
+            sender.send(()).unwrap();
+            "Khalili".to_string()
+        };
+
+        let name = timeout(Duration::from_secs(5), full_name(first, last))
+            .await
+            .expect("the futures were awaited one after the other");
+        assert_eq!(name, "Khan Khalili");
+    }
+}
@@ -0,0 +1,416 @@
+// Integers that can only hold the values of a range, and decide what happens when a calculation goes outside of it.
+// A plain "u8" silently wraps around in release builds ("255 + 1" is 0), and only panics in debug builds.
+// An age is better written as:
+//      type Age = Bounded<u8, 0, 150>;
+//      let age = Age::new(20);
+//      let older = age + 10;           // 30
+//      let too_old = age + 200;        // panics, in debug and release builds alike
+// The last parameter is the policy, which decides what happens when a result is outside of the range:
+//      Panic       panics (the default).
+//      Wrap        wraps around to the other end of the range, so "Bounded<u8, 1, 12, Wrap>" counts months: 12 + 1 is 1.
+//      Saturate    stops at the end of the range: "Bounded<u8, 0, 100, Saturate>" holds a percentage, and 90 + 20 is 100.
+//      Checked     returns a "Result", so every operation has to handle the error.
+// "Saturating<T>" saturates at the limits of the type itself, such as 0 and 255 for "u8".
+// The lessons use these types for the ages of "structs", "pointers" and "optionals".
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
+
+// The integer types that can be bounded.
+// Every calculation is done in "i128", which holds the exact sum, difference and quotient of two of these types.
+// Only a product of two large "u64" (or "usize") does not fit, and is then kept as its two factors (see "Exact"), so nothing overflows before the policy decides.
+// "i128" and "u128" themselves are left out, since their sums would not fit either.
+pub trait Integer: Copy + Ord + fmt::Debug + fmt::Display {
+    const LOWEST: i128;
+    const HIGHEST: i128;
+
+    fn to_i128(self) -> i128;
+
+    // Only called with a value between "LOWEST" and "HIGHEST".
+    fn from_i128(value: i128) -> Self;
+}
+
+macro_rules! integer {
+    ($($ty:ty),*) => {
+        $(
+            impl Integer for $ty {
+                const LOWEST: i128 = <$ty>::MIN as i128;
+                const HIGHEST: i128 = <$ty>::MAX as i128;
+
+                fn to_i128(self) -> i128 {
+                    self as i128
+                }
+
+                fn from_i128(value: i128) -> Self {
+                    value as $ty
+                }
+            }
+        )*
+    };
+}
+
+integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    // The exact result of an operation, and the range it is outside of.
+    OutOfRange { value: i128, min: i128, max: i128 },
+    // The exact result does not even fit in "i128", such as "u64::MAX * u64::MAX", so only its sign is known.
+    Overflow { negative: bool, min: i128, max: i128 },
+    DivisionByZero,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::OutOfRange { value, min, max } => write!(f, "{} is out of the range {}..={}", value, min, max),
+            Error::Overflow { negative, min, max } => {
+                let side = if *negative { "below" } else { "above" };
+                write!(f, "the result does not fit in i128, and is {} the range {}..={}", side, min, max)
+            }
+            Error::DivisionByZero => write!(f, "attempt to divide by zero"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+// What happens to a result that is outside of the range.
+// "Output<V>" is what an operation returns: the value itself, or a "Result" for "Checked".
+pub trait Policy {
+    type Output<V>;
+
+    // Brings the exact result of an operation into "min..=max", or fails.
+    fn resolve(exact: Exact, min: i128, max: i128) -> Result<i128, Error>;
+
+    fn output<V>(result: Result<V, Error>) -> Self::Output<V>;
+}
+
+fn out_of_range(value: i128, min: i128, max: i128) -> Result<i128, Error> {
+    if (min..=max).contains(&value) {
+        Ok(value)
+    } else {
+        Err(Error::OutOfRange { value, min, max })
+    }
+}
+
+// The exact result of an operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Exact {
+    Value(i128),
+    // A product that is too big for "i128", kept as its two factors.
+    Product(i128, i128),
+}
+
+impl Exact {
+    fn is_negative(self) -> bool {
+        match self {
+            Exact::Value(value) => value < 0,
+            Exact::Product(lhs, rhs) => (lhs < 0) != (rhs < 0),
+        }
+    }
+
+    // The result itself if it is inside of "min..=max", and an error otherwise.
+    fn in_range(self, min: i128, max: i128) -> Result<i128, Error> {
+        match self {
+            Exact::Value(value) => out_of_range(value, min, max),
+            Exact::Product(..) => Err(Error::Overflow { negative: self.is_negative(), min, max }),
+        }
+    }
+
+    // The remainder of the result divided by "modulus", from 0 to "modulus - 1".
+    fn rem_euclid(self, modulus: i128) -> i128 {
+        match self {
+            Exact::Value(value) => value.rem_euclid(modulus),
+            // The factors are brought below "modulus" first. A range holds at most 2^64 values, so their product fits in "u128".
+            Exact::Product(lhs, rhs) => {
+                let product = lhs.rem_euclid(modulus) as u128 * rhs.rem_euclid(modulus) as u128;
+                (product % modulus as u128) as i128
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Panic;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Wrap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Saturate;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Checked;
+
+impl Policy for Panic {
+    type Output<V> = V;
+
+    fn resolve(exact: Exact, min: i128, max: i128) -> Result<i128, Error> {
+        exact.in_range(min, max)
+    }
+
+    #[track_caller]
+    fn output<V>(result: Result<V, Error>) -> V {
+        result.unwrap_or_else(|error| panic!("{}", error))
+    }
+}
+
+impl Policy for Wrap {
+    type Output<V> = V;
+
+    fn resolve(exact: Exact, min: i128, max: i128) -> Result<i128, Error> {
+        let modulus = max - min + 1;
+        Ok(min + (exact.rem_euclid(modulus) - min.rem_euclid(modulus)).rem_euclid(modulus))
+    }
+
+    // Only a division by zero can fail, and it panics like it does for the integer types.
+    #[track_caller]
+    fn output<V>(result: Result<V, Error>) -> V {
+        result.unwrap_or_else(|error| panic!("{}", error))
+    }
+}
+
+impl Policy for Saturate {
+    type Output<V> = V;
+
+    fn resolve(exact: Exact, min: i128, max: i128) -> Result<i128, Error> {
+        match exact {
+            Exact::Value(value) => Ok(value.clamp(min, max)),
+            Exact::Product(..) if exact.is_negative() => Ok(min),
+            Exact::Product(..) => Ok(max),
+        }
+    }
+
+    #[track_caller]
+    fn output<V>(result: Result<V, Error>) -> V {
+        result.unwrap_or_else(|error| panic!("{}", error))
+    }
+}
+
+impl Policy for Checked {
+    type Output<V> = Result<V, Error>;
+
+    fn resolve(exact: Exact, min: i128, max: i128) -> Result<i128, Error> {
+        exact.in_range(min, max)
+    }
+
+    fn output<V>(result: Result<V, Error>) -> Result<V, Error> {
+        result
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Operation {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+fn exact(lhs: i128, rhs: i128, operation: Operation) -> Result<Exact, Error> {
+    match operation {
+        Operation::Add => Ok(Exact::Value(lhs + rhs)),
+        Operation::Sub => Ok(Exact::Value(lhs - rhs)),
+        Operation::Mul => Ok(lhs.checked_mul(rhs).map_or(Exact::Product(lhs, rhs), Exact::Value)),
+        Operation::Div | Operation::Rem if rhs == 0 => Err(Error::DivisionByZero),
+        Operation::Div => Ok(Exact::Value(lhs / rhs)),
+        Operation::Rem => Ok(Exact::Value(lhs % rhs)),
+    }
+}
+
+// An integer of type "T" that is always between "MIN" and "MAX".
+// The bounds are "i128" because a const parameter cannot have the generic type "T", and they are checked against "T" when the type is used.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bounded<T, const MIN: i128, const MAX: i128, P = Panic> {
+    value: T,
+    policy: PhantomData<P>,
+}
+
+impl<T: Integer, const MIN: i128, const MAX: i128, P: Policy> Bounded<T, MIN, MAX, P> {
+    // Fails to compile (when the type is used) if the range does not fit in "T", such as "Bounded<u8, 0, 300>".
+    const VALID: () = assert!(
+        T::LOWEST <= MIN && MIN <= MAX && MAX <= T::HIGHEST,
+        "the range of a Bounded must be inside the range of its integer type"
+    );
+
+    // Panics if "value" is out of the range, whatever the policy.
+    #[track_caller]
+    pub fn new(value: T) -> Self {
+        Self::try_new(value).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    pub fn try_new(value: T) -> Result<Self, Error> {
+        let () = Self::VALID;
+        out_of_range(value.to_i128(), MIN, MAX).map(|_| Self::from_raw(value))
+    }
+
+    // Brings "value" into the range with the policy, so "Bounded::<u8, 1, 12, Wrap>::with_policy(13)" is 1.
+    #[track_caller]
+    pub fn with_policy(value: T) -> P::Output<Self> {
+        let () = Self::VALID;
+        P::output(P::resolve(Exact::Value(value.to_i128()), MIN, MAX).map(|value| Self::from_raw(T::from_i128(value))))
+    }
+
+    pub fn get(self) -> T {
+        self.value
+    }
+
+    pub fn min() -> Self {
+        let () = Self::VALID;
+        Self::from_raw(T::from_i128(MIN))
+    }
+
+    pub fn max() -> Self {
+        let () = Self::VALID;
+        Self::from_raw(T::from_i128(MAX))
+    }
+
+    fn from_raw(value: T) -> Self {
+        Bounded {
+            value,
+            policy: PhantomData,
+        }
+    }
+
+    #[track_caller]
+    fn apply(&self, rhs: T, operation: Operation) -> P::Output<Self> {
+        let result = exact(self.value.to_i128(), rhs.to_i128(), operation)
+            .and_then(|exact| P::resolve(exact, MIN, MAX))
+            .map(|value| Self::from_raw(T::from_i128(value)));
+        P::output(result)
+    }
+}
+
+impl<T: fmt::Display, const MIN: i128, const MAX: i128, P> fmt::Display for Bounded<T, MIN, MAX, P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+// Written as "Bounded(20)", since the policy holds nothing to show.
+impl<T: fmt::Debug, const MIN: i128, const MAX: i128, P> fmt::Debug for Bounded<T, MIN, MAX, P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Bounded").field(&self.value).finish()
+    }
+}
+
+// Every operator works with another "Bounded" of the same type, or with a plain "T" ("age + 1").
+// The assigning operators ("age += 1") only exist for the policies that return the value itself, since "Checked" returns a "Result".
+macro_rules! bounded_operator {
+    ($op:ident, $method:ident, $op_assign:ident, $method_assign:ident, $operation:expr) => {
+        impl<T: Integer, const MIN: i128, const MAX: i128, P: Policy> $op<T> for Bounded<T, MIN, MAX, P> {
+            type Output = P::Output<Self>;
+
+            #[track_caller]
+            fn $method(self, rhs: T) -> Self::Output {
+                self.apply(rhs, $operation)
+            }
+        }
+
+        impl<T: Integer, const MIN: i128, const MAX: i128, P: Policy> $op for Bounded<T, MIN, MAX, P> {
+            type Output = P::Output<Self>;
+
+            #[track_caller]
+            fn $method(self, rhs: Self) -> Self::Output {
+                self.apply(rhs.value, $operation)
+            }
+        }
+
+        impl<T: Integer, const MIN: i128, const MAX: i128, P> $op_assign<T> for Bounded<T, MIN, MAX, P>
+        where
+            P: Policy<Output<Self> = Self>,
+        {
+            #[track_caller]
+            fn $method_assign(&mut self, rhs: T) {
+                *self = self.apply(rhs, $operation);
+            }
+        }
+
+        impl<T: Integer, const MIN: i128, const MAX: i128, P> $op_assign for Bounded<T, MIN, MAX, P>
+        where
+            P: Policy<Output<Self> = Self>,
+        {
+            #[track_caller]
+            fn $method_assign(&mut self, rhs: Self) {
+                *self = self.apply(rhs.value, $operation);
+            }
+        }
+    };
+}
+
+bounded_operator!(Add, add, AddAssign, add_assign, Operation::Add);
+bounded_operator!(Sub, sub, SubAssign, sub_assign, Operation::Sub);
+bounded_operator!(Mul, mul, MulAssign, mul_assign, Operation::Mul);
+bounded_operator!(Div, div, DivAssign, div_assign, Operation::Div);
+bounded_operator!(Rem, rem, RemAssign, rem_assign, Operation::Rem);
+
+// An integer that stops at the limits of its type instead of overflowing, so "Saturating(250u8) + 10" is 255.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Saturating<T>(pub T);
+
+impl<T: Integer> Saturating<T> {
+    #[track_caller]
+    fn apply(self, rhs: T, operation: Operation) -> Self {
+        let result = exact(self.0.to_i128(), rhs.to_i128(), operation)
+            .and_then(|exact| Saturate::resolve(exact, T::LOWEST, T::HIGHEST))
+            .map(|value| Saturating(T::from_i128(value)));
+        Saturate::output(result)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Saturating<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Saturating<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Saturating").field(&self.0).finish()
+    }
+}
+
+macro_rules! saturating_operator {
+    ($op:ident, $method:ident, $op_assign:ident, $method_assign:ident, $operation:expr) => {
+        impl<T: Integer> $op<T> for Saturating<T> {
+            type Output = Self;
+
+            #[track_caller]
+            fn $method(self, rhs: T) -> Self {
+                self.apply(rhs, $operation)
+            }
+        }
+
+        impl<T: Integer> $op for Saturating<T> {
+            type Output = Self;
+
+            #[track_caller]
+            fn $method(self, rhs: Self) -> Self {
+                self.apply(rhs.0, $operation)
+            }
+        }
+
+        impl<T: Integer> $op_assign<T> for Saturating<T> {
+            #[track_caller]
+            fn $method_assign(&mut self, rhs: T) {
+                *self = self.apply(rhs, $operation);
+            }
+        }
+
+        impl<T: Integer> $op_assign for Saturating<T> {
+            #[track_caller]
+            fn $method_assign(&mut self, rhs: Self) {
+                *self = self.apply(rhs.0, $operation);
+            }
+        }
+    };
+}
+
+saturating_operator!(Add, add, AddAssign, add_assign, Operation::Add);
+saturating_operator!(Sub, sub, SubAssign, sub_assign, Operation::Sub);
+saturating_operator!(Mul, mul, MulAssign, mul_assign, Operation::Mul);
+saturating_operator!(Div, div, DivAssign, div_assign, Operation::Div);
+saturating_operator!(Rem, rem, RemAssign, rem_assign, Operation::Rem);
@@ -0,0 +1,118 @@
+// The tests of every policy of "Bounded", and of "Saturating".
+
+use bounded::{Bounded, Checked, Error, Saturate, Saturating, Wrap};
+
+const U64_MAX: i128 = u64::MAX as i128;
+
+type Age = Bounded<u8, 0, 150>;
+type Month = Bounded<u8, 1, 12, Wrap>;
+type Percent = Bounded<u8, 0, 100, Saturate>;
+type Temperature = Bounded<i8, -50, 50, Checked>;
+
+#[test]
+fn values_must_start_in_the_range() {
+    assert_eq!(Age::new(20).get(), 20);
+    assert_eq!(Age::try_new(151), Err(Error::OutOfRange { value: 151, min: 0, max: 150 }));
+    assert_eq!(Month::try_new(0).unwrap_err().to_string(), "0 is out of the range 1..=12");
+    assert_eq!((Month::min().get(), Month::max().get()), (1, 12));
+
+    // "with_policy" brings the value into the range instead.
+    assert_eq!(Month::with_policy(13).get(), 1);
+    assert_eq!(Percent::with_policy(255).get(), 100);
+    assert!(Temperature::with_policy(-51).is_err());
+}
+
+#[test]
+fn panic_is_the_default_policy() {
+    let mut age = Age::new(20);
+    age += 1;
+    assert_eq!(age + 9, Age::new(30));
+    assert_eq!(age * 2, Age::new(42));
+    assert_eq!(age - age, Age::new(0));
+    assert_eq!(age.to_string(), "21");
+    assert_eq!(format!("{:?}", age), "Bounded(21)");
+
+    // The operation is done on the exact values, so "u8" itself never overflows, even though 200 + 100 does not fit.
+    let result = std::panic::catch_unwind(|| Age::new(100) + 100);
+    let message = *result.unwrap_err().downcast::<String>().unwrap();
+    assert_eq!(message, "200 is out of the range 0..=150");
+
+    assert!(std::panic::catch_unwind(|| Age::new(0) - 1).is_err());
+    assert!(std::panic::catch_unwind(|| Age::new(1) / 0).is_err());
+}
+
+#[test]
+fn wrap_goes_around_to_the_other_end() {
+    let december = Month::new(12);
+    assert_eq!(december + 1, Month::new(1));
+    assert_eq!(december + 25, Month::new(1));
+    assert_eq!(Month::new(1) - 1, Month::new(12));
+    assert_eq!(Month::new(3) - 15, Month::new(12));
+
+    let mut month = Month::new(11);
+    month *= 2;
+    assert_eq!(month, Month::new(10));
+
+    // A full range wraps like the integer type itself.
+    let byte = Bounded::<u8, 0, 255, Wrap>::new(255);
+    assert_eq!((byte + 1).get(), 255u8.wrapping_add(1));
+}
+
+#[test]
+fn saturate_stops_at_the_ends() {
+    let mut percent = Percent::new(90);
+    percent += 20;
+    assert_eq!(percent.get(), 100);
+    percent -= 250;
+    assert_eq!(percent.get(), 0);
+    assert_eq!((Percent::new(60) * Percent::new(2)).get(), 100);
+    assert_eq!((Percent::new(60) / 7).get(), 8);
+    assert_eq!((Percent::new(60) % 7).get(), 4);
+}
+
+#[test]
+fn checked_returns_an_error() {
+    let warm = Temperature::new(30);
+    assert_eq!(warm + 20, Ok(Temperature::new(50)));
+    assert_eq!(warm + 21, Err(Error::OutOfRange { value: 51, min: -50, max: 50 }));
+    assert_eq!(warm - 100, Err(Error::OutOfRange { value: -70, min: -50, max: 50 }));
+    assert_eq!(warm / 0, Err(Error::DivisionByZero));
+    assert_eq!(warm % 0, Err(Error::DivisionByZero));
+}
+
+#[test]
+fn saturating_stops_at_the_limits_of_its_type() {
+    let mut value = Saturating(250u8);
+    value += 10;
+    assert_eq!(value, Saturating(255));
+    assert_eq!(value - 255 - 1, Saturating(0));
+    assert_eq!(Saturating(i8::MIN) * -1, Saturating(i8::MAX));
+    assert_eq!(Saturating(i8::MIN) / -1, Saturating(i8::MAX));
+    assert_eq!(Saturating(100i64) + Saturating(i64::MAX), Saturating(i64::MAX));
+    assert_eq!(Saturating(7u32).to_string(), "7");
+}
+
+// "u64::MAX * u64::MAX" does not even fit in "i128", where the calculations are done.
+#[test]
+fn products_that_do_not_fit_in_i128_are_resolved_by_the_policy() {
+    let result = std::panic::catch_unwind(|| Bounded::<u64, 0, U64_MAX>::new(u64::MAX) * u64::MAX);
+    let message = *result.unwrap_err().downcast::<String>().unwrap();
+    assert_eq!(message, "the result does not fit in i128, and is above the range 0..=18446744073709551615");
+
+    let wrapped = Bounded::<u64, 0, U64_MAX, Wrap>::new(u64::MAX) * u64::MAX;
+    assert_eq!(wrapped.get(), u64::MAX.wrapping_mul(u64::MAX));
+    // The product fits in "u128", which gives the expected value for a range that does not start at 0.
+    let wrapped = Bounded::<u64, 5, U64_MAX, Wrap>::new(u64::MAX) * u64::MAX;
+    let expected = 5 + (u64::MAX as u128 * u64::MAX as u128 - 5) % (u64::MAX as u128 - 4);
+    assert_eq!(wrapped.get() as u128, expected);
+
+    let saturated = Bounded::<u64, 0, 1000, Saturate>::new(1000) * u64::MAX;
+    assert_eq!(saturated.get(), 1000);
+    assert_eq!((Bounded::<u64, 0, U64_MAX, Saturate>::new(u64::MAX) * u64::MAX).get(), u64::MAX);
+
+    let checked = Bounded::<u64, 0, U64_MAX, Checked>::new(u64::MAX) * u64::MAX;
+    assert_eq!(checked, Err(Error::Overflow { negative: false, min: 0, max: U64_MAX }));
+
+    assert_eq!(Saturating(u64::MAX) * u64::MAX, Saturating(u64::MAX));
+    assert_eq!(Saturating(usize::MAX) * Saturating(usize::MAX), Saturating(usize::MAX));
+}
@@ -0,0 +1,11 @@
+// The reference solutions of "src/exercises.rs" (see "Exercises" in the README).
+
+use std::collections::HashMap;
+
+pub fn word_counts(text: &str) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for word in text.split_whitespace() {
+        *counts.entry(word.to_string()).or_insert(0) += 1;
+    }
+    counts
+}
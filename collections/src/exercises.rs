@@ -0,0 +1,12 @@
+// The exercises of "collections", checked with "cargo run -p main -- check collections::word_counts" (see "Exercises" in the README).
+
+#![allow(unused_variables)]
+
+use std::collections::HashMap;
+
+// Count how many times every word appears in "text", where words are separated by whitespace.
+// "word_counts("the cat saw the dog")" should map "the" to 2, and "cat", "saw" and "dog" to 1.
+// Hint: the "entry()" function of a HashMap inserts a value only if the key is not in the map yet.
+pub fn word_counts(text: &str) -> HashMap<String, usize> {
+    todo!()
+}
@@ -1,5 +1,6 @@
 // Hashmaps need to be imported first.
 use std::collections::HashMap;
+use std::io::{self, Write};
 
 // Hashmaps are basically like dictionaries in python.
 // The following function creates a new hashmap.
@@ -10,7 +11,7 @@ pub fn hashmap_string_string() -> HashMap<String , String> {
     HashMap::new()
 }
 
-pub fn functions_on_hashmaps() {
+pub fn functions_on_hashmaps_to(out: &mut dyn Write) -> io::Result<()> {
     let mut map = hashmap_string_string();
     map.insert("Key".to_string(), "Value".to_string());
     
@@ -41,8 +42,8 @@ pub fn functions_on_hashmaps() {
         // "If something is found in this value, print it."
         // "If nothing is found in this value, print 'Not Found'."
     match map.get("Key"){
-        Some(value) => println!("Value is -> {}" , value),
-        None => println!("Not Found"),
+        Some(value) => writeln!(out, "Value is -> {}" , value)?,
+        None => writeln!(out, "Not Found")?,
     }
 
 
@@ -52,7 +53,7 @@ pub fn functions_on_hashmaps() {
         // "Then put the references as is in 'k' and 'v' respectively."
     // Note that the map needs to be referenced.
     for (k , v) in &map {
-        println!("{} : {}" , k , v);
+        writeln!(out, "{} : {}" , k , v)?;
     }
 
     // So why does the code above work but the following commented code doesn't?
@@ -64,9 +65,10 @@ pub fn functions_on_hashmaps() {
     // The following code does not work because of the COPYING that happens.
     // Remember that for some value to be copied, it needs to be of type "copy", such as ints.
     // "String" is not of type copy.
+    // compile_fail: E0507
     /*
     for (&k , &v) in &map {
-        println!("{} : {}" , k , v);
+        writeln!(out, "{} : {}" , k , v)?;
     }
     */
 
@@ -79,7 +81,7 @@ pub fn functions_on_hashmaps() {
     let mut mapstr: HashMap<&str, &str> = HashMap::new();
     mapstr.insert("Key", "Value");
     for (&k , &v) in &mapstr{
-        println!("{} : {}" , k , v);
+        writeln!(out, "{} : {}" , k , v)?;
     }
     
     // The following "entry" function gets the entire entry with the specified key.
@@ -88,14 +90,14 @@ pub fn functions_on_hashmaps() {
     // Thus, the entire entry's (in the "HashMap") ownership will not be moved into the variable "entry".
     // The variable "entry" (under the hood) will just have a reference to the entry in the "HashMap".  
     let entry = map.entry("Key".to_string());
-    println!("{}" , entry.key());
+    writeln!(out, "{}" , entry.key())?;
 
     // entries can be matched to do some task if they are occupied.
     match entry {
         std::collections::hash_map::Entry::Occupied(value) => {
-            println!("{}" , value.get());
+            writeln!(out, "{}" , value.get())?;
         }
-        _ => println!("Not found")
+        _ => writeln!(out, "Not found")?,
     }
 
     // Entries can be entered if the key is absent.
@@ -107,6 +109,12 @@ pub fn functions_on_hashmaps() {
 
     
     map.remove(&"Key".to_string());
+
+    Ok(())
+}
+
+pub fn functions_on_hashmaps() {
+    functions_on_hashmaps_to(&mut io::stdout()).expect("failed to write to stdout");
 }
 
 // Hashmaps can contain Structs.
@@ -1,3 +1,7 @@
 pub mod tuples;
 pub mod vectors;
-pub mod hashmaps;
\ No newline at end of file
+pub mod hashmaps;
+
+// The exercises for this lesson (see "Exercises" in the README).
+#[cfg_attr(feature = "solution", path = "../solutions/exercises.rs")]
+pub mod exercises;
@@ -1,9 +1,17 @@
+use std::io::{self, Write};
+
 // Tuples can be returned from functions as the following.
 pub fn tuple_return() -> (String , String , i32) {
     ("Hello".to_string() , "World".to_string() , 25)
 }
 
-pub fn tuple_testing() {
+pub fn tuple_testing_to(out: &mut dyn Write) -> io::Result<()> {
     let (hello , _ , _) = tuple_return();
-    println!("{}" , hello);
+    writeln!(out, "{}" , hello)?;
+
+    Ok(())
+}
+
+pub fn tuple_testing() {
+    tuple_testing_to(&mut io::stdout()).expect("failed to write to stdout");
 }
\ No newline at end of file
@@ -1,5 +1,7 @@
 // Vectors in rust are the same as arrays in other programming languages.
 
+use std::io::{self, Write};
+
 // A vector of a fixed size is written in the form of [datatype ; length].
 // Note that the datatype and length are separated with a semi-colon ";".
 pub fn vector_fixed_size() -> [String ; 2] {
@@ -12,17 +14,29 @@ pub fn vector_fixed_size() -> [String ; 2] {
 }
 
 // Using loops to iterate over a vector is done by calling the "iter()" function in vectors:
-pub fn iter_over_vector_loops() {
+pub fn iter_over_vector_loops_to(out: &mut dyn Write) -> io::Result<()> {
     for value in vector_fixed_size().iter(){
-        println!("{}" , value);
+        writeln!(out, "{}" , value)?;
     }
+
+    Ok(())
+}
+
+pub fn iter_over_vector_loops() {
+    iter_over_vector_loops_to(&mut io::stdout()).expect("failed to write to stdout");
 }
 
 // calling specific values is done by calling the reference of the element being called.
-pub fn specific_vector_element() {
+pub fn specific_vector_element_to(out: &mut dyn Write) -> io::Result<()> {
     let values = vector_fixed_size();
     let hello = &values[0];
-    println!("{}, {}" , hello , &values[1]);
+    writeln!(out, "{}, {}" , hello , &values[1])?;
+
+    Ok(())
+}
+
+pub fn specific_vector_element() {
+    specific_vector_element_to(&mut io::stdout()).expect("failed to write to stdout");
 }
 
 // Values in a vector can be mapped.
@@ -41,34 +55,45 @@ pub fn mapping_iteration_vector() {
 // This is done using macros.
 // Using this method creates a vector that does not have a fixed size.
 // vectors are immutable by default, but this one will be mutable by using the "mut" keyword.
-pub fn vector_shorthand() {
+pub fn vector_shorthand_to(out: &mut dyn Write) -> io::Result<()> {
     let mut values = vec![1 , 2 , 3 , 4];
     // Because this does not have a fixed size, values can be added and removed from the vector.
 
     values.push(100);
     values.remove(1);
 
-    println!("Values are {:?}" , values);
+    writeln!(out, "Values are {:?}" , values)?;
     values.clear();
-    println!("Values are {:?}" , values);
+    writeln!(out, "Values are {:?}" , values)?;
 
+    Ok(())
+}
+
+pub fn vector_shorthand() {
+    vector_shorthand_to(&mut io::stdout()).expect("failed to write to stdout");
 }
 
 // 2 vectors can be concatinated together:
-pub fn concat_vectors() {
+pub fn concat_vectors_to(out: &mut dyn Write) -> io::Result<()> {
     let mut values = vec![1 , 2 , 3 , 4];
-    println!("Values are {:?}" , values);
+    writeln!(out, "Values are {:?}" , values)?;
     values.extend_from_slice(&[10 , 11 , 12 , 13]);
-    println!("Values are {:?}" , values);
+    writeln!(out, "Values are {:?}" , values)?;
 
 
     // it can also be done in the following way:
     let mut values1 = vec! [5 , 6 , 7 , 8];
     let mut values2 = vec! [15 , 16 , 17 , 18];
 
-    println!("Values1 are {:?}" , values1);
-    println!("Values2 are {:?}" , values2);
+    writeln!(out, "Values1 are {:?}" , values1)?;
+    writeln!(out, "Values2 are {:?}" , values2)?;
     values1.append(&mut values2);
-    println!("Values1 are {:?}" , values1);
-    println!("Values2 are {:?}" , values2);
+    writeln!(out, "Values1 are {:?}" , values1)?;
+    writeln!(out, "Values2 are {:?}" , values2)?;
+
+    Ok(())
+}
+
+pub fn concat_vectors() {
+    concat_vectors_to(&mut io::stdout()).expect("failed to write to stdout");
 }
\ No newline at end of file
@@ -0,0 +1,29 @@
+// The tests of the exercises in "src/exercises.rs" (see "Exercises" in the README).
+
+mod word_counts {
+    use collections::exercises::word_counts;
+
+    #[test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    fn counts_repeated_words() {
+        let counts = word_counts("the cat saw the dog");
+        assert_eq!(counts.len(), 4);
+        assert_eq!(counts["the"], 2);
+        assert_eq!(counts["cat"], 1);
+        assert_eq!(counts["dog"], 1);
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    fn ignores_extra_whitespace() {
+        let counts = word_counts("  a\tb \n a  ");
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts["a"], 2);
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    fn empty_text_has_no_words() {
+        assert!(word_counts("").is_empty());
+    }
+}
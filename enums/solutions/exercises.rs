@@ -0,0 +1,19 @@
+// The reference solutions of "src/exercises.rs" (see "Exercises" in the README).
+
+use std::f64::consts::PI;
+
+pub enum Shapes {
+    Circle { center: (f64, f64), radius: f64 },
+    Rectangle { width: f64, height: f64 },
+    Triangle { base: f64, height: f64 },
+}
+
+impl Shapes {
+    pub fn calc_area(&self) -> f64 {
+        match self {
+            Shapes::Circle { center: _, radius } => PI * radius * radius,
+            Shapes::Rectangle { width, height } => width * height,
+            Shapes::Triangle { base, height } => base * height / 2.0,
+        }
+    }
+}
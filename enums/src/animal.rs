@@ -4,6 +4,7 @@
 // The enumerations themselves are also written in PascalCase.
 // Each enum value is separated by a comma.
 
+use std::io::{self, Write};
 use std::default;
 
 enum AnimalType {
@@ -13,24 +14,31 @@ enum AnimalType {
     Snake,
 }
 
-pub fn enum_testing() {
+pub fn enum_testing_to(out: &mut dyn Write) -> io::Result<()> {
     // Calling a value from the enum is done using the double colons.
     let animal_1 = AnimalType::Dog;
 
     // When comparing enum instances, an if-statement can be used, even though it is not recommended.
     // This will only work if you use the "#[derive(PartialEq)]" trait. (Traits will be in a later section).
+    // compile_fail: E0369
     /*
     if animal_1 == AnimalType::Dog {
-        println!("Animal is a dog!");
+        writeln!(out, "Animal is a dog!")?;
     }
     */
 
     // What is generally recommended for comparison (of course this is not always the case) is actually using a switch statement.
     // In a switch statement, the default is written with an underscore "_".
     match animal_1 {
-        AnimalType::Cat => println!("Animal_1 is a cat!"),
-        AnimalType::Dog => println!("Animal_1 is a Dog!"),
-        AnimalType::Rabbit => println!("Animal_1 is a Rabbit!"),
-        _ => println!("This seems Complicated!"),
+        AnimalType::Cat => writeln!(out, "Animal_1 is a cat!")?,
+        AnimalType::Dog => writeln!(out, "Animal_1 is a Dog!")?,
+        AnimalType::Rabbit => writeln!(out, "Animal_1 is a Rabbit!")?,
+        _ => writeln!(out, "This seems Complicated!")?,
     }
+
+    Ok(())
+}
+
+pub fn enum_testing() {
+    enum_testing_to(&mut io::stdout()).expect("failed to write to stdout");
 }
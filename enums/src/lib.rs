@@ -1,2 +1,6 @@
 pub mod animal;
-pub mod shapes;
\ No newline at end of file
+pub mod shapes;
+
+// The exercises for this lesson (see "Exercises" in the README).
+#[cfg_attr(feature = "solution", path = "../solutions/exercises.rs")]
+pub mod exercises;
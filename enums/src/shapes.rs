@@ -1,3 +1,5 @@
+use std::io::{self, Write};
+
 // Enums in Rust are very flexible because enum values can have attributes that define each enum value.
 // It is kind of like adding a structure in an enum.
 // Here, the values in "Shapes" enum is defined using curly braces.
@@ -22,7 +24,7 @@ impl Shapes {
     }
 }
 
-pub fn enum_testing() {
+pub fn enum_testing_to(out: &mut dyn Write) -> io::Result<()> {
     // This codeblock create an enum with associated values "width" , "height".
     let rect = Shapes::Rectangle { 
         width: 2.0,
@@ -32,7 +34,7 @@ pub fn enum_testing() {
     // To compare enums with associated values, the "if let" statement is used.
     // The following piece checks if "rect" is of type rectangle or not.
     if let Shapes::Rectangle { width, height } = rect{
-        println!("This shape is a Rectangle with width = {}, height = {}, and area = {}!" , width , height , rect.calc_area());
+        writeln!(out, "This shape is a Rectangle with width = {}, height = {}, and area = {}!" , width , height , rect.calc_area())?;
     }
 
     // These types of enums can also be matched with a switch statement.
@@ -40,11 +42,16 @@ pub fn enum_testing() {
 
     match circ {
         Shapes::Circle { center, radius } => {
-            println!("This shape is a circle with center = ({} , {}), radius = {} and area = {}!" , center.0 , center.1 , radius , circ.calc_area());
+            writeln!(out, "This shape is a circle with center = ({} , {}), radius = {} and area = {}!" , center.0 , center.1 , radius , circ.calc_area())?;
         },
-        _ => println!("This is complicated!"),
+        _ => writeln!(out, "This is complicated!")?,
     }
 
+    Ok(())
+}
+
+pub fn enum_testing() {
+    enum_testing_to(&mut io::stdout()).expect("failed to write to stdout");
 }
 
 
@@ -65,7 +72,7 @@ enum ShapesPar {
 }
 
 
-pub fn enum_testing_unnamed() {
+pub fn enum_testing_unnamed_to(out: &mut dyn Write) -> io::Result<()> {
     // This segment creates a rectangle and the size struct and associates the struct with the rectangle enum.
     // The struct can be also written in the enum creation itself such as "let _rect = ShapesPar::Rectangle(1.0, 1.0, Size {width: 10.0 , height: 20.0});".
     let rect_size = Size {
@@ -80,15 +87,21 @@ pub fn enum_testing_unnamed() {
 
     // checking that this instance of the unnamed enum is of type rectangle
     if let ShapesPar::Rectangle(_x, _y , Size { width:_, height:_ }) = rect {
-        println!("This is a rectangle!");
+        writeln!(out, "This is a rectangle!")?;
     }
 
     // Again, this can also be matched with a switch statement.
     match rect {
         ShapesPar::Rectangle(_x, _y , Size { width:_, height:_ }) => {
-            println!("This is a rectangle!");
+            writeln!(out, "This is a rectangle!")?;
         },
 
-        _ => println!("This is complicated!"),
+        _ => writeln!(out, "This is complicated!")?,
     }
+
+    Ok(())
+}
+
+pub fn enum_testing_unnamed() {
+    enum_testing_unnamed_to(&mut io::stdout()).expect("failed to write to stdout");
 }
\ No newline at end of file
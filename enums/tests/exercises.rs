@@ -0,0 +1,30 @@
+// The tests of the exercises in "src/exercises.rs" (see "Exercises" in the README).
+
+mod triangle_area {
+    use enums::exercises::Shapes;
+
+    #[test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    fn triangle_area_is_half_of_base_times_height() {
+        let triangle = Shapes::Triangle {
+            base: 4.0,
+            height: 3.0,
+        };
+        assert_eq!(triangle.calc_area(), 6.0);
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    fn other_shapes_still_work() {
+        let rect = Shapes::Rectangle {
+            width: 2.0,
+            height: 4.0,
+        };
+        let circle = Shapes::Circle {
+            center: (0.0, 0.0),
+            radius: 1.0,
+        };
+        assert_eq!(rect.calc_area(), 8.0);
+        assert!((circle.calc_area() - std::f64::consts::PI).abs() < 1e-9);
+    }
+}
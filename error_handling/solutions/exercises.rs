@@ -0,0 +1,15 @@
+// The reference solutions of "src/exercises.rs" (see "Exercises" in the README).
+
+#[derive(Debug, PartialEq)]
+pub enum AgeError {
+    NotANumber,
+    TooOld(u32),
+}
+
+pub fn parse_age(input: &str) -> Result<u32, AgeError> {
+    let age: u32 = input.trim().parse().map_err(|_| AgeError::NotANumber)?;
+    if age > 150 {
+        return Err(AgeError::TooOld(age));
+    }
+    Ok(age)
+}
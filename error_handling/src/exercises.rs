@@ -0,0 +1,16 @@
+// The exercises of "error_handling", checked with "cargo run -p main -- check error_handling::parse_age" (see "Exercises" in the README).
+
+#![allow(unused_variables)]
+
+#[derive(Debug, PartialEq)]
+pub enum AgeError {
+    NotANumber,
+    TooOld(u32),
+}
+
+// Parse an age from "input", ignoring any whitespace around it.
+// Return "AgeError::NotANumber" if the input is not a whole number, and "AgeError::TooOld" with the number if it is above 150.
+// Hint: "str::parse()" returns a "Result" too, and "map_err()" can turn its error into an "AgeError".
+pub fn parse_age(input: &str) -> Result<u32, AgeError> {
+    todo!()
+}
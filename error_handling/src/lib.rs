@@ -1,8 +1,14 @@
+// The exercises for this lesson (see "Exercises" in the README).
+#[cfg_attr(feature = "solution", path = "../solutions/exercises.rs")]
+pub mod exercises;
+
+use std::io::{self, Write};
+
 // Rust is not like other languages in which exceptions are just thrown as a string.
 // Rust has error types and an error thrown needs to be of that particular type.
 // If a function can return either a value or an error, then the return type of the function should be of type "Result".
 // There a lot of concepts to understand here.
-pub fn errors() {
+pub fn errors_to(out: &mut dyn Write) -> io::Result<()> {
     // This is a simple "Result".
     // Saying "Result<&str , Box<dyn std::error::Error>>" means that the result can be a string slice, or an error that sits in the heap.
     // The keyword "Box" is used to reference a value in the heap (to be discussed later in the pointers chapter).
@@ -15,8 +21,8 @@ pub fn errors() {
     // In case of an "Err", the error will be printed.
     // note the "error" of type "Box dynamic".
     match value {
-        Ok(value) => println!("{}", value),
-        Err(error) => println!("{}", error),
+        Ok(value) => writeln!(out, "{}", value)?,
+        Err(error) => writeln!(out, "{}", error)?,
     }
 
     // Exceptions can also be customized.
@@ -24,8 +30,8 @@ pub fn errors() {
     let value: Result<&str, ()> = Err(());
 
     match value {
-        Ok(value) => println!("{}", value),
-        Err(_) => println!("Some Error Occured"),
+        Ok(value) => writeln!(out, "{}", value)?,
+        Err(_) => writeln!(out, "Some Error Occured")?,
     }
 
     // Results can have values expected from them using the ".expect(msg)" function.
@@ -37,7 +43,7 @@ pub fn errors() {
     // If the function returns "Err" then the code will panic with the expected "msg".
     // The code will panic if the ".expect(msg)" returns an error
     let user_name = get_user_name().expect("Failed to get user_name.");
-    println!("Hello, {}", user_name);
+    writeln!(out, "Hello, {}", user_name)?;
 
     // The ".expect_err(msg)" is the opposite.
     // It expects the code to actually bring an error and will panic if the result is "Ok".
@@ -52,7 +58,7 @@ pub fn errors() {
     // Results can be checked whether they're an "Err" or "Ok" with ".is_err()" and ".is_ok" functions.
     let ok = get_user_name().is_ok();
     let err = get_user_name().is_err();
-    println!("{} , {}", ok, err);
+    writeln!(out, "{} , {}", ok, err)?;
 
     // There is a cool feature in rust in which rust can trigger early exits in the code if an error is present.
     // As an example, consider the functions "get_first_name" , "get_last_name" , and "get_full_name".
@@ -71,8 +77,8 @@ pub fn errors() {
 
     let full_name = get_full_name();
     match full_name {
-        Ok(name) => println!("Hello , {}", name),
-        Err(_) => println!("ERROR!"),
+        Ok(name) => writeln!(out, "Hello , {}", name)?,
+        Err(_) => writeln!(out, "ERROR!")?,
     }
 
     // If the "?" is not present, then the code will continue even though the "get_first_name()" returns "Err".
@@ -80,10 +86,10 @@ pub fn errors() {
     let f_name = get_first_name();
     let l_name = get_last_name();
 
-    println!("Debug f_name: {:?}", f_name);
-    println!("Debug l_name: {:?}", l_name);
+    writeln!(out, "Debug f_name: {:?}", f_name)?;
+    writeln!(out, "Debug l_name: {:?}", l_name)?;
 
-    println!("Still Works"); // still returns Ok!
+    writeln!(out, "Still Works")?; // still returns Ok!
 
     // Errors can be mapped as well.
     let get_something =
@@ -91,4 +97,10 @@ pub fn errors() {
 
     let name = get_something();
     let _error_length = name.map_err(|e| e.len());
+
+    Ok(())
+}
+
+pub fn errors() {
+    errors_to(&mut io::stdout()).expect("failed to write to stdout");
 }
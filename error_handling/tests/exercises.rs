@@ -0,0 +1,27 @@
+// The tests of the exercises in "src/exercises.rs" (see "Exercises" in the README).
+
+mod parse_age {
+    use error_handling::exercises::{AgeError, parse_age};
+
+    #[test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    fn parses_valid_ages() {
+        assert_eq!(parse_age("30"), Ok(30));
+        assert_eq!(parse_age(" 0\n"), Ok(0));
+        assert_eq!(parse_age("150"), Ok(150));
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    fn rejects_text_that_is_not_a_number() {
+        assert_eq!(parse_age("thirty"), Err(AgeError::NotANumber));
+        assert_eq!(parse_age("-5"), Err(AgeError::NotANumber));
+        assert_eq!(parse_age(""), Err(AgeError::NotANumber));
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    fn rejects_ages_above_150() {
+        assert_eq!(parse_age("151"), Err(AgeError::TooOld(151)));
+    }
+}
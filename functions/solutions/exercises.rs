@@ -0,0 +1,5 @@
+// The reference solutions of "src/exercises.rs" (see "Exercises" in the README).
+
+pub fn apply_twice(function: fn(i32) -> i32, value: i32) -> i32 {
+    function(function(value))
+}
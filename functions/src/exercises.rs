@@ -0,0 +1,10 @@
+// The exercises of "functions", checked with "cargo run -p main -- check functions::apply_twice" (see "Exercises" in the README).
+
+#![allow(unused_variables)]
+
+// Functions can be passed as arguments (see "func_in_arg()" in the lesson).
+// Call "function" on "value", then call it again on the result, and return what the second call returns.
+// "apply_twice(|x| x * 2, 3)" should return 12.
+pub fn apply_twice(function: fn(i32) -> i32, value: i32) -> i32 {
+    todo!()
+}
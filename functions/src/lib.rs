@@ -1,3 +1,7 @@
+// The exercises for this lesson (see "Exercises" in the README).
+#[cfg_attr(feature = "solution", path = "../solutions/exercises.rs")]
+pub mod exercises;
+
 // Functions are written in snake_case.
 // They are written in the form of:
 // <encapsulation> fn <function_name>(<arg1> , <arg2> , <etc...>) {
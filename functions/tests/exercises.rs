@@ -0,0 +1,22 @@
+// The tests of the exercises in "src/exercises.rs" (see "Exercises" in the README).
+
+mod apply_twice {
+    use functions::exercises::apply_twice;
+
+    fn add_three(x: i32) -> i32 {
+        x + 3
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    fn applies_a_named_function_twice() {
+        assert_eq!(apply_twice(add_three, 1), 7);
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    fn applies_an_inline_function_twice() {
+        assert_eq!(apply_twice(|x| x * 2, 3), 12);
+        assert_eq!(apply_twice(|x| x - 10, 0), -20);
+    }
+}
@@ -0,0 +1,23 @@
+// The reference solutions of "src/exercises.rs" (see "Exercises" in the README).
+
+use std::ops::{AddAssign, SubAssign};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GenericPoint2<T: Copy> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: AddAssign + Copy> AddAssign for GenericPoint2<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign + Copy> SubAssign for GenericPoint2<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
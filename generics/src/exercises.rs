@@ -0,0 +1,26 @@
+// The exercises of "generics", checked with "cargo run -p main -- check generics::sub_assign" (see "Exercises" in the README).
+
+#![allow(unused_variables)]
+
+use std::ops::{AddAssign, SubAssign};
+
+// This is "GenericPoint2" from the lesson, which can already be added to with "+=".
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GenericPoint2<T: Copy> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: AddAssign + Copy> AddAssign for GenericPoint2<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+// Make "-=" work too, for any "T" that can be subtracted from with "-=".
+impl<T: SubAssign + Copy> SubAssign for GenericPoint2<T> {
+    fn sub_assign(&mut self, other: Self) {
+        todo!()
+    }
+}
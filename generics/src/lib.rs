@@ -1,27 +1,40 @@
+// The exercises for this lesson (see "Exercises" in the README).
+#[cfg_attr(feature = "solution", path = "../solutions/exercises.rs")]
+pub mod exercises;
+
 // Rust Generics are similar in purpose to Templates in C++.
 // They both allow writing code that works with different data types, which avoids code duplication and improves reusability.
 
+use std::io::{self, Write};
+
 // Say, for example, a "Point" needs to be created that takes coordinates as integer values.
 struct Point {
     x: i32,
     y: i32
 }
 
-pub fn pre_generic_testing(){
+pub fn pre_generic_testing_to(out: &mut dyn Write) -> io::Result<()> {
     let p1 = Point {
         x: 10,
         y: 20
     };
-    println!("({} , {})" , p1.x , p1.y);
+    writeln!(out, "({} , {})" , p1.x , p1.y)?;
 
     // What if that point is to accept floats as well?
     // The following code block, as expected, will fail.
+    // compile_fail: E0308
     /*
     let _p2 = Point {
         x: 1.5,
         y: 2.25
     };
     */
+
+    Ok(())
+}
+
+pub fn pre_generic_testing() {
+    pre_generic_testing_to(&mut io::stdout()).expect("failed to write to stdout");
 }
 
 // Enter, Generics!
@@ -35,21 +48,25 @@ struct GenericPoint <T> {
 }
 
 // Since Now "x" and "y" are assigned to any data type, an "GenericPoint" can be initialized as an integer, a float, or any other data type.
-pub fn generic_testing() {
+pub fn generic_testing_to(out: &mut dyn Write) -> io::Result<()> {
     let p1: GenericPoint<i32> = GenericPoint { x: 10 , y: 20 };
-    println!("({} , {})" , p1.x , p1.y);
+    writeln!(out, "({} , {})" , p1.x , p1.y)?;
 
     let p2: GenericPoint<f32> = GenericPoint { x: 1.5 , y: 2.25 };
-    println!("({} , {})" , p2.x , p2.y);
+    writeln!(out, "({} , {})" , p2.x , p2.y)?;
 
     // But now, there is a problem!
     // Creating a point of type string slices will also work.
     // It shouldn't make sense because coordinates, logically speaking, should be numbers.
     // They shouldn't be strings.
     let p3: GenericPoint<&str> = GenericPoint { x: "Hello" , y: "World" };
-    println!("({} , {})" , p3.x , p3.y);
+    writeln!(out, "({} , {})" , p3.x , p3.y)?;
 
-    
+    Ok(())
+}
+
+pub fn generic_testing() {
+    generic_testing_to(&mut io::stdout()).expect("failed to write to stdout");
 }
 
 // This means the The struct "GenericPoint" is a point, yes!
@@ -60,6 +77,7 @@ impl <T> GenericPoint<T> {
     // The following function will result in a compilation error.
     // This happens because at compile time, the compiler does not know the type of "T".
     // if "T" is of type "&str" then the function will fail.
+    // compile_fail: E0368
     /*
     fn shift_point(&mut self , x: T , y: T){
         self.x += x;
@@ -130,20 +148,22 @@ impl <T: PartialEq + Copy> PartialEq for GenericPoint2<T> {
     }
 }
 
-pub fn generic_testing_after_fix() {
+pub fn generic_testing_after_fix_to(out: &mut dyn Write) -> io::Result<()> {
     let mut p1: GenericPoint2<i32> = GenericPoint2 { x: 10 , y: 20 };
     p1.shift_point_addassign(3, 15);
-    println!("({} , {})" , p1.x , p1.y);
+    writeln!(out, "({} , {})" , p1.x , p1.y)?;
 
     let mut p2: GenericPoint2<f32> = GenericPoint2 { x: 1.5 , y: 2.25 };
     p2.shift_point_add(2.24, 4.89);
-    println!("({} , {})" , p2.x , p2.y);
+    writeln!(out, "({} , {})" , p2.x , p2.y)?;
 
     // If a point is initialized as a "String", then the object creation will fail because "String" does not have "Copy".
+    // The methods cannot be called either, since they need "T: Copy" too.
+    // compile_fail: E0277, E0599
     /*
     let mut p3: GenericPoint2<String> = GenericPoint2 { x: "Hello".to_string() , y: "World".to_string() };
     p3.shift_point_add(2.24, 4.89);
-    println!("({} , {})" , p3.x , p3.y);
+    writeln!(out, "({} , {})" , p3.x , p3.y)?;
     */
 
     // To add 2 points together, now the "+=" can be used.
@@ -151,17 +171,22 @@ pub fn generic_testing_after_fix() {
     let p4: GenericPoint2<i32> = GenericPoint2 { x: 3 , y: 5 };
     p1 += p4; // Note that p4 here is now consumed and therefore using it again is obsolete.
               // It will need to implement "Clone" so that the clone is consumed and not the original point.
-    println!("({} , {})" , p1.x , p1.y);
+    writeln!(out, "({} , {})" , p1.x , p1.y)?;
 
     let p5: GenericPoint2<i32> = GenericPoint2 { x: 32 , y: 9 };
     let p6: GenericPoint2<i32> = GenericPoint2 { x: 15 , y: 11 };
     // Since the "PartialEq" is now implemented, 2 points can be checked if they are equal to each other.
     if p5 == p6 {
-        println!("P1 and P4 Are Equal!");
+        writeln!(out, "P1 and P4 Are Equal!")?;
     } else {
-        println!("P1 and P4 Are NOT Equal!");
+        writeln!(out, "P1 and P4 Are NOT Equal!")?;
     }
 
+    Ok(())
+}
+
+pub fn generic_testing_after_fix() {
+    generic_testing_after_fix_to(&mut io::stdout()).expect("failed to write to stdout");
 }
 
 
@@ -0,0 +1,33 @@
+// The tests of the exercises in "src/exercises.rs" (see "Exercises" in the README).
+
+mod sub_assign {
+    use generics::exercises::GenericPoint2;
+
+    #[test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    fn subtracts_integer_points() {
+        let mut point = GenericPoint2 { x: 10, y: 20 };
+        point -= GenericPoint2 { x: 3, y: 25 };
+        assert_eq!(point, GenericPoint2 { x: 7, y: -5 });
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    fn subtracts_float_points() {
+        let mut point = GenericPoint2 { x: 1.5, y: 2.25 };
+        point -= GenericPoint2 { x: 0.5, y: 0.25 };
+        assert_eq!(point, GenericPoint2 { x: 1.0, y: 2.0 });
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    fn subtracting_undoes_adding() {
+        let start = GenericPoint2 { x: 4, y: 9 };
+        let offset = GenericPoint2 { x: 32, y: -11 };
+
+        let mut point = start;
+        point += offset;
+        point -= offset;
+        assert_eq!(point, start);
+    }
+}
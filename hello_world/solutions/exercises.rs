@@ -0,0 +1,5 @@
+// The reference solutions of "src/exercises.rs" (see "Exercises" in the README).
+
+pub fn greeting(name: &str) -> String {
+    format!("hello, {}!!!!", name)
+}
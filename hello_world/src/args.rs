@@ -0,0 +1,198 @@
+// A small command-line argument parser that only uses the standard library.
+// Crates such as "clap" do much more, but parsing a few options by hand shows what such a crate does for you.
+// The options of a program are declared once, and the same declarations are used to parse the arguments and to print the help:
+//      let parser = Parser::new("hello_world", "Greets someone.")
+//          .option("name", 'n', "NAME", "Who to greet.")
+//          .flag("shout", 's', "Greet in capital letters.");
+// Options can be given as "--name Khan", "--name=Khan" or "-n Khan", and everything after "--" is a positional argument.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+struct Opt {
+    long: &'static str,
+    short: char,
+    // The name of the value in the help, such as "NAME", or None for a flag that takes no value.
+    value_name: Option<&'static str>,
+    help: &'static str,
+}
+
+pub struct Parser {
+    program: &'static str,
+    about: &'static str,
+    options: Vec<Opt>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    UnknownOption(String),
+    MissingValue(&'static str),
+    UnexpectedValue(&'static str),
+    GivenTwice(&'static str),
+    InvalidValue {
+        option: &'static str,
+        value: String,
+        reason: String,
+    },
+    UnexpectedArgument(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::UnknownOption(option) => write!(f, "unknown option \"{}\"", option),
+            Error::MissingValue(option) => write!(f, "\"--{}\" needs a value", option),
+            Error::UnexpectedValue(option) => write!(f, "\"--{}\" does not take a value", option),
+            Error::GivenTwice(option) => write!(f, "\"--{}\" was given more than once", option),
+            Error::InvalidValue { option, value, reason } => {
+                write!(f, "invalid value \"{}\" for \"--{}\": {}", value, option, reason)
+            }
+            Error::UnexpectedArgument(argument) => write!(f, "unexpected argument \"{}\"", argument),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+// The result of a successful parse.
+#[derive(Debug, Default)]
+pub struct Matches {
+    values: HashMap<&'static str, String>,
+    flags: Vec<&'static str>,
+    pub positional: Vec<String>,
+}
+
+impl Matches {
+    pub fn flag(&self, long: &str) -> bool {
+        self.flags.contains(&long)
+    }
+
+    pub fn value(&self, long: &str) -> Option<&str> {
+        self.values.get(long).map(String::as_str)
+    }
+
+    // Parses the value of an option into any type that implements "FromStr", such as a number.
+    pub fn parsed<T>(&self, long: &'static str) -> Result<Option<T>, Error>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        self.value(long)
+            .map(|value| {
+                value.parse().map_err(|error: T::Err| Error::InvalidValue {
+                    option: long,
+                    value: value.to_string(),
+                    reason: error.to_string(),
+                })
+            })
+            .transpose()
+    }
+}
+
+impl Parser {
+    pub fn new(program: &'static str, about: &'static str) -> Parser {
+        Parser {
+            program,
+            about,
+            options: Vec::new(),
+        }
+    }
+
+    // An option that takes a value, such as "--name Khan".
+    pub fn option(mut self, long: &'static str, short: char, value_name: &'static str, help: &'static str) -> Parser {
+        self.options.push(Opt {
+            long,
+            short,
+            value_name: Some(value_name),
+            help,
+        });
+        self
+    }
+
+    // An option that is either there or not, such as "--shout".
+    pub fn flag(mut self, long: &'static str, short: char, help: &'static str) -> Parser {
+        self.options.push(Opt {
+            long,
+            short,
+            value_name: None,
+            help,
+        });
+        self
+    }
+
+    fn find(&self, argument: &str) -> Option<&Opt> {
+        match argument.strip_prefix("--") {
+            Some(long) => self.options.iter().find(|opt| opt.long == long),
+            None => {
+                let mut chars = argument.strip_prefix('-')?.chars();
+                let short = chars.next()?;
+                if chars.next().is_some() {
+                    return None;
+                }
+                self.options.iter().find(|opt| opt.short == short)
+            }
+        }
+    }
+
+    // Parses the arguments, without the name of the program.
+    pub fn parse<S: AsRef<str>>(&self, args: &[S]) -> Result<Matches, Error> {
+        let mut matches = Matches::default();
+        let mut args = args.iter().map(AsRef::as_ref);
+
+        while let Some(argument) = args.next() {
+            if argument == "--" {
+                matches.positional.extend(args.by_ref().map(str::to_string));
+                break;
+            }
+            // A single "-" is usually a stand-in for stdin, so it counts as a positional argument.
+            if !argument.starts_with('-') || argument == "-" {
+                matches.positional.push(argument.to_string());
+                continue;
+            }
+
+            let (name, inline_value) = match argument.split_once('=') {
+                Some((name, value)) if argument.starts_with("--") => (name, Some(value)),
+                _ => (argument, None),
+            };
+            let opt = self.find(name).ok_or_else(|| Error::UnknownOption(name.to_string()))?;
+            let given = matches.flags.contains(&opt.long) || matches.values.contains_key(opt.long);
+            if given {
+                return Err(Error::GivenTwice(opt.long));
+            }
+
+            match (opt.value_name, inline_value) {
+                (None, None) => matches.flags.push(opt.long),
+                (None, Some(_)) => return Err(Error::UnexpectedValue(opt.long)),
+                (Some(_), Some(value)) => {
+                    matches.values.insert(opt.long, value.to_string());
+                }
+                (Some(_), None) => {
+                    // The next argument is the value, even if it starts with a "-", such as "--repeat -1".
+                    // A value that makes no sense is then rejected when it is parsed, which says more than "needs a value".
+                    let value = args.next().ok_or(Error::MissingValue(opt.long))?;
+                    matches.values.insert(opt.long, value.to_string());
+                }
+            }
+        }
+        Ok(matches)
+    }
+
+    // The help, with every option and its description lined up.
+    pub fn help(&self) -> String {
+        let mut help = format!("{}\n\nUsage: {} [options]\n\nOptions:\n", self.about, self.program);
+        let names: Vec<String> = self
+            .options
+            .iter()
+            .map(|opt| match opt.value_name {
+                Some(value_name) => format!("-{}, --{} <{}>", opt.short, opt.long, value_name),
+                None => format!("-{}, --{}", opt.short, opt.long),
+            })
+            .collect();
+        let width = names.iter().map(String::len).max().unwrap_or(0);
+        for (name, opt) in names.iter().zip(&self.options) {
+            help.push_str(&format!("    {:<width$}    {}\n", name, opt.help, width = width));
+        }
+        help
+    }
+}
@@ -0,0 +1,503 @@
+// "hello()" can only greet the world, in English.
+// This module grows it into a small library: a "Greeter" that greets in English, French, German or Arabic, by name, for the time of day, and a number of people.
+// The texts of every language live in "locales/<language>.txt", which are embedded into the crate with "include_str!" so the library needs no files at runtime.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{self, Write};
+
+// The embedded locale files, with the language tag of each one.
+const LOCALES: &[(&str, &str)] = &[
+    ("en", include_str!("../locales/en.txt")),
+    ("fr", include_str!("../locales/fr.txt")),
+    ("de", include_str!("../locales/de.txt")),
+    ("ar", include_str!("../locales/ar.txt")),
+];
+
+// Every greeting falls back to English when the other locales do not have it.
+const FALLBACK: &str = "en";
+
+// The tags of the locales that are built in.
+pub fn available_locales() -> Vec<&'static str> {
+    LOCALES.iter().map(|(tag, _)| *tag).collect()
+}
+
+// --------------------------------------------------------------------------------------------------------------------------- //
+// Time of day
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimeOfDay {
+    Morning,
+    Afternoon,
+    Evening,
+}
+
+impl TimeOfDay {
+    // The morning is from 5:00 until noon, the afternoon until 18:00, and the evening (and night) is the rest.
+    pub fn from_hour(hour: u8) -> TimeOfDay {
+        match hour % 24 {
+            5..=11 => TimeOfDay::Morning,
+            12..=17 => TimeOfDay::Afternoon,
+            _ => TimeOfDay::Evening,
+        }
+    }
+
+    // The name that is used in the keys of the locale files, such as "greeting.morning".
+    pub fn key(self) -> &'static str {
+        match self {
+            TimeOfDay::Morning => "morning",
+            TimeOfDay::Afternoon => "afternoon",
+            TimeOfDay::Evening => "evening",
+        }
+    }
+}
+
+// --------------------------------------------------------------------------------------------------------------------------- //
+// Plural rules
+
+// Languages do not agree on what is plural.
+// English has "1 person" and "2 people", French also says "0 personne", and Arabic has six different forms.
+// Each locale file lists the rules of its language, in a small version of the notation of the Unicode CLDR:
+//      "plural.one = n = 1"                    The count is exactly 1.
+//      "plural.one = n = 0, 1"                 The count is 0 or 1.
+//      "plural.few = n % 100 = 3..10"          The last two digits of the count are between 3 and 10.
+// Conditions can be combined with "and" and "or", where "and" binds tighter, and "!=" can be used instead of "=".
+// The rules are tried in the order zero, one, two, few and many, and a count that matches none of them is "other".
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Category {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl Category {
+    const ORDER: [Category; 5] = [Category::Zero, Category::One, Category::Two, Category::Few, Category::Many];
+
+    pub fn key(self) -> &'static str {
+        match self {
+            Category::Zero => "zero",
+            Category::One => "one",
+            Category::Two => "two",
+            Category::Few => "few",
+            Category::Many => "many",
+            Category::Other => "other",
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Category> {
+        Category::ORDER.into_iter().find(|category| category.key() == key)
+    }
+}
+
+// "n % modulo = ranges", or "!=" when "negated" is true.
+#[derive(Debug, Clone, PartialEq)]
+struct Condition {
+    modulo: Option<u64>,
+    negated: bool,
+    ranges: Vec<(u64, u64)>,
+}
+
+impl Condition {
+    fn parse(text: &str) -> Result<Condition, String> {
+        let (operand, ranges, negated) = match text.split_once("!=") {
+            Some((operand, ranges)) => (operand, ranges, true),
+            None => {
+                let (operand, ranges) = text
+                    .split_once('=')
+                    .ok_or_else(|| format!("\"{}\" needs \"=\" or \"!=\"", text.trim()))?;
+                (operand, ranges, false)
+            }
+        };
+
+        let modulo = match operand.trim().split_once('%') {
+            None if operand.trim() == "n" => None,
+            Some((n, modulo)) if n.trim() == "n" => match parse_number(modulo)? {
+                0 => return Err("\"n % 0\" cannot be computed".to_string()),
+                modulo => Some(modulo),
+            },
+            _ => return Err(format!("\"{}\" should be \"n\" or \"n % <number>\"", operand.trim())),
+        };
+
+        let ranges = ranges
+            .split(',')
+            .map(|range| match range.split_once("..") {
+                Some((start, end)) => Ok((parse_number(start)?, parse_number(end)?)),
+                None => parse_number(range).map(|number| (number, number)),
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        Ok(Condition { modulo, negated, ranges })
+    }
+
+    fn matches(&self, n: u64) -> bool {
+        let value = self.modulo.map_or(n, |modulo| n % modulo);
+        let in_ranges = self.ranges.iter().any(|&(start, end)| start <= value && value <= end);
+        in_ranges != self.negated
+    }
+}
+
+fn parse_number(text: &str) -> Result<u64, String> {
+    text.trim().parse().map_err(|_| format!("\"{}\" is not a number", text.trim()))
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PluralRules {
+    // Every rule is a list of alternatives ("or"), and every alternative is a list of conditions that must all hold ("and").
+    rules: Vec<(Category, Vec<Vec<Condition>>)>,
+}
+
+impl PluralRules {
+    // Adds the rule of a category, such as "n % 100 = 3..10" for "few".
+    pub fn add(&mut self, category: Category, rule: &str) -> Result<(), String> {
+        if category == Category::Other {
+            return Err("\"other\" is what is left, so it cannot have a rule".to_string());
+        }
+        let alternatives = rule
+            .split(" or ")
+            .map(|alternative| alternative.split(" and ").map(Condition::parse).collect())
+            .collect::<Result<Vec<Vec<Condition>>, String>>()?;
+        self.rules.retain(|(existing, _)| *existing != category);
+        self.rules.push((category, alternatives));
+        Ok(())
+    }
+
+    pub fn category(&self, n: u64) -> Category {
+        Category::ORDER
+            .into_iter()
+            .find(|category| {
+                self.rules.iter().any(|(rule_category, alternatives)| {
+                    rule_category == category
+                        && alternatives
+                            .iter()
+                            .any(|conditions| conditions.iter().all(|condition| condition.matches(n)))
+                })
+            })
+            .unwrap_or(Category::Other)
+    }
+}
+
+// --------------------------------------------------------------------------------------------------------------------------- //
+// Locales and templates
+
+#[derive(Debug)]
+pub struct Locale {
+    pub tag: &'static str,
+    texts: HashMap<String, String>,
+    plural: PluralRules,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl Locale {
+    // Reads a locale file: a "key = template" per line, with empty lines and "#" comments in between.
+    // The "plural.<category>" keys hold the plural rules of the language instead of a template.
+    pub fn parse(tag: &'static str, text: &str) -> Result<Locale, ParseError> {
+        let mut locale = Locale {
+            tag,
+            texts: HashMap::new(),
+            plural: PluralRules::default(),
+        };
+        for (index, line) in text.lines().enumerate() {
+            let error = |message: String| ParseError { line: index + 1, message };
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| error("expected \"key = template\"".to_string()))?;
+            let (key, value) = (key.trim(), value.trim());
+            if key.is_empty() {
+                return Err(error("the key is empty".to_string()));
+            }
+
+            match key.strip_prefix("plural.") {
+                Some(category) => {
+                    let category = Category::from_key(category)
+                        .ok_or_else(|| error(format!("unknown plural category \"{}\"", category)))?;
+                    locale.plural.add(category, value).map_err(error)?;
+                }
+                None => {
+                    locale.texts.insert(key.to_string(), value.to_string());
+                }
+            }
+        }
+        Ok(locale)
+    }
+
+    pub fn text(&self, key: &str) -> Option<&str> {
+        self.texts.get(key).map(String::as_str)
+    }
+}
+
+// Replaces every "{placeholder}" of the template with its value.
+// A placeholder without a value is left as it is, so a typo in a locale file shows up in the greeting instead of silently disappearing.
+pub fn render(template: &str, values: &[(&str, &str)]) -> String {
+    let mut rendered = String::new();
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        let Some(close) = rest[open..].find('}') else {
+            break;
+        };
+        rendered.push_str(&rest[..open]);
+        let placeholder = &rest[open + 1..open + close];
+        match values.iter().find(|(name, _)| *name == placeholder) {
+            Some((_, value)) => rendered.push_str(value),
+            None => rendered.push_str(&rest[open..=open + close]),
+        }
+        rest = &rest[open + close + 1..];
+    }
+    rendered.push_str(rest);
+    rendered
+}
+
+// --------------------------------------------------------------------------------------------------------------------------- //
+// Greeter
+
+pub struct Greeter {
+    // The locales to look a text up in, from the most specific to "en".
+    chain: Vec<Locale>,
+}
+
+impl Greeter {
+    // A tag such as "fr-CA" looks in "fr-CA", then in "fr", then in "en".
+    // Only the locales that are built in are kept, so an unknown tag greets in English.
+    pub fn new(tag: &str) -> Greeter {
+        let language = tag.split(['-', '_']).next().unwrap_or(tag);
+        let mut chain: Vec<Locale> = Vec::new();
+        for wanted in [tag, language, FALLBACK] {
+            let Some(&(tag, text)) = LOCALES.iter().find(|(tag, _)| tag.eq_ignore_ascii_case(wanted)) else {
+                continue;
+            };
+            if chain.iter().all(|locale| locale.tag != tag) {
+                chain.push(Locale::parse(tag, text).expect("the built in locales are valid, which is checked by the tests"));
+            }
+        }
+        Greeter { chain }
+    }
+
+    // The tags of the locales that are looked in, in order.
+    pub fn chain(&self) -> Vec<&'static str> {
+        self.chain.iter().map(|locale| locale.tag).collect()
+    }
+
+    // Looks in every locale of the chain, and within each locale tries the keys in order.
+    // A more specific key in a fallback locale loses to a less specific key in the wanted locale, so a French greeting is never partly English.
+    fn lookup(&self, keys: &[&str]) -> Option<(&Locale, &str)> {
+        self.chain
+            .iter()
+            .find_map(|locale| keys.iter().find_map(|key| locale.text(key)).map(|text| (locale, text)))
+    }
+
+    pub fn greet(&self, name: &str) -> String {
+        match self.lookup(&["greeting"]) {
+            Some((_, template)) => render(template, &[("name", name)]),
+            None => format!("hello, {}!", name),
+        }
+    }
+
+    // "greeting.<time of day>" is the greeting for a time of day, and "greeting.timed" is one template for every time of day, using "{time_of_day}".
+    pub fn greet_at(&self, name: &str, time: TimeOfDay) -> String {
+        let specific = format!("greeting.{}", time.key());
+        let Some((locale, template)) = self.lookup(&[&specific, "greeting.timed", "greeting"]) else {
+            return self.greet(name);
+        };
+        let time_key = format!("time_of_day.{}", time.key());
+        let time_of_day = locale.text(&time_key).unwrap_or(time.key());
+        render(template, &[("name", name), ("time_of_day", time_of_day)])
+    }
+
+    // "people.<category>" is the greeting for a count in that plural category, and "people.other" is used when the category has no text.
+    pub fn greet_people(&self, count: u64) -> String {
+        let count_text = count.to_string();
+        for locale in &self.chain {
+            let category = locale.plural.category(count);
+            let specific = format!("people.{}", category.key());
+            if let Some(template) = locale.text(&specific).or(locale.text("people.other")) {
+                return render(template, &[("count", &count_text)]);
+            }
+        }
+        format!("hello, {} people!", count)
+    }
+}
+
+// --------------------------------------------------------------------------------------------------------------------------- //
+// The lesson
+
+pub fn greeter_testing_to(out: &mut dyn Write) -> io::Result<()> {
+    // A "Greeter" is created for a language.
+    // "fr-CA" (French, as spoken in Canada) is not built in, so the greeter falls back to "fr", and then to "en".
+    let greeter = Greeter::new("fr-CA");
+    writeln!(out, "{:?}", greeter.chain())?;
+    writeln!(out, "{}", greeter.greet("Khan"))?;
+    writeln!(out, "{}", greeter.greet_at("Khan", TimeOfDay::Evening))?;
+
+    // The same calls give a different text in every language.
+    // German builds the greeting from a template, which fills in "{time_of_day}" with "Morgen", "Tag" or "Abend".
+    for tag in available_locales() {
+        let greeter = Greeter::new(tag);
+        writeln!(out, "{}: {}", tag, greeter.greet_at("Khan", TimeOfDay::from_hour(9)))?;
+    }
+
+    // Counting people needs the plural rules of the language.
+    // English and German only have "one" and "other", French treats 0 as singular, and Arabic has six forms.
+    for tag in ["en", "fr", "ar"] {
+        let greeter = Greeter::new(tag);
+        for count in [0, 1, 2, 5, 11, 100] {
+            writeln!(out, "{} {}: {}", tag, count, greeter.greet_people(count))?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn greeter_testing() {
+    greeter_testing_to(&mut io::stdout()).expect("failed to write to stdout");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_built_in_locale_parses() {
+        for (tag, text) in LOCALES {
+            let locale = Locale::parse(tag, text).unwrap_or_else(|error| panic!("{}: {}", tag, error));
+            assert!(locale.text("greeting").is_some(), "{} has no greeting", tag);
+            assert!(locale.text("people.other").is_some(), "{} has no people.other", tag);
+        }
+    }
+
+    #[test]
+    fn parse_errors_have_the_line() {
+        let error = Locale::parse("xx", "# a comment\ngreeting = hi\nno equals sign").unwrap_err();
+        assert_eq!(error, ParseError { line: 3, message: "expected \"key = template\"".to_string() });
+
+        let error = Locale::parse("xx", "plural.several = n = 3").unwrap_err();
+        assert_eq!(error.to_string(), "line 1: unknown plural category \"several\"");
+
+        let error = Locale::parse("xx", "\nplural.one = n % 0 = 1").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert!(Locale::parse("xx", "plural.one = m = 1").is_err());
+        assert!(Locale::parse("xx", "plural.one = n = one").is_err());
+    }
+
+    #[test]
+    fn templates_fill_in_their_placeholders() {
+        assert_eq!(render("hello, {name}!", &[("name", "Khan")]), "hello, Khan!");
+        assert_eq!(render("{a}{b}{a}", &[("a", "1"), ("b", "2")]), "121");
+        // Unknown and unclosed placeholders are kept.
+        assert_eq!(render("hi {nmae}", &[("name", "Khan")]), "hi {nmae}");
+        assert_eq!(render("hi {name", &[("name", "Khan")]), "hi {name");
+        assert_eq!(render("no placeholders", &[]), "no placeholders");
+    }
+
+    #[test]
+    fn times_of_day_cover_every_hour() {
+        assert_eq!(TimeOfDay::from_hour(4), TimeOfDay::Evening);
+        assert_eq!(TimeOfDay::from_hour(5), TimeOfDay::Morning);
+        assert_eq!(TimeOfDay::from_hour(11), TimeOfDay::Morning);
+        assert_eq!(TimeOfDay::from_hour(12), TimeOfDay::Afternoon);
+        assert_eq!(TimeOfDay::from_hour(18), TimeOfDay::Evening);
+        assert_eq!(TimeOfDay::from_hour(23), TimeOfDay::Evening);
+    }
+
+    #[test]
+    fn plural_rules_combine_conditions() {
+        let mut rules = PluralRules::default();
+        rules.add(Category::One, "n % 10 = 1 and n % 100 != 11").unwrap();
+        rules.add(Category::Few, "n = 2..4 or n = 22").unwrap();
+
+        assert_eq!(rules.category(1), Category::One);
+        assert_eq!(rules.category(21), Category::One);
+        assert_eq!(rules.category(11), Category::Other);
+        assert_eq!(rules.category(3), Category::Few);
+        assert_eq!(rules.category(22), Category::Few);
+        assert_eq!(rules.category(0), Category::Other);
+        assert!(rules.add(Category::Other, "n = 1").is_err());
+    }
+
+    #[test]
+    fn arabic_has_six_plural_forms() {
+        let greeter = Greeter::new("ar");
+        let categories: Vec<Category> = [0, 1, 2, 3, 10, 11, 99, 100, 102, 103, 111]
+            .into_iter()
+            .map(|count| greeter.chain[0].plural.category(count))
+            .collect();
+        assert_eq!(
+            categories,
+            [
+                Category::Zero,
+                Category::One,
+                Category::Two,
+                Category::Few,
+                Category::Few,
+                Category::Many,
+                Category::Many,
+                Category::Other,
+                Category::Other,
+                Category::Few,
+                Category::Many,
+            ]
+        );
+        assert_eq!(greeter.greet_people(2), "مرحبا بشخصين!");
+        assert_eq!(greeter.greet_people(5), "مرحبا بـ 5 أشخاص!");
+    }
+
+    #[test]
+    fn people_are_counted_in_every_language() {
+        assert_eq!(Greeter::new("en").greet_people(0), "hello, 0 people!");
+        assert_eq!(Greeter::new("en").greet_people(1), "hello, 1 person!");
+        assert_eq!(Greeter::new("fr").greet_people(0), "bonjour, 0 personne !");
+        assert_eq!(Greeter::new("fr").greet_people(2), "bonjour, 2 personnes !");
+        assert_eq!(Greeter::new("de").greet_people(1), "hallo, 1 Person!");
+        assert_eq!(Greeter::new("de").greet_people(7), "hallo, 7 Personen!");
+    }
+
+    #[test]
+    fn greetings_follow_the_time_of_day() {
+        let english = Greeter::new("en");
+        assert_eq!(english.greet("Khan"), "hello, Khan!");
+        assert_eq!(english.greet_at("Khan", TimeOfDay::Morning), "good morning, Khan!");
+
+        let german = Greeter::new("de");
+        assert_eq!(german.greet_at("Khan", TimeOfDay::Afternoon), "guten Tag, Khan!");
+        assert_eq!(german.greet_at("Khan", TimeOfDay::Evening), "guten Abend, Khan!");
+
+        let arabic = Greeter::new("ar");
+        assert_eq!(arabic.greet_at("خان", TimeOfDay::Morning), "صباح الخير يا خان!");
+        // Arabic has no afternoon greeting, so its plain greeting is used rather than the English one.
+        assert_eq!(arabic.greet_at("خان", TimeOfDay::Afternoon), "مرحبا يا خان!");
+    }
+
+    #[test]
+    fn missing_locales_and_keys_fall_back() {
+        assert_eq!(Greeter::new("fr-CA").chain(), ["fr", "en"]);
+        assert_eq!(Greeter::new("DE_at").chain(), ["de", "en"]);
+        assert_eq!(Greeter::new("xx").chain(), ["en"]);
+        assert_eq!(Greeter::new("xx").greet("Khan"), "hello, Khan!");
+
+        let french = Greeter::new("fr-CA");
+        assert_eq!(french.greet_at("Khan", TimeOfDay::Morning), "bonjour, Khan !");
+        assert_eq!(french.greet_at("Khan", TimeOfDay::Evening), "bonsoir, Khan !");
+
+        // A locale that only has some of the keys gets the others from English.
+        let mut chain = vec![Locale::parse("xx", "greeting = salut, {name}").unwrap()];
+        chain.extend(Greeter::new("en").chain);
+        let partial = Greeter { chain };
+        assert_eq!(partial.greet("Khan"), "salut, Khan");
+        assert_eq!(partial.greet_people(3), "hello, 3 people!");
+    }
+}
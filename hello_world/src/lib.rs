@@ -1,3 +1,21 @@
+// The exercises for this lesson (see "Exercises" in the README).
+#[cfg_attr(feature = "solution", path = "../solutions/exercises.rs")]
+pub mod exercises;
+
+// A greeting library that builds on "hello()", with several languages (see "greeter.rs").
+pub mod greeter;
+
+// The argument parser of the "hello_world" program in "main.rs", which other programs can use as well.
+pub mod args;
+
+use std::io::{self, Write};
+
+pub fn hello_to(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "hello, world!!!!")?;
+
+    Ok(())
+}
+
 pub fn hello() {
-    println!("hello, world!!!!");
+    hello_to(&mut io::stdout()).expect("failed to write to stdout");
 }
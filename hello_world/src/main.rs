@@ -0,0 +1,109 @@
+// The "hello_world" program, which greets from the command line:
+//      cargo run -p hello_world -- --name Khan --locale fr --repeat 2 --shout
+// It is meant as a template for other command-line programs: the options are parsed by "hello_world::args", every input is checked,
+// and the program exits with 0 on success, 2 when the arguments are wrong, and 1 when the greeting cannot be written.
+
+use std::io::{self, Write};
+use std::process::ExitCode;
+
+use hello_world::args::{Error, Parser};
+use hello_world::greeter::{self, Greeter};
+
+const MAX_REPEAT: u32 = 100;
+
+fn parser() -> Parser {
+    Parser::new("hello_world", "Greets someone, in English, French, German or Arabic.")
+        .option("name", 'n', "NAME", "Who to greet (default: world).")
+        .option("locale", 'l', "LOCALE", "The language to greet in, such as \"fr\" or \"fr-CA\" (default: en).")
+        .option("repeat", 'r', "COUNT", "How many times to greet, from 1 to 100 (default: 1).")
+        .flag("shout", 's', "Greet in capital letters.")
+        .flag("help", 'h', "Show this message.")
+}
+
+struct Options {
+    name: String,
+    locale: String,
+    repeat: u32,
+    shout: bool,
+}
+
+// Checks the values of the options, on top of what the parser already checked.
+fn options(args: &[String]) -> Result<Option<Options>, Error> {
+    let matches = parser().parse(args)?;
+    if let Some(argument) = matches.positional.first() {
+        return Err(Error::UnexpectedArgument(argument.clone()));
+    }
+    if matches.flag("help") {
+        return Ok(None);
+    }
+
+    let name = matches.value("name").unwrap_or("world");
+    if name.trim().is_empty() {
+        return Err(invalid("name", name, "the name cannot be empty"));
+    }
+
+    // A region such as "fr-CA" is fine as long as its language is built in.
+    let locale = matches.value("locale").unwrap_or("en");
+    let language = locale.split(['-', '_']).next().unwrap_or(locale);
+    let available = greeter::available_locales();
+    if !available.iter().any(|tag| tag.eq_ignore_ascii_case(language)) {
+        let reason = format!("expected one of {}", available.join(", "));
+        return Err(invalid("locale", locale, &reason));
+    }
+
+    let repeat = matches.parsed::<u32>("repeat")?.unwrap_or(1);
+    if !(1..=MAX_REPEAT).contains(&repeat) {
+        let reason = format!("expected a number from 1 to {}", MAX_REPEAT);
+        return Err(invalid("repeat", &repeat.to_string(), &reason));
+    }
+
+    Ok(Some(Options {
+        name: name.to_string(),
+        locale: locale.to_string(),
+        repeat,
+        shout: matches.flag("shout"),
+    }))
+}
+
+fn invalid(option: &'static str, value: &str, reason: &str) -> Error {
+    Error::InvalidValue {
+        option,
+        value: value.to_string(),
+        reason: reason.to_string(),
+    }
+}
+
+fn greet(options: &Options, out: &mut dyn Write) -> io::Result<()> {
+    let mut greeting = Greeter::new(&options.locale).greet(&options.name);
+    if options.shout {
+        greeting = greeting.to_uppercase();
+    }
+    for _ in 0..options.repeat {
+        writeln!(out, "{}", greeting)?;
+    }
+    out.flush()
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let options = match options(&args) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            print!("{}", parser().help());
+            return ExitCode::SUCCESS;
+        }
+        Err(error) => {
+            eprintln!("error: {}", error);
+            eprintln!("run \"hello_world --help\" to see the options");
+            return ExitCode::from(2);
+        }
+    };
+
+    match greet(&options, &mut io::stdout()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: failed to write the greeting: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
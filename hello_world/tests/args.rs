@@ -0,0 +1,80 @@
+// The tests of the argument parser in "src/args.rs".
+
+use hello_world::args::{Error, Parser};
+
+fn parser() -> Parser {
+    Parser::new("test", "A test program.")
+        .option("name", 'n', "NAME", "Who to greet.")
+        .option("repeat", 'r', "COUNT", "How many times.")
+        .flag("shout", 's', "Shout.")
+}
+
+#[test]
+fn options_can_be_long_short_or_inline() {
+    for args in [
+        ["--name", "Khan", "--shout"].as_slice(),
+        ["-n", "Khan", "-s"].as_slice(),
+        ["--name=Khan", "-s"].as_slice(),
+    ] {
+        let matches = parser().parse(args).unwrap();
+        assert_eq!(matches.value("name"), Some("Khan"), "{:?}", args);
+        assert!(matches.flag("shout"), "{:?}", args);
+        assert_eq!(matches.value("repeat"), None);
+    }
+    assert!(!parser().parse::<&str>(&[]).unwrap().flag("shout"));
+}
+
+#[test]
+fn values_are_parsed_into_any_type() {
+    let matches = parser().parse(&["--repeat", "3"]).unwrap();
+    assert_eq!(matches.parsed::<u32>("repeat"), Ok(Some(3)));
+    assert_eq!(matches.parsed::<u32>("name"), Ok(None));
+
+    let matches = parser().parse(&["--repeat", "three"]).unwrap();
+    let error = matches.parsed::<u32>("repeat").unwrap_err();
+    assert_eq!(error.to_string(), "invalid value \"three\" for \"--repeat\": invalid digit found in string");
+
+    // A value that looks like an option is still the value.
+    let matches = parser().parse(&["--repeat", "-1", "-s"]).unwrap();
+    assert_eq!(matches.value("repeat"), Some("-1"));
+    assert!(matches.flag("shout"));
+    let error = matches.parsed::<u32>("repeat").unwrap_err();
+    assert_eq!(error.to_string(), "invalid value \"-1\" for \"--repeat\": invalid digit found in string");
+    assert_eq!(parser().parse(&["-n", "--shout"]).unwrap().value("name"), Some("--shout"));
+}
+
+#[test]
+fn positional_arguments_are_kept() {
+    let matches = parser().parse(&["first", "-s", "-", "--", "--name", "-x"]).unwrap();
+    assert_eq!(matches.positional, ["first", "-", "--name", "-x"]);
+    assert!(matches.flag("shout"));
+    assert_eq!(matches.value("name"), None);
+}
+
+#[test]
+fn mistakes_are_reported() {
+    assert_eq!(parser().parse(&["--nmae", "Khan"]).unwrap_err(), Error::UnknownOption("--nmae".to_string()));
+    assert_eq!(parser().parse(&["-x"]).unwrap_err(), Error::UnknownOption("-x".to_string()));
+    assert_eq!(parser().parse(&["-sn"]).unwrap_err(), Error::UnknownOption("-sn".to_string()));
+    assert_eq!(parser().parse(&["--name"]).unwrap_err(), Error::MissingValue("name"));
+    assert_eq!(parser().parse(&["--shout=yes"]).unwrap_err(), Error::UnexpectedValue("shout"));
+    assert_eq!(parser().parse(&["-s", "--shout"]).unwrap_err(), Error::GivenTwice("shout"));
+    assert_eq!(parser().parse(&["-n", "a", "--name=b"]).unwrap_err(), Error::GivenTwice("name"));
+}
+
+#[test]
+fn help_lists_every_option() {
+    assert_eq!(
+        parser().help(),
+        "\
+A test program.
+
+Usage: test [options]
+
+Options:
+    -n, --name <NAME>       Who to greet.
+    -r, --repeat <COUNT>    How many times.
+    -s, --shout             Shout.
+"
+    );
+}
@@ -0,0 +1,74 @@
+// Runs the "hello_world" program and checks what it prints, and the code it exits with.
+
+use std::process::Command;
+
+struct Run {
+    code: i32,
+    stdout: String,
+    stderr: String,
+}
+
+fn hello_world(args: &[&str]) -> Run {
+    let output = Command::new(env!("CARGO_BIN_EXE_hello_world")).args(args).output().unwrap();
+    Run {
+        code: output.status.code().expect("the program was not stopped by a signal"),
+        stdout: String::from_utf8(output.stdout).unwrap(),
+        stderr: String::from_utf8(output.stderr).unwrap(),
+    }
+}
+
+#[test]
+fn greets_the_world_by_default() {
+    let run = hello_world(&[]);
+    assert_eq!((run.code, run.stdout.as_str(), run.stderr.as_str()), (0, "hello, world!\n", ""));
+}
+
+#[test]
+fn greets_by_name_in_a_locale() {
+    let run = hello_world(&["--name", "Khan", "--locale", "de"]);
+    assert_eq!((run.code, run.stdout.as_str()), (0, "hallo, Khan!\n"));
+
+    // "fr-CA" is not built in, so the French greeting is used.
+    let run = hello_world(&["-n", "Khan", "-l", "fr-CA"]);
+    assert_eq!(run.stdout, "bonjour, Khan !\n");
+}
+
+#[test]
+fn repeats_and_shouts() {
+    let run = hello_world(&["--name=Khan", "--repeat", "3", "--shout"]);
+    assert_eq!((run.code, run.stdout.as_str()), (0, "HELLO, KHAN!\nHELLO, KHAN!\nHELLO, KHAN!\n"));
+}
+
+#[test]
+fn help_goes_to_stdout() {
+    let run = hello_world(&["--help"]);
+    assert_eq!(run.code, 0);
+    assert!(run.stdout.starts_with("Greets someone"));
+    for option in ["--name <NAME>", "--locale <LOCALE>", "--repeat <COUNT>", "--shout", "--help"] {
+        assert!(run.stdout.contains(option), "the help does not mention {}", option);
+    }
+    assert_eq!(run.stderr, "");
+}
+
+// Every mistake exits with 2, prints nothing to stdout, and says what is wrong on stderr.
+#[test]
+fn wrong_arguments_exit_with_2() {
+    let cases: &[(&[&str], &str)] = &[
+        (&["--repeat", "0"], "error: invalid value \"0\" for \"--repeat\": expected a number from 1 to 100"),
+        (&["--repeat", "101"], "error: invalid value \"101\" for \"--repeat\": expected a number from 1 to 100"),
+        (&["--repeat", "-1"], "error: invalid value \"-1\" for \"--repeat\": invalid digit found in string"),
+        (&["--repeat", "many"], "error: invalid value \"many\" for \"--repeat\": invalid digit found in string"),
+        (&["--locale", "xx"], "error: invalid value \"xx\" for \"--locale\": expected one of en, fr, de, ar"),
+        (&["--name", " "], "error: invalid value \" \" for \"--name\": the name cannot be empty"),
+        (&["--name"], "error: \"--name\" needs a value"),
+        (&["--shout=loud"], "error: \"--shout\" does not take a value"),
+        (&["--loud"], "error: unknown option \"--loud\""),
+        (&["Khan"], "error: unexpected argument \"Khan\""),
+    ];
+    for (args, message) in cases {
+        let run = hello_world(args);
+        assert_eq!(run.code, 2, "{:?}", args);
+        assert_eq!(run.stdout, "", "{:?}", args);
+        assert_eq!(run.stderr, format!("{}\nrun \"hello_world --help\" to see the options\n", message), "{:?}", args);
+    }
+}
@@ -0,0 +1,17 @@
+// The tests of the exercises in "src/exercises.rs" (see "Exercises" in the README).
+
+mod greeting {
+    use hello_world::exercises::greeting;
+
+    #[test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    fn greets_by_name() {
+        assert_eq!(greeting("Khan"), "hello, Khan!!!!");
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    fn greets_an_empty_name() {
+        assert_eq!(greeting(""), "hello, !!!!");
+    }
+}
@@ -0,0 +1,9 @@
+// The reference solutions of "src/exercises.rs" (see "Exercises" in the README).
+
+pub fn sum_of_even_squares(values: &[i32]) -> i32 {
+    values
+        .iter()
+        .filter(|value| *value % 2 == 0)
+        .map(|value| value * value)
+        .sum()
+}
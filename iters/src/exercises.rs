@@ -0,0 +1,10 @@
+// The exercises of "iters", checked with "cargo run -p main -- check iters::sum_of_even_squares" (see "Exercises" in the README).
+
+#![allow(unused_variables)]
+
+// Square every even number in "values" and return the sum of the squares.
+// Try to do it with iterator functions ("iter()", "filter()", "map()" and "sum()") instead of a for loop.
+// "sum_of_even_squares(&[1, 2, 3, 4])" should return 2 * 2 + 4 * 4 = 20.
+pub fn sum_of_even_squares(values: &[i32]) -> i32 {
+    todo!()
+}
@@ -1,3 +1,9 @@
+// The exercises for this lesson (see "Exercises" in the README).
+#[cfg_attr(feature = "solution", path = "../solutions/exercises.rs")]
+pub mod exercises;
+
+use std::io::{self, Write};
+
 // Iterators are lists containing elements that can be consumed.
 // Iterators in RUST are lazy, meaning they need to be consumed to perform a certain task.
 // In RUST, iterators use the "iterator" trait (to be discussed in the traits session).
@@ -5,11 +11,11 @@
 // This means that the iterators do not need to be consumed manually in for loops
 // In vectors, the ".iter()" function gives an iterator over the vector, which can be consumed.
 // Note that the ".iter()" function works with references to the variables such as the "&i32" in the for loop.
-pub fn iterating_over_vector () {
+pub fn iterating_over_vector_to(out: &mut dyn Write) -> io::Result<()> {
     let values = vec![1 , 2 , 3 , 4 , 5];
     
     for value in values.iter(){
-        println!("{}" , value);
+        writeln!(out, "{}" , value)?;
     }
 
     // Some functions can be performed on the iterator itself.
@@ -20,6 +26,7 @@ pub fn iterating_over_vector () {
     // Now that the pointer of "iter" is at the end of the vector, the iterator is now consumed.
     // If sum or any other function for this iterator is called, a compile_time error will be thrown.
     // Another iterator will need to be created for the "values" vector.
+    // compile_fail: E0382
     /*
     let _sum2: i32 = iter.sum();
     */
@@ -30,12 +37,17 @@ pub fn iterating_over_vector () {
     // Leaving the map like that will not return a vector. 
     // To return a vector, the "collect()" function should be used.
     let values_updated: Vec<i32>= values.iter().map(|v| v * 2).collect();
-    println!("{:?}" , values_updated);
+    writeln!(out, "{:?}" , values_updated)?;
 
 
     // Using ".into_iter()" instead of ".iter()" will move the values themselves and will not work with references.
     // This moves the ownership from the vector entirely and the ".into_iter()" will own the collection.
     let values_updated: Vec<i32>= values.into_iter().map(|v| v * 2).collect();
-    println!("{:?}" , values_updated);
+    writeln!(out, "{:?}" , values_updated)?;
+
+    Ok(())
+}
 
+pub fn iterating_over_vector() {
+    iterating_over_vector_to(&mut io::stdout()).expect("failed to write to stdout");
 }
\ No newline at end of file
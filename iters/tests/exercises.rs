@@ -0,0 +1,19 @@
+// The tests of the exercises in "src/exercises.rs" (see "Exercises" in the README).
+
+mod sum_of_even_squares {
+    use iters::exercises::sum_of_even_squares;
+
+    #[test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    fn squares_only_the_even_numbers() {
+        assert_eq!(sum_of_even_squares(&[1, 2, 3, 4]), 20);
+        assert_eq!(sum_of_even_squares(&[-2, 5, 6]), 40);
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    fn no_even_numbers_sum_to_zero() {
+        assert_eq!(sum_of_even_squares(&[1, 3, 5]), 0);
+        assert_eq!(sum_of_even_squares(&[]), 0);
+    }
+}
@@ -0,0 +1,11 @@
+// The reference solutions of "src/exercises.rs" (see "Exercises" in the README).
+
+pub fn longest_of_three<'a>(x: &'a str, y: &'a str, z: &'a str) -> &'a str {
+    let mut longest = x;
+    for candidate in [y, z] {
+        if candidate.len() > longest.len() {
+            longest = candidate;
+        }
+    }
+    longest
+}
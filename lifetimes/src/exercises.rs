@@ -0,0 +1,10 @@
+// The exercises of "lifetimes", checked with "cargo run -p main -- check lifetimes::longest_of_three" (see "Exercises" in the README).
+
+#![allow(unused_variables)]
+
+// Return the longest of the three strings, like "longest()" in the lesson does for two.
+// If more than one string has the longest length, return the first of them.
+// All three inputs share the lifetime "'a", so the returned reference is valid for as long as all of them are.
+pub fn longest_of_three<'a>(x: &'a str, y: &'a str, z: &'a str) -> &'a str {
+    todo!()
+}
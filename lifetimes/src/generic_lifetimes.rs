@@ -3,10 +3,13 @@
 // Rust uses lifetimes to prevent dangling references—that is, references to data that has already been dropped.
 // Lifetimes are needed as they ensure that the data being referred to lives long enough and the data is not accidentally used after it's been freed.
 
+use std::io::{self, Write};
+
 // The following function gives a lifetime specifier error because no lifetime is specified.
 // This will also fail because the returned reference will outlive the string.
 // Remember that references are borrowed values.
 // They do not own the string.
+// compile_fail: E0106
 /*
 pub fn lifetime_error_1() -> &str {
     &"Khannnn"
@@ -20,6 +23,7 @@ pub fn lifetime() -> String {
 }
 
 // When the return type is changed to "&String", it then fails because the return type is a reference and not an actual instance.
+// compile_fail: E0106
 /*
 pub fn lifetime_error_3() -> &String {
     &"Khannnn".to_string()
@@ -57,7 +61,7 @@ fn _ret_y(y: &str) -> &str {
     &y
 }
 
-pub fn input_lifetime() {
+pub fn input_lifetime_to(out: &mut dyn Write) -> io::Result<()> {
     // The returned reference from the function "longest()" will be live only as long as the arguments live.
     // This is completely safe because rust assigns "&str" values by default to be "'static".
     // This means that the strings will actually outlive the references no matter what.
@@ -70,8 +74,9 @@ pub fn input_lifetime() {
     // Now try to print...
     // It'll immediately cause the line above to give a compilation error.
     // Why? This happens because the the strings, when declared within the parameters, will die right after the line finishes execution.
+    // compile_fail: E0716
     /*
-    println!("{}" , _longer_string);
+    writeln!(out, "{}" , _longer_string)?;
     */
 
     // To get around this, the strings need to be declared from before, like the following.
@@ -79,5 +84,11 @@ pub fn input_lifetime() {
     let x = "long_string_1";
     let y = "longer_string_2";
     let longer = longest(x, y);
-    println!("{}", longer);
+    writeln!(out, "{}", longer)?;
+
+    Ok(())
+}
+
+pub fn input_lifetime() {
+    input_lifetime_to(&mut io::stdout()).expect("failed to write to stdout");
 }
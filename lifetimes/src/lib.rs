@@ -1,3 +1,7 @@
 pub mod generic_lifetimes;
 pub mod lifetimes_in_structs;
-pub mod lifetime_elision_rules;
\ No newline at end of file
+pub mod lifetime_elision_rules;
+
+// The exercises for this lesson (see "Exercises" in the README).
+#[cfg_attr(feature = "solution", path = "../solutions/exercises.rs")]
+pub mod exercises;
@@ -1,6 +1,7 @@
 // Lifetimes need to be specified in Rust "struct"s.
 // Without these lifetimes, Rust will not know for how long should an attribute within the struct live.
 // The following struct gives an error because it does not specify a lifetime.
+// compile_fail: E0106
 /*
 struct Person{
     name: &str,
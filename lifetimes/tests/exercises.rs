@@ -0,0 +1,32 @@
+// The tests of the exercises in "src/exercises.rs" (see "Exercises" in the README).
+
+mod longest_of_three {
+    use lifetimes::exercises::longest_of_three;
+
+    #[test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    fn returns_the_longest_string() {
+        assert_eq!(longest_of_three("Khan", "Khalili", "Kh"), "Khalili");
+        assert_eq!(longest_of_three("a", "bb", "ccc"), "ccc");
+        assert_eq!(longest_of_three("ccc", "bb", "a"), "ccc");
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    fn ties_go_to_the_first_string() {
+        assert_eq!(longest_of_three("one", "two", "six"), "one");
+        assert_eq!(longest_of_three("a", "bcd", "efg"), "bcd");
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    fn works_with_strings_that_live_for_different_scopes() {
+        let outer = String::from("outer");
+        let result;
+        {
+            let inner = String::from("the inner string");
+            result = longest_of_three(&outer, &inner, "static").to_string();
+        }
+        assert_eq!(result, "the inner string");
+    }
+}
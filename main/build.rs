@@ -0,0 +1,58 @@
+// Embeds the source files of every lesson crate that is built into "main", for "main run --explain".
+// The sources are embedded, rather than read when a lesson is explained, so the explanation always matches the code that was compiled.
+// The crates are found from the features that are on, since every lesson crate has a feature with its own name (see Cargo.toml).
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+fn main() -> io::Result<()> {
+    let manifest_dir = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").expect("cargo sets CARGO_MANIFEST_DIR"));
+    let root = manifest_dir.parent().expect("the main crate lives inside the workspace");
+
+    let mut crates: Vec<String> = env::vars()
+        .filter_map(|(name, _)| name.strip_prefix("CARGO_FEATURE_").map(str::to_lowercase))
+        .filter(|crate_name| root.join(crate_name).join("src").is_dir())
+        .collect();
+    crates.sort();
+
+    let mut files = Vec::new();
+    for crate_name in &crates {
+        let src = root.join(crate_name).join("src");
+        println!("cargo:rerun-if-changed={}", src.display());
+        collect_rs_files(&src, &mut files)?;
+    }
+    files.sort();
+
+    let mut generated = String::from("pub static SOURCES: &[(&str, &str)] = &[\n");
+    for file in &files {
+        let relative: Vec<String> = file
+            .strip_prefix(root)
+            .expect("the lesson crates live inside the workspace")
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy().into_owned())
+            .collect();
+        generated.push_str(&format!(
+            "    ({:?}, include_str!({:?})),\n",
+            relative.join("/"),
+            file.display().to_string()
+        ));
+    }
+    generated.push_str("];\n");
+
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").expect("cargo sets OUT_DIR"));
+    fs::write(out_dir.join("sources.rs"), generated)
+}
+
+fn collect_rs_files(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_rs_files(&path, files)?;
+        } else if path.extension().is_some_and(|extension| extension == "rs") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,69 @@
+#![deny(clippy::all)]
+
+// Generates the lesson book from the source files of the lesson crates.
+// From the root folder, run:
+//      cargo run -p main --bin book -- [--format <html|markdown>] [--out <folder>]
+// The book is written to "target/book" unless another folder is given.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use main::book::{self, Format};
+use main::source;
+
+const USAGE: &str = "\
+Usage: book [--format <html|markdown>] [--out <folder>]
+
+Options:
+    --format <html|markdown>    The format of the pages, \"html\" by default.
+    --out <folder>              Where to write the book, \"target/book\" by default.";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "--help" || arg == "-h") {
+        println!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+
+    let (format, out_dir) = match parse(&args) {
+        Ok(parsed) => parsed,
+        Err(message) => {
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    match book::write_book(format, &out_dir) {
+        Ok(written) => {
+            println!("wrote {} file(s) to {}", written.len(), out_dir.display());
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("error: failed to write the book: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse(args: &[String]) -> Result<(Format, PathBuf), String> {
+    let mut format = Format::Html;
+    let mut out_dir = source::workspace_root().join("target").join("book");
+
+    let mut args = args.iter();
+    while let Some(flag) = args.next() {
+        let value = match flag.as_str() {
+            "--format" | "--out" => args.next().ok_or(format!("\"{}\" needs a value", flag))?,
+            other => return Err(format!("unexpected argument \"{}\"", other)),
+        };
+        if flag == "--format" {
+            format = match value.as_str() {
+                "html" => Format::Html,
+                "markdown" | "md" => Format::Markdown,
+                other => return Err(format!("unknown format \"{}\", expected html or markdown", other)),
+            };
+        } else {
+            out_dir = PathBuf::from(value);
+        }
+    }
+    Ok((format, out_dir))
+}
@@ -0,0 +1,536 @@
+// Renders the lesson crates as a book that can be read offline, with one page per module.
+// The teaching content of a lesson lives in the "//" comments between the code, so each source file is split into:
+//      Prose       A run of comment lines that starts at the beginning of a line, outside of any function.
+//      Code        Everything else, including the comments inside function bodies, which explain the code line by line.
+//      Broken      A "/* ... */" block that does not compile on purpose, together with its annotation (see "source.rs").
+// The pages are ordered by the curriculum, and the modules of a crate in the order of the "pub mod" lines of its "lib.rs".
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::curriculum::{self, Topic};
+use crate::source::{self, Expectation};
+
+#[derive(Debug, PartialEq)]
+pub enum Segment {
+    Prose(Vec<String>),
+    Code(String),
+    Broken { code: String, expectation: Expectation },
+}
+
+pub struct Page {
+    // The module path of the page, such as "lifetimes::generic_lifetimes", or just the crate name for a "lib.rs".
+    pub id: String,
+    pub topic: &'static Topic,
+    pub segments: Vec<Segment>,
+}
+
+impl Page {
+    // The file name of the page without an extension, written the same way as the snapshot files.
+    pub fn file_stem(&self) -> String {
+        self.id.replace("::", "__")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Html,
+    Markdown,
+}
+
+impl Format {
+    fn extension(self) -> &'static str {
+        match self {
+            Format::Html => "html",
+            Format::Markdown => "md",
+        }
+    }
+}
+
+pub fn segments(source: &str) -> Vec<Segment> {
+    let lines: Vec<&str> = source.lines().collect();
+    let blocks = source::broken_blocks(source);
+    let mut segments = Vec::new();
+    let mut prose: Vec<String> = Vec::new();
+    let mut code: Vec<&str> = Vec::new();
+    let mut index = 0;
+
+    while index < lines.len() {
+        let number = index + 1;
+
+        // The annotation of a broken block is shown as the title of its callout, so it is not part of the prose or code.
+        if let Some(block) = blocks.iter().find(|block| block.open_line == number + 1)
+            && block.expectation != Expectation::Missing
+        {
+            index += 1;
+            continue;
+        }
+
+        if let Some(block) = blocks.iter().find(|block| block.open_line == number) {
+            flush_prose(&mut prose, &mut segments);
+            flush_code(&mut code, &mut segments);
+            segments.push(Segment::Broken {
+                code: dedent(&lines[block.open_line..block.close_line - 1]),
+                expectation: block.expectation.clone(),
+            });
+            index = block.close_line;
+            continue;
+        }
+
+        let line = lines[index];
+        if let Some(text) = line.strip_prefix("//") {
+            flush_code(&mut code, &mut segments);
+            prose.push(text.strip_prefix(' ').unwrap_or(text).trim_end().to_string());
+        } else {
+            flush_prose(&mut prose, &mut segments);
+            code.push(line);
+        }
+        index += 1;
+    }
+
+    flush_prose(&mut prose, &mut segments);
+    flush_code(&mut code, &mut segments);
+    segments
+}
+
+fn flush_prose(prose: &mut Vec<String>, segments: &mut Vec<Segment>) {
+    if !prose.is_empty() {
+        segments.push(Segment::Prose(std::mem::take(prose)));
+    }
+}
+
+// Blank lines around a piece of code are dropped, and code that is only blank lines is dropped entirely.
+fn flush_code(code: &mut Vec<&str>, segments: &mut Vec<Segment>) {
+    let text = dedent(code);
+    code.clear();
+    if !text.is_empty() {
+        segments.push(Segment::Code(text));
+    }
+}
+
+// Removes the indentation that all the lines have in common, so that code from inside a function starts at the left edge.
+fn dedent(lines: &[&str]) -> String {
+    let indent = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    let text = lines
+        .iter()
+        .map(|line| line.get(indent..).unwrap_or("").trim_end())
+        .collect::<Vec<&str>>()
+        .join("\n");
+    text.trim_matches('\n').to_string()
+}
+
+// Reads every page of the book, in curriculum order.
+// A "lib.rs" that only declares modules has nothing to teach, so it does not get a page.
+pub fn pages() -> io::Result<Vec<Page>> {
+    let topics = curriculum::ordered(curriculum::TOPICS).map_err(io::Error::other)?;
+    let mut pages = Vec::new();
+
+    for topic in topics {
+        for (id, path) in module_files(topic.crate_name)? {
+            let source = fs::read_to_string(&path)?;
+            if id == topic.crate_name && only_declares_modules(&source) {
+                continue;
+            }
+            pages.push(Page {
+                id,
+                topic,
+                segments: segments(&source),
+            });
+        }
+    }
+    Ok(pages)
+}
+
+// Lists the "lib.rs" of a crate followed by its modules, in the order they are declared.
+// Any file that is not declared in "lib.rs" comes last, so that nothing is left out of the book.
+fn module_files(crate_name: &str) -> io::Result<Vec<(String, PathBuf)>> {
+    let src = source::workspace_root().join(crate_name).join("src");
+    let lib = src.join("lib.rs");
+    let mut files = vec![(crate_name.to_string(), lib.clone())];
+
+    for line in fs::read_to_string(&lib)?.lines() {
+        if let Some(name) = line.trim().strip_prefix("pub mod ").and_then(|rest| rest.strip_suffix(';')) {
+            files.push((format!("{}::{}", crate_name, name), src.join(format!("{}.rs", name))));
+        }
+    }
+
+    for path in source::lesson_files(crate_name)? {
+        if !files.iter().any(|(_, listed)| *listed == path) {
+            let module = path
+                .strip_prefix(&src)
+                .unwrap_or(&path)
+                .with_extension("")
+                .to_string_lossy()
+                .replace(std::path::MAIN_SEPARATOR, "::");
+            files.push((format!("{}::{}", crate_name, module), path));
+        }
+    }
+    Ok(files)
+}
+
+// Comments and attributes (such as the "path" of the exercises module) do not count as teaching either.
+fn only_declares_modules(source: &str) -> bool {
+    source.lines().map(str::trim).all(|line| {
+        line.is_empty()
+            || line.starts_with("//")
+            || line.starts_with("#[")
+            || (line.starts_with("mod ") || line.starts_with("pub mod ")) && line.ends_with(';')
+    })
+}
+
+// Writes the whole book into "out_dir" and returns the paths of the files that were written.
+pub fn write_book(format: Format, out_dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let pages = pages()?;
+    fs::create_dir_all(out_dir)?;
+    let mut written = Vec::new();
+
+    let mut write = |name: String, contents: String| -> io::Result<()> {
+        let path = out_dir.join(name);
+        fs::write(&path, contents)?;
+        written.push(path);
+        Ok(())
+    };
+
+    let index = match format {
+        Format::Html => html_index(&pages),
+        Format::Markdown => markdown_index(&pages),
+    };
+    write(format!("index.{}", format.extension()), index)?;
+    if format == Format::Html {
+        write("style.css".to_string(), STYLE.to_string())?;
+    }
+
+    for (position, page) in pages.iter().enumerate() {
+        let previous = position.checked_sub(1).map(|position| &pages[position]);
+        let next = pages.get(position + 1);
+        let contents = match format {
+            Format::Html => html_page(page, previous, next),
+            Format::Markdown => markdown_page(page, previous, next),
+        };
+        write(format!("{}.{}", page.file_stem(), format.extension()), contents)?;
+    }
+
+    Ok(written)
+}
+
+fn callout_title(expectation: &Expectation) -> String {
+    match expectation {
+        Expectation::CompileFail(codes) => format!("This does not compile: {}", codes.join(", ")),
+        Expectation::Snippet(reason) => format!("This is only a snippet: {}", reason),
+        Expectation::Missing => "This does not compile".to_string(),
+    }
+}
+
+// The table of contents groups the pages by topic, so the pages of a topic have to be next to each other.
+fn by_topic(pages: &[Page]) -> Vec<(&'static Topic, Vec<&Page>)> {
+    let mut groups: Vec<(&'static Topic, Vec<&Page>)> = Vec::new();
+    for page in pages {
+        match groups.last_mut() {
+            Some((topic, group)) if topic.crate_name == page.topic.crate_name => group.push(page),
+            _ => groups.push((page.topic, vec![page])),
+        }
+    }
+    groups
+}
+
+fn markdown_index(pages: &[Page]) -> String {
+    let mut text = String::from("# Rust Lessons\n\n");
+    for (number, (topic, group)) in by_topic(pages).iter().enumerate() {
+        text.push_str(&format!("{}. **{}**\n", number + 1, topic.title));
+        for page in group {
+            text.push_str(&format!("    - [{}]({}.md)\n", page.id, page.file_stem()));
+        }
+    }
+    text
+}
+
+fn markdown_page(page: &Page, previous: Option<&Page>, next: Option<&Page>) -> String {
+    let mut text = format!("# {}\n\n*{}*\n", page.id, page.topic.title);
+
+    for segment in &page.segments {
+        text.push('\n');
+        match segment {
+            Segment::Prose(lines) => {
+                for line in lines {
+                    // Every comment line is its own sentence, so each one keeps its own line.
+                    text.push_str(&format!("{}  \n", line));
+                }
+            }
+            Segment::Code(code) => text.push_str(&format!("```rust\n{}\n```\n", code)),
+            Segment::Broken { code, expectation } => {
+                text.push_str(&format!("> **{}**\n>\n", callout_title(expectation)));
+                text.push_str("> ```rust\n");
+                for line in code.lines() {
+                    text.push_str(&format!("> {}\n", line).replace("> \n", ">\n"));
+                }
+                text.push_str("> ```\n");
+            }
+        }
+    }
+
+    text.push_str("\n---\n\n");
+    let mut links = vec!["[Contents](index.md)".to_string()];
+    if let Some(previous) = previous {
+        links.insert(0, format!("[Previous: {}]({}.md)", previous.id, previous.file_stem()));
+    }
+    if let Some(next) = next {
+        links.push(format!("[Next: {}]({}.md)", next.id, next.file_stem()));
+    }
+    text.push_str(&links.join(" | "));
+    text.push('\n');
+    text
+}
+
+fn html_document(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n\
+         <link rel=\"stylesheet\" href=\"style.css\">\n</head>\n<body>\n<main>\n{}</main>\n</body>\n</html>\n",
+        escape(title),
+        body
+    )
+}
+
+fn html_index(pages: &[Page]) -> String {
+    let mut body = String::from("<h1>Rust Lessons</h1>\n<ol class=\"toc\">\n");
+    for (topic, group) in by_topic(pages) {
+        body.push_str(&format!("<li>{}\n<ul>\n", escape(topic.title)));
+        for page in group {
+            body.push_str(&format!(
+                "<li><a href=\"{}.html\">{}</a></li>\n",
+                page.file_stem(),
+                escape(&page.id)
+            ));
+        }
+        body.push_str("</ul>\n</li>\n");
+    }
+    body.push_str("</ol>\n");
+    html_document("Rust Lessons", &body)
+}
+
+fn html_page(page: &Page, previous: Option<&Page>, next: Option<&Page>) -> String {
+    let mut nav = String::from("<nav>\n");
+    if let Some(previous) = previous {
+        nav.push_str(&format!(
+            "<a href=\"{}.html\">&larr; {}</a>\n",
+            previous.file_stem(),
+            escape(&previous.id)
+        ));
+    }
+    nav.push_str("<a href=\"index.html\">Contents</a>\n");
+    if let Some(next) = next {
+        nav.push_str(&format!("<a href=\"{}.html\">{} &rarr;</a>\n", next.file_stem(), escape(&next.id)));
+    }
+    nav.push_str("</nav>\n");
+
+    let mut body = nav.clone();
+    body.push_str(&format!(
+        "<h1>{}</h1>\n<p class=\"topic\">{}</p>\n",
+        escape(&page.id),
+        escape(page.topic.title)
+    ));
+
+    for segment in &page.segments {
+        match segment {
+            Segment::Prose(lines) => {
+                for line in lines.iter().filter(|line| !line.is_empty()) {
+                    body.push_str(&format!("<p>{}</p>\n", inline_code(&escape(line))));
+                }
+            }
+            Segment::Code(code) => body.push_str(&format!("<pre><code>{}</code></pre>\n", highlight(code))),
+            Segment::Broken { code, expectation } => body.push_str(&format!(
+                "<div class=\"callout\">\n<p class=\"callout-title\">{}</p>\n<pre><code>{}</code></pre>\n</div>\n",
+                escape(&callout_title(expectation)),
+                highlight(code)
+            )),
+        }
+    }
+
+    body.push_str(&nav);
+    html_document(&page.id, &body)
+}
+
+// The lessons quote the names from the code in double quotes, such as "longest()", which are shown as code.
+// Quotes that span several words (such as a quoted sentence) are left as they are.
+fn inline_code(escaped: &str) -> String {
+    let parts: Vec<&str> = escaped.split("&quot;").collect();
+    if parts.len().is_multiple_of(2) {
+        return escaped.to_string();
+    }
+
+    let mut text = String::new();
+    for (index, part) in parts.iter().enumerate() {
+        if index.is_multiple_of(2) {
+            text.push_str(part);
+        } else if !part.is_empty() && !part.contains(' ') {
+            text.push_str(&format!("<code>{}</code>", part));
+        } else {
+            text.push_str(&format!("&quot;{}&quot;", part));
+        }
+    }
+    text
+}
+
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(character),
+        }
+    }
+    escaped
+}
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern", "false", "fn",
+    "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "self", "Self",
+    "static", "struct", "super", "trait", "true", "type", "unsafe", "use", "where", "while",
+];
+
+// A small syntax highlighter for the lesson code, which wraps each token in a "<span>" with a class:
+//      "comment", "string", "lifetime", "keyword", "number", "macro" or "type" (any name that starts with a capital letter).
+// It only needs to be good enough for the lessons, so it does not handle every corner of the Rust grammar.
+pub fn highlight(code: &str) -> String {
+    let chars: Vec<char> = code.chars().collect();
+    let mut html = String::new();
+    let mut index = 0;
+
+    let span = |html: &mut String, class: &str, text: &[char]| {
+        let text: String = text.iter().collect();
+        html.push_str(&format!("<span class=\"{}\">{}</span>", class, escape(&text)));
+    };
+
+    while index < chars.len() {
+        let character = chars[index];
+        let next = chars.get(index + 1).copied();
+        let start = index;
+
+        if character == '/' && next == Some('/') {
+            while index < chars.len() && chars[index] != '\n' {
+                index += 1;
+            }
+            span(&mut html, "comment", &chars[start..index]);
+        } else if character == '/' && next == Some('*') {
+            index += 2;
+            while index < chars.len() && !(chars[index] == '*' && chars.get(index + 1) == Some(&'/')) {
+                index += 1;
+            }
+            index = (index + 2).min(chars.len());
+            span(&mut html, "comment", &chars[start..index]);
+        } else if character == '"' || (character == 'r' && matches!(next, Some('"') | Some('#'))) {
+            index = end_of_string(&chars, index);
+            span(&mut html, "string", &chars[start..index]);
+        } else if character == '\'' {
+            // "'a'" and "'\n'" are characters, while "'a" is a lifetime.
+            if next == Some('\\') || chars.get(index + 2) == Some(&'\'') {
+                index += 2;
+                while index < chars.len() && chars[index] != '\'' {
+                    index += 1;
+                }
+                index = (index + 1).min(chars.len());
+                span(&mut html, "string", &chars[start..index]);
+            } else {
+                index += 1;
+                while index < chars.len() && is_identifier(chars[index]) {
+                    index += 1;
+                }
+                span(&mut html, "lifetime", &chars[start..index]);
+            }
+        } else if character.is_ascii_digit() {
+            while index < chars.len()
+                && (is_identifier(chars[index])
+                    || chars[index] == '.' && chars.get(index + 1).is_some_and(|next| next.is_ascii_digit()))
+            {
+                index += 1;
+            }
+            span(&mut html, "number", &chars[start..index]);
+        } else if is_identifier(character) {
+            while index < chars.len() && is_identifier(chars[index]) {
+                index += 1;
+            }
+            let word: String = chars[start..index].iter().collect();
+            if chars.get(index) == Some(&'!') && chars.get(index + 1) != Some(&'=') {
+                index += 1;
+                span(&mut html, "macro", &chars[start..index]);
+            } else if KEYWORDS.contains(&word.as_str()) {
+                span(&mut html, "keyword", &chars[start..index]);
+            } else if character.is_uppercase() {
+                span(&mut html, "type", &chars[start..index]);
+            } else {
+                html.push_str(&word);
+            }
+        } else {
+            html.push_str(&escape(&character.to_string()));
+            index += 1;
+        }
+    }
+    html
+}
+
+fn is_identifier(character: char) -> bool {
+    character.is_alphanumeric() || character == '_'
+}
+
+// Returns the index right after the string that starts at "start", which can be a normal string or a raw string (r"..." or r#"..."#).
+fn end_of_string(chars: &[char], start: usize) -> usize {
+    let mut index = start;
+    if chars[index] == 'r' {
+        index += 1;
+        let mut hashes = 0;
+        while chars.get(index) == Some(&'#') {
+            hashes += 1;
+            index += 1;
+        }
+        if chars.get(index) != Some(&'"') {
+            // Not a raw string after all, such as the identifier "r" followed by something else.
+            return start + 1;
+        }
+        index += 1;
+        while index < chars.len() {
+            if chars[index] == '"' && (1..=hashes).all(|offset| chars.get(index + offset) == Some(&'#')) {
+                return index + 1 + hashes;
+            }
+            index += 1;
+        }
+        return chars.len();
+    }
+
+    index += 1;
+    while index < chars.len() {
+        match chars[index] {
+            '\\' => index += 2,
+            '"' => return index + 1,
+            _ => index += 1,
+        }
+    }
+    chars.len()
+}
+
+const STYLE: &str = "\
+body { font-family: sans-serif; line-height: 1.5; color: #222; background: #fdfdfd; }
+main { max-width: 50rem; margin: 0 auto; padding: 1rem; }
+nav { display: flex; justify-content: space-between; margin: 1rem 0; }
+.topic { color: #666; margin-top: -0.5rem; }
+pre { background: #f4f4f4; padding: 0.75rem; overflow-x: auto; border-radius: 4px; }
+p code { background: #f4f4f4; padding: 0 0.2rem; }
+.callout { border-left: 4px solid #c0392b; background: #fbeeee; padding: 0.25rem 0.75rem; margin: 1rem 0; }
+.callout-title { font-weight: bold; color: #c0392b; }
+.callout pre { background: #fff6f6; }
+.comment { color: #6a737d; font-style: italic; }
+.string { color: #22863a; }
+.lifetime { color: #b08800; }
+.keyword { color: #d73a49; font-weight: bold; }
+.number { color: #005cc5; }
+.macro { color: #6f42c1; }
+.type { color: #e36209; }
+";
@@ -0,0 +1,220 @@
+// The command line of the lesson runner is parsed by hand, without any third-party crate.
+// The first argument is the subcommand, and the rest of the arguments belong to that subcommand.
+
+pub const USAGE: &str = "\
+Usage: main <command>
+
+Commands:
+    list                    List the id of every lesson.
+    run <lesson>...         Run one or more lessons by id, such as \"enums::shapes::enum_testing\".
+    run --crate <name>      Run every lesson in a crate, such as \"ownership\".
+    run-all                 Run every lesson in the workspace.
+                            \"run\" and \"run-all\" take \"--format json\" to print the results as JSON records.
+                            \"run\" also takes \"--explain\" to show the code of each lesson with the output of every line.
+    curriculum [--format <text|dot|json>]
+                            Show the topics in the order they should be learned, or export the prerequisite graph.
+    progress                Show how many lessons of each crate have been run.
+    next                    Suggest the next lesson to run.
+    reset                   Forget which lessons have been run.
+    check [<exercise>]      Check an exercise, such as \"enums::triangle_area\", or show which exercises are solved.
+    watch <crate>           Run the lessons of a crate again every time its source files change, and show what changed.
+    quiz <crate> [--answers <file>]
+                            Answer the questions about a crate, or read the answers from a file, one per line.
+    playground [<file>] [--timeout <seconds>]
+                            Compile and run a snippet from a file (or stdin) against the lesson crates.
+    diagnose [<file>]       Read rustc's JSON diagnostics from a file (or stdin), and show the lessons that explain them.
+    simulate [<file>]       Step through a tiny program from a file (or stdin, one statement at a time) with a simulated borrow checker.
+    help                    Show this message.";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    List,
+    Run(Vec<String>, OutputFormat),
+    RunCrate(String, OutputFormat),
+    RunAll(OutputFormat),
+    Curriculum(CurriculumFormat),
+    Progress,
+    Next,
+    Reset,
+    Check(Option<String>),
+    Watch(String),
+    Quiz {
+        crate_name: String,
+        answers: Option<String>,
+    },
+    // Without a file, the snippet is read from stdin.
+    Playground {
+        file: Option<String>,
+        timeout_secs: Option<u64>,
+    },
+    // Without a file, the diagnostics are read from stdin.
+    Diagnose(Option<String>),
+    // Without a file, the statements are read from stdin as they are typed.
+    Simulate(Option<String>),
+    Help,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+    // The code and comments of each lesson, with the output under the line that wrote it.
+    Explain,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CurriculumFormat {
+    Text,
+    Dot,
+    Json,
+}
+
+pub fn parse(args: &[String]) -> Result<Command, String> {
+    let Some((command, rest)) = args.split_first() else {
+        return Ok(Command::Help);
+    };
+
+    match command.as_str() {
+        "list" => {
+            no_extra_arguments("list", rest)?;
+            Ok(Command::List)
+        }
+        "run" => parse_run(rest),
+        "run-all" => {
+            let (format, rest) = take_output_format(rest)?;
+            no_extra_arguments("run-all", &rest)?;
+            Ok(Command::RunAll(format))
+        }
+        "curriculum" => parse_curriculum(rest),
+        "progress" => {
+            no_extra_arguments("progress", rest)?;
+            Ok(Command::Progress)
+        }
+        "next" => {
+            no_extra_arguments("next", rest)?;
+            Ok(Command::Next)
+        }
+        "reset" => {
+            no_extra_arguments("reset", rest)?;
+            Ok(Command::Reset)
+        }
+        "check" => match rest {
+            [] => Ok(Command::Check(None)),
+            [id] if !id.starts_with("--") => Ok(Command::Check(Some(id.clone()))),
+            _ => Err("\"check\" takes at most one exercise id".to_string()),
+        },
+        "watch" => match rest {
+            [crate_name] if !crate_name.starts_with("--") => Ok(Command::Watch(crate_name.clone())),
+            _ => Err("\"watch\" needs exactly one crate name".to_string()),
+        },
+        "quiz" => parse_quiz(rest),
+        "playground" => parse_playground(rest),
+        "diagnose" => match rest {
+            [] => Ok(Command::Diagnose(None)),
+            [file] if !file.starts_with("--") => Ok(Command::Diagnose(Some(file.clone()))),
+            _ => Err("\"diagnose\" takes at most one file".to_string()),
+        },
+        "simulate" => match rest {
+            [] => Ok(Command::Simulate(None)),
+            [file] if !file.starts_with("--") => Ok(Command::Simulate(Some(file.clone()))),
+            _ => Err("\"simulate\" takes at most one file".to_string()),
+        },
+        "help" | "--help" | "-h" => Ok(Command::Help),
+        other => Err(format!("unknown command \"{}\"", other)),
+    }
+}
+
+fn parse_run(args: &[String]) -> Result<Command, String> {
+    let (format, args) = take_output_format(args)?;
+    match args.as_slice() {
+        [] => Err("\"run\" needs at least one lesson id or \"--crate <name>\"".to_string()),
+        [flag] if flag == "--crate" => Err("\"--crate\" needs a crate name".to_string()),
+        [flag, name] if flag == "--crate" => Ok(Command::RunCrate(name.clone(), format)),
+        [flag, ..] if flag == "--crate" => Err("\"--crate\" takes exactly one crate name".to_string()),
+        ids => {
+            if let Some(flag) = ids.iter().find(|id| id.starts_with("--")) {
+                return Err(format!("unexpected flag \"{}\"", flag));
+            }
+            Ok(Command::Run(ids.to_vec(), format))
+        }
+    }
+}
+
+// "--format <text|json>" (or "--explain") can be given anywhere after "run" or "run-all", and the other arguments are returned without it.
+fn take_output_format(args: &[String]) -> Result<(OutputFormat, Vec<String>), String> {
+    if let Some(at) = args.iter().position(|arg| arg == "--explain") {
+        if args.iter().any(|arg| arg == "--format") {
+            return Err("\"--explain\" cannot be used together with \"--format\"".to_string());
+        }
+        let mut rest = args.to_vec();
+        rest.remove(at);
+        return Ok((OutputFormat::Explain, rest));
+    }
+    let Some(at) = args.iter().position(|arg| arg == "--format") else {
+        return Ok((OutputFormat::Text, args.to_vec()));
+    };
+    let format = match args.get(at + 1).map(String::as_str) {
+        Some("text") => OutputFormat::Text,
+        Some("json") => OutputFormat::Json,
+        Some(other) => return Err(format!("unknown format \"{}\", expected text or json", other)),
+        None => return Err("\"--format\" needs a format, text or json".to_string()),
+    };
+    let mut rest = args.to_vec();
+    rest.drain(at..at + 2);
+    Ok((format, rest))
+}
+
+fn parse_curriculum(args: &[String]) -> Result<Command, String> {
+    let format = match args {
+        [] => CurriculumFormat::Text,
+        [flag, format] if flag == "--format" => match format.as_str() {
+            "text" => CurriculumFormat::Text,
+            "dot" => CurriculumFormat::Dot,
+            "json" => CurriculumFormat::Json,
+            other => return Err(format!("unknown format \"{}\", expected text, dot or json", other)),
+        },
+        _ => return Err("\"curriculum\" only takes \"--format <text|dot|json>\"".to_string()),
+    };
+    Ok(Command::Curriculum(format))
+}
+
+fn parse_quiz(args: &[String]) -> Result<Command, String> {
+    let (crate_name, answers) = match args {
+        [crate_name] if !crate_name.starts_with("--") => (crate_name, None),
+        [crate_name, flag, file] if !crate_name.starts_with("--") && flag == "--answers" => (crate_name, Some(file.clone())),
+        _ => return Err("\"quiz\" needs a crate name, and optionally \"--answers <file>\"".to_string()),
+    };
+    Ok(Command::Quiz {
+        crate_name: crate_name.clone(),
+        answers,
+    })
+}
+
+fn parse_playground(args: &[String]) -> Result<Command, String> {
+    let mut args = args.to_vec();
+    let timeout_secs = match args.iter().position(|arg| arg == "--timeout") {
+        Some(at) => {
+            let seconds = match args.get(at + 1).map(|seconds| seconds.parse::<u64>()) {
+                Some(Ok(seconds)) if seconds > 0 => seconds,
+                Some(_) => return Err("\"--timeout\" needs a whole number of seconds, greater than 0".to_string()),
+                None => return Err("\"--timeout\" needs a number of seconds".to_string()),
+            };
+            args.drain(at..at + 2);
+            Some(seconds)
+        }
+        None => None,
+    };
+    let file = match args.as_slice() {
+        [] => None,
+        [file] if !file.starts_with("--") => Some(file.clone()),
+        _ => return Err("\"playground\" takes at most one file, and optionally \"--timeout <seconds>\"".to_string()),
+    };
+    Ok(Command::Playground { file, timeout_secs })
+}
+
+fn no_extra_arguments(command: &str, rest: &[String]) -> Result<(), String> {
+    match rest.first() {
+        Some(extra) => Err(format!("\"{}\" does not take an argument, got \"{}\"", command, extra)),
+        None => Ok(()),
+    }
+}
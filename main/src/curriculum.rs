@@ -0,0 +1,284 @@
+// The curriculum describes the order in which the lesson crates are meant to be learned.
+// Each lesson crate is a "Topic", with a title, tags, and the topics that should be learned before it (its prerequisites).
+// The lessons often say that something "will be discussed in a later module", and the prerequisites are where that is written down.
+
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct Topic {
+    #[serde(rename = "crate")]
+    pub crate_name: &'static str,
+    pub title: &'static str,
+    // The order in which the topic appears in the workspace, used to break ties between topics that are equally ready.
+    pub order: u32,
+    pub tags: &'static [&'static str],
+    pub prerequisites: &'static [&'static str],
+}
+
+pub static TOPICS: &[Topic] = &[
+    Topic {
+        crate_name: "hello_world",
+        title: "Hello, World",
+        order: 1,
+        tags: &["basics"],
+        prerequisites: &[],
+    },
+    Topic {
+        crate_name: "variables",
+        title: "Variables, Strings and Constants",
+        order: 2,
+        tags: &["basics"],
+        prerequisites: &["hello_world"],
+    },
+    Topic {
+        crate_name: "ownership",
+        title: "Ownership and Borrowing",
+        order: 3,
+        tags: &["basics", "memory"],
+        prerequisites: &["variables"],
+    },
+    Topic {
+        crate_name: "functions",
+        title: "Functions and Closures",
+        order: 4,
+        tags: &["basics"],
+        prerequisites: &["variables"],
+    },
+    Topic {
+        crate_name: "structs",
+        title: "Structs",
+        order: 5,
+        tags: &["types"],
+        prerequisites: &["functions", "ownership"],
+    },
+    Topic {
+        crate_name: "enums",
+        title: "Enums and Matching",
+        order: 6,
+        tags: &["types"],
+        prerequisites: &["structs"],
+    },
+    Topic {
+        crate_name: "collections",
+        title: "Tuples, Vectors and HashMaps",
+        order: 7,
+        tags: &["collections"],
+        prerequisites: &["ownership", "structs"],
+    },
+    Topic {
+        crate_name: "iters",
+        title: "Iterators",
+        order: 8,
+        tags: &["collections"],
+        prerequisites: &["collections", "functions"],
+    },
+    Topic {
+        crate_name: "optionals",
+        title: "Optionals",
+        order: 9,
+        tags: &["types", "error-handling"],
+        prerequisites: &["enums"],
+    },
+    Topic {
+        crate_name: "error_handling",
+        title: "Error Handling with Result",
+        order: 10,
+        tags: &["error-handling"],
+        prerequisites: &["optionals", "traits"],
+    },
+    Topic {
+        crate_name: "lifetimes",
+        title: "Lifetimes",
+        order: 11,
+        tags: &["memory"],
+        prerequisites: &["ownership", "structs", "enums"],
+    },
+    Topic {
+        crate_name: "traits",
+        title: "Traits",
+        order: 12,
+        tags: &["types", "traits"],
+        prerequisites: &["structs", "enums"],
+    },
+    Topic {
+        crate_name: "pointers",
+        title: "Smart Pointers",
+        order: 13,
+        tags: &["memory", "traits"],
+        prerequisites: &["ownership", "traits", "optionals"],
+    },
+    Topic {
+        crate_name: "generics",
+        title: "Generics",
+        order: 14,
+        tags: &["types", "traits"],
+        prerequisites: &["traits"],
+    },
+    Topic {
+        crate_name: "async_rust",
+        title: "Async Rust",
+        order: 15,
+        tags: &["async"],
+        prerequisites: &["traits", "structs", "functions"],
+    },
+];
+
+#[derive(Debug, PartialEq)]
+pub enum CurriculumError {
+    UnknownPrerequisite {
+        topic: &'static str,
+        prerequisite: &'static str,
+    },
+    // The topics that form the cycle, starting and ending with the same topic.
+    Cycle(Vec<&'static str>),
+}
+
+impl fmt::Display for CurriculumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CurriculumError::UnknownPrerequisite { topic, prerequisite } => {
+                write!(f, "\"{}\" requires \"{}\", which is not a topic", topic, prerequisite)
+            }
+            CurriculumError::Cycle(cycle) => write!(f, "the prerequisites form a cycle: {}", cycle.join(" -> ")),
+        }
+    }
+}
+
+impl std::error::Error for CurriculumError {}
+
+pub fn find(crate_name: &str) -> Option<&'static Topic> {
+    TOPICS.iter().find(|topic| topic.crate_name == crate_name)
+}
+
+// Orders the topics so that every topic comes after all of its prerequisites.
+// Among the topics whose prerequisites are all done, the one with the lowest "order" comes first, so the result is always the same.
+pub fn ordered(topics: &[Topic]) -> Result<Vec<&Topic>, CurriculumError> {
+    let by_name: HashMap<&str, &Topic> = topics.iter().map(|topic| (topic.crate_name, topic)).collect();
+    for topic in topics {
+        if let Some(prerequisite) = topic.prerequisites.iter().find(|name| !by_name.contains_key(*name)) {
+            return Err(CurriculumError::UnknownPrerequisite {
+                topic: topic.crate_name,
+                prerequisite,
+            });
+        }
+    }
+
+    let mut remaining: HashMap<&str, usize> = topics
+        .iter()
+        .map(|topic| (topic.crate_name, topic.prerequisites.len()))
+        .collect();
+    let mut ready: BTreeSet<(u32, &str)> = topics
+        .iter()
+        .filter(|topic| topic.prerequisites.is_empty())
+        .map(|topic| (topic.order, topic.crate_name))
+        .collect();
+
+    let mut result = Vec::new();
+    while let Some((_, name)) = ready.pop_first() {
+        result.push(by_name[name]);
+        for topic in topics.iter().filter(|topic| topic.prerequisites.contains(&name)) {
+            let count = remaining.get_mut(topic.crate_name).unwrap();
+            *count -= 1;
+            if *count == 0 {
+                ready.insert((topic.order, topic.crate_name));
+            }
+        }
+    }
+
+    if result.len() < topics.len() {
+        return Err(CurriculumError::Cycle(find_cycle(topics, &by_name)));
+    }
+    Ok(result)
+}
+
+// Walks the prerequisites depth-first until a topic is reached that is already on the current path.
+fn find_cycle(topics: &[Topic], by_name: &HashMap<&str, &Topic>) -> Vec<&'static str> {
+    fn visit(
+        topic: &Topic,
+        by_name: &HashMap<&str, &Topic>,
+        path: &mut Vec<&'static str>,
+        done: &mut BTreeSet<&'static str>,
+    ) -> Option<Vec<&'static str>> {
+        if let Some(start) = path.iter().position(|name| *name == topic.crate_name) {
+            let mut cycle = path[start..].to_vec();
+            cycle.push(topic.crate_name);
+            return Some(cycle);
+        }
+        if done.contains(topic.crate_name) {
+            return None;
+        }
+
+        path.push(topic.crate_name);
+        for prerequisite in topic.prerequisites {
+            if let Some(cycle) = visit(by_name[prerequisite], by_name, path, done) {
+                return Some(cycle);
+            }
+        }
+        path.pop();
+        done.insert(topic.crate_name);
+        None
+    }
+
+    let mut done = BTreeSet::new();
+    topics
+        .iter()
+        .find_map(|topic| visit(topic, by_name, &mut Vec::new(), &mut done))
+        .unwrap_or_default()
+}
+
+// Exports the prerequisite graph in the Graphviz DOT format, with an arrow from each prerequisite to the topic that needs it.
+// It can be rendered with: "main curriculum --format dot | dot -Tsvg > curriculum.svg".
+pub fn to_dot(topics: &[Topic]) -> String {
+    let mut dot = String::from("digraph curriculum {\n    rankdir=LR;\n    node [shape=box];\n\n");
+    for topic in topics {
+        dot.push_str(&format!(
+            "    {} [label=\"{}. {}\"];\n",
+            topic.crate_name,
+            topic.order,
+            topic.title.replace('"', "\\\"")
+        ));
+    }
+    dot.push('\n');
+    for topic in topics {
+        for prerequisite in topic.prerequisites {
+            dot.push_str(&format!("    {} -> {};\n", prerequisite, topic.crate_name));
+        }
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+#[derive(Serialize)]
+struct Edge<'a> {
+    from: &'a str,
+    to: &'a str,
+}
+
+#[derive(Serialize)]
+struct Graph<'a> {
+    topics: &'a [&'a Topic],
+    edges: Vec<Edge<'a>>,
+}
+
+// Exports the topics (in curriculum order) together with the prerequisite edges as JSON.
+pub fn to_json(topics: &[Topic]) -> Result<String, CurriculumError> {
+    let ordered = ordered(topics)?;
+    let edges = ordered
+        .iter()
+        .flat_map(|topic| {
+            topic.prerequisites.iter().map(|prerequisite| Edge {
+                from: prerequisite,
+                to: topic.crate_name,
+            })
+        })
+        .collect();
+
+    let graph = Graph {
+        topics: &ordered,
+        edges,
+    };
+    Ok(serde_json::to_string_pretty(&graph).expect("the curriculum is always valid JSON"))
+}
@@ -0,0 +1,200 @@
+// Links the errors (and some warnings) of rustc back to the lessons that teach them.
+// rustc and cargo can print their diagnostics as JSON ("--error-format json" and "--message-format json"), one message per line.
+// Every diagnostic has a code, such as "E0499" for errors or "unused_must_use" for lints, which is looked up in "HINTS".
+// "main diagnose" reads that JSON, and "main playground" uses it to add the hints to the errors of a snippet.
+
+use std::collections::HashSet;
+use std::fmt::Write as _;
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::curriculum;
+use crate::lessons;
+use crate::source;
+
+pub struct Hint {
+    pub code: &'static str,
+    pub hint: &'static str,
+    // A lesson id ("ownership::i_borrow_move::borrow_vs_moving_str"), a module ("lifetimes::lifetimes_in_structs") or a crate ("generics").
+    pub see: &'static str,
+}
+
+// Every code that a "// compile_fail:" block of the lessons fails with has a hint, which is checked by the tests of "main".
+pub const HINTS: &[Hint] = &[
+    Hint {
+        code: "E0106",
+        hint: "a reference in a struct, or returned from a function, needs a lifetime such as \"&'a str\", declared with \"<'a>\".",
+        see: "lifetimes::lifetimes_in_structs",
+    },
+    Hint {
+        code: "E0277",
+        hint: "the type does not implement a trait that is needed here. A generic type may need a bound such as \"T: Copy\", and a struct may need \"#[derive(Debug)]\".",
+        see: "generics",
+    },
+    Hint {
+        code: "E0308",
+        hint: "the value does not have the type that is expected here. An integer is not a float, and a \"&String\" is not a \"&mut String\".",
+        see: "generics::pre_generic_testing",
+    },
+    Hint {
+        code: "E0368",
+        hint: "\"+=\" only works on a generic type that is bound by the \"AddAssign\" trait.",
+        see: "generics::generic_testing_after_fix",
+    },
+    Hint {
+        code: "E0369",
+        hint: "\"==\" (and the other operators) only work on types that implement them, such as with \"#[derive(PartialEq)]\".",
+        see: "enums::animal::enum_testing",
+    },
+    Hint {
+        code: "E0382",
+        hint: "the value was moved to another variable (or function), so it cannot be used after that. Borrow it with \"&\", or clone it.",
+        see: "ownership::i_borrow_move::borrow_vs_moving_str",
+    },
+    Hint {
+        code: "E0384",
+        hint: "variables cannot be changed unless they are declared with \"let mut\".",
+        see: "variables::vars",
+    },
+    Hint {
+        code: "E0425",
+        hint: "the name does not exist here. A variable only lives until the end of the block (\"{ ... }\") it was declared in.",
+        see: "ownership::i_borrow_move::code_block",
+    },
+    Hint {
+        code: "E0499",
+        hint: "a value can only have one mutable reference (\"&mut\") at a time.",
+        see: "ownership::iii_mutable_references::multi_mut_ref_error",
+    },
+    Hint {
+        code: "E0502",
+        hint: "a value cannot be borrowed as mutable while an immutable reference to it is still used (or the other way around).",
+        see: "ownership::iii_mutable_references::demo_read_with_write",
+    },
+    Hint {
+        code: "E0507",
+        hint: "a value cannot be moved out of a reference. Borrow it instead, or copy it if its type is \"Copy\".",
+        see: "collections::hashmaps::functions_on_hashmaps",
+    },
+    Hint {
+        code: "E0515",
+        hint: "a reference to a local variable cannot be returned, since the variable is dropped at the end of the function. Return the value itself.",
+        see: "ownership::iv_dangling_references::demo_dangle",
+    },
+    Hint {
+        code: "E0596",
+        hint: "only a variable that is declared with \"let mut\" can be borrowed as mutable (\"&mut\").",
+        see: "ownership::iii_mutable_references::demo_mut_borrow",
+    },
+    Hint {
+        code: "E0599",
+        hint: "the method only exists when the bounds of its \"impl\" block are met, such as \"T: Copy\" in \"impl<T: Copy> GenericPoint2<T>\".",
+        see: "generics::generic_testing_after_fix",
+    },
+    Hint {
+        code: "E0716",
+        hint: "a temporary value (such as \"&\"text\".to_string()\") is dropped at the end of the statement, so a reference to it cannot be kept.",
+        see: "lifetimes::generic_lifetimes::input_lifetime",
+    },
+    Hint {
+        code: "unused_must_use",
+        hint: "a \"Future\" does nothing until it is awaited (and a \"Result\" should be handled).",
+        see: "async_rust::futures",
+    },
+];
+
+pub fn hint_for(code: &str) -> Option<&'static Hint> {
+    HINTS.iter().find(|hint| hint.code == code)
+}
+
+// What "see" of a hint points at.
+#[derive(Debug, PartialEq)]
+pub enum Target {
+    Lesson(&'static str),
+    // The source file of a module, relative to the workspace.
+    Module(String),
+    Crate(&'static str),
+}
+
+pub fn target(see: &str) -> Option<Target> {
+    if let Some(lesson) = lessons::find(see) {
+        return Some(Target::Lesson(lesson.id));
+    }
+    if let Some(topic) = curriculum::find(see) {
+        return Some(Target::Crate(topic.crate_name));
+    }
+    let (crate_name, modules) = see.split_once("::")?;
+    let modules = modules.replace("::", "/");
+    [format!("{}/src/{}.rs", crate_name, modules), format!("{}/src/{}/mod.rs", crate_name, modules)]
+        .into_iter()
+        .find(|file| source::workspace_root().join(file).is_file())
+        .map(Target::Module)
+}
+
+// The parts of rustc's JSON diagnostics that are needed here, the other fields are ignored.
+#[derive(Debug, Deserialize)]
+pub struct Diagnostic {
+    pub message: String,
+    pub code: Option<Code>,
+    pub level: String,
+    // The diagnostic as rustc would have printed it without JSON.
+    pub rendered: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Code {
+    pub code: String,
+}
+
+impl Diagnostic {
+    pub fn code(&self) -> Option<&str> {
+        self.code.as_ref().map(|code| code.code.as_str())
+    }
+}
+
+// Reads the diagnostics from the JSON lines of either rustc or cargo.
+// Cargo wraps every diagnostic in a "compiler-message", and its other messages (such as "compiler-artifact") are skipped, as are lines that are not JSON.
+pub fn parse(json_lines: &str) -> Vec<Diagnostic> {
+    json_lines
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+        .filter_map(|mut value| match value.get("reason") {
+            Some(reason) if reason == "compiler-message" => Some(value["message"].take()),
+            Some(_) => None,
+            None => Some(value),
+        })
+        .filter_map(|value| serde_json::from_value(value).ok())
+        .collect()
+}
+
+// Prints every diagnostic as rustc would have, with the hint and the lesson under the first diagnostic of every code that has a hint.
+pub fn explain(diagnostics: &[Diagnostic]) -> String {
+    let mut explained = String::new();
+    let mut hinted = HashSet::new();
+    for diagnostic in diagnostics {
+        match &diagnostic.rendered {
+            Some(rendered) => explained.push_str(rendered),
+            None => {
+                let _ = writeln!(explained, "{}: {}", diagnostic.level, diagnostic.message);
+            }
+        }
+        let Some(hint) = diagnostic.code().and_then(hint_for) else {
+            continue;
+        };
+        if !hinted.insert(hint.code) {
+            continue;
+        }
+        let _ = writeln!(explained, "hint[{}]: {}", hint.code, hint.hint);
+        let _ = match target(hint.see) {
+            Some(Target::Lesson(id)) => writeln!(explained, "  lesson: {}, run it with \"main run {}\"", id, id),
+            Some(Target::Module(file)) => writeln!(explained, "  lesson: {}, read it in {}", hint.see, file),
+            Some(Target::Crate(crate_name)) => {
+                writeln!(explained, "  lesson: {}, run it with \"main run --crate {}\"", crate_name, crate_name)
+            }
+            None => writeln!(explained, "  lesson: {}", hint.see),
+        };
+        explained.push('\n');
+    }
+    explained
+}
@@ -0,0 +1,177 @@
+// Every lesson crate has an "exercises" module with "todo!()" stubs, and a "tests/exercises.rs" that checks them.
+// The tests are ignored until they are asked for, so checking an exercise runs them through cargo with "--include-ignored".
+// An exercise is identified as "<crate>::<name>", where "<name>" is the test module that holds its tests.
+
+use std::env;
+use std::io;
+use std::process::Command;
+
+use crate::source;
+
+pub struct Exercise {
+    pub id: &'static str,
+    pub summary: &'static str,
+}
+
+impl Exercise {
+    pub fn crate_name(&self) -> &'static str {
+        self.id.split("::").next().unwrap_or(self.id)
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.id.rsplit("::").next().unwrap_or(self.id)
+    }
+}
+
+// The exercises in curriculum order.
+pub static EXERCISES: &[Exercise] = &[
+    Exercise {
+        id: "hello_world::greeting",
+        summary: "Greet someone by name.",
+    },
+    Exercise {
+        id: "variables::sum_up_to",
+        summary: "Add up the numbers from 1 to n with a mutable variable.",
+    },
+    Exercise {
+        id: "ownership::make_loud",
+        summary: "Change a string through a mutable reference.",
+    },
+    Exercise {
+        id: "functions::apply_twice",
+        summary: "Call a function that was passed as an argument twice.",
+    },
+    Exercise {
+        id: "structs::rectangle",
+        summary: "Write the methods of a \"Rectangle\" struct.",
+    },
+    Exercise {
+        id: "enums::triangle_area",
+        summary: "Calculate the area of a new \"Shapes::Triangle\".",
+    },
+    Exercise {
+        id: "collections::word_counts",
+        summary: "Count the words of a text in a HashMap.",
+    },
+    Exercise {
+        id: "iters::sum_of_even_squares",
+        summary: "Sum the squares of the even numbers with iterators.",
+    },
+    Exercise {
+        id: "optionals::find_age",
+        summary: "Look up an age that may not be there.",
+    },
+    Exercise {
+        id: "lifetimes::longest_of_three",
+        summary: "Write \"longest()\" for three strings.",
+    },
+    Exercise {
+        id: "traits::cow",
+        summary: "Implement \"Talk\" and \"Display\" for a cow.",
+    },
+    Exercise {
+        id: "error_handling::parse_age",
+        summary: "Parse an age and return the right error.",
+    },
+    Exercise {
+        id: "pointers::shared_counter",
+        summary: "Share a counter with \"Rc\" and \"Cell\".",
+    },
+    Exercise {
+        id: "generics::sub_assign",
+        summary: "Make \"GenericPoint2\" support \"-=\".",
+    },
+    Exercise {
+        id: "async_rust::full_name",
+        summary: "Wait for two futures at the same time.",
+    },
+];
+
+pub fn find(id: &str) -> Option<&'static Exercise> {
+    EXERCISES.iter().find(|exercise| exercise.id == id)
+}
+
+#[derive(Debug, PartialEq)]
+pub struct TestResult {
+    // The full name of the test, such as "triangle_area::triangle_area_is_half_of_base_times_height".
+    pub name: String,
+    pub passed: bool,
+}
+
+pub enum Outcome {
+    Tested {
+        results: Vec<TestResult>,
+        // Everything the tests printed, including the panic messages of the failed tests.
+        output: String,
+    },
+    // The exercises of the crate do not compile, with the errors from the compiler.
+    DoesNotCompile(String),
+}
+
+impl Outcome {
+    // The results of the tests that belong to one exercise of the crate.
+    pub fn results_of<'a>(&'a self, exercise: &Exercise) -> Vec<&'a TestResult> {
+        let prefix = format!("{}::", exercise.name());
+        match self {
+            Outcome::Tested { results, .. } => results
+                .iter()
+                .filter(|result| result.name.starts_with(&prefix))
+                .collect(),
+            Outcome::DoesNotCompile(_) => Vec::new(),
+        }
+    }
+
+    // An exercise is solved when it has tests, and all of them pass.
+    pub fn solved(&self, exercise: &Exercise) -> bool {
+        let results = self.results_of(exercise);
+        !results.is_empty() && results.iter().all(|result| result.passed)
+    }
+}
+
+// Runs the exercise tests of one crate, including the ignored ones.
+// Cargo is the same one that runs "main" when it is started with "cargo run", and "cargo" from the PATH otherwise.
+// Backtraces are turned off, so that a failed test only shows its panic message.
+pub fn check_crate(crate_name: &str) -> io::Result<Outcome> {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let output = Command::new(cargo)
+        .current_dir(source::workspace_root())
+        .env("RUST_BACKTRACE", "0")
+        .args(["test", "--color", "never", "-p", crate_name, "--test", "exercises"])
+        .args(["--", "--include-ignored", "--color", "never"])
+        .output()?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+    if !stdout.contains("test result:") {
+        return Ok(Outcome::DoesNotCompile(String::from_utf8_lossy(&output.stderr).into_owned()));
+    }
+    Ok(Outcome::Tested {
+        results: parse_results(&stdout),
+        output: stdout,
+    })
+}
+
+// Reads the lines that the test harness prints for every test, such as "test sum_up_to::sums_small_numbers ... ok".
+pub fn parse_results(stdout: &str) -> Vec<TestResult> {
+    stdout
+        .lines()
+        .filter_map(|line| line.strip_prefix("test "))
+        .filter_map(|line| line.split_once(" ... "))
+        .map(|(name, verdict)| TestResult {
+            name: name.to_string(),
+            passed: verdict == "ok",
+        })
+        .collect()
+}
+
+// Returns what a failed test printed, which is the section that starts with "---- <name> stdout ----".
+pub fn failure_output<'a>(output: &'a str, test_name: &str) -> Option<&'a str> {
+    let header = format!("---- {} stdout ----\n", test_name);
+    let start = output.find(&header)? + header.len();
+    let rest = &output[start..];
+    let end = ["\n---- ", "\n\nfailures:"]
+        .iter()
+        .filter_map(|marker| rest.find(marker))
+        .min()
+        .unwrap_or(rest.len());
+    Some(rest[..end].trim_end())
+}
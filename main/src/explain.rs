@@ -0,0 +1,316 @@
+// "main run --explain" shows the code and comments of a lesson, with the output of every statement right below the statement that wrote it.
+// The lesson writes to a "Tracer", which records every "write!" and "writeln!" together with the line of the lesson that made it.
+// That line is read from a backtrace, so it is only known when "main" is built with debug info (which is the default for "cargo run").
+// The sources of the lessons are embedded into "main" by "build.rs", so an explanation always matches the code that ran.
+
+use std::backtrace::Backtrace;
+use std::fmt::{self, Write as _};
+use std::io::{self, Write};
+
+use crate::source;
+
+include!(concat!(env!("OUT_DIR"), "/sources.rs"));
+
+// Returns the embedded source of a file, such as "enums/src/shapes.rs".
+pub fn source_of(file: &str) -> Option<&'static str> {
+    SOURCES.iter().find(|(path, _)| *path == file).map(|(_, source)| *source)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Location {
+    pub file: &'static str,
+    pub line: usize,
+}
+
+// A piece of output, with the line of the lesson that wrote it.
+#[derive(Debug)]
+pub struct Chunk {
+    pub location: Option<Location>,
+    pub text: String,
+}
+
+#[derive(Default)]
+pub struct Tracer {
+    pub chunks: Vec<Chunk>,
+}
+
+impl Tracer {
+    fn record(&mut self, text: String) {
+        self.chunks.push(Chunk {
+            location: caller_location(),
+            text,
+        });
+    }
+}
+
+impl Write for Tracer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.record(String::from_utf8_lossy(buf).into_owned());
+        Ok(buf.len())
+    }
+
+    // "write!" and "writeln!" call this once per statement, so it is overridden to record the whole line at once.
+    fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> io::Result<()> {
+        self.record(args.to_string());
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+// The innermost frame of the backtrace that is in the source of a lesson, which is the statement that wrote the output.
+// The frames are printed as "at <path>:<line>:<column>", where the path may be relative or absolute.
+fn caller_location() -> Option<Location> {
+    let backtrace = Backtrace::force_capture().to_string();
+    backtrace.lines().find_map(|line| {
+        let mut parts = line.trim().strip_prefix("at ")?.rsplitn(3, ':');
+        let (_column, line, path) = (parts.next()?, parts.next()?, parts.next()?);
+        let path = path.replace('\\', "/");
+        let (file, _) = SOURCES
+            .iter()
+            .find(|(file, _)| path == *file || path.ends_with(&format!("/{}", file)))?;
+        Some(Location {
+            file,
+            line: line.parse().ok()?,
+        })
+    })
+}
+
+// A function in a source file, from the first line of the comments above it to its closing brace (all 1-based).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Span {
+    pub comments_start: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    fn contains(&self, line: usize) -> bool {
+        self.start <= line && line <= self.end
+    }
+}
+
+// Finds the end of the function that starts at "start", by matching its braces.
+// Braces inside comments, strings and characters (such as the "{}" of a format string) are skipped.
+pub fn function_span(source: &str, start: usize) -> Option<Span> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut comments_start = start;
+    while comments_start > 1 {
+        let above = lines[comments_start - 2].trim_start();
+        if !(above.starts_with("//") || above.starts_with("#[")) {
+            break;
+        }
+        comments_start -= 1;
+    }
+
+    let mut scanner = Scanner::default();
+    let mut depth = 0;
+    for (index, line) in lines.iter().enumerate().skip(start - 1) {
+        for code in scanner.code(line) {
+            match code {
+                '{' => depth += 1,
+                '}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(Span {
+                            comments_start,
+                            start,
+                            end: index + 1,
+                        });
+                    }
+                }
+                // A function without a body, such as a method of a trait.
+                ';' if depth == 0 => {
+                    return Some(Span {
+                        comments_start,
+                        start,
+                        end: index + 1,
+                    });
+                }
+                _ => {}
+            }
+        }
+    }
+    None
+}
+
+// The innermost function that contains a line.
+pub fn enclosing_function(source: &str, line: usize) -> Option<Span> {
+    let lines: Vec<&str> = source.lines().collect();
+    let blocks = source::broken_blocks(source);
+    (1..=line.min(lines.len()))
+        .rev()
+        .filter(|&number| {
+            !blocks
+                .iter()
+                .any(|block| block.open_line <= number && number <= block.close_line)
+                && defines_function(lines[number - 1])
+        })
+        .filter_map(|number| function_span(source, number))
+        .find(|span| span.contains(line))
+}
+
+fn defines_function(line: &str) -> bool {
+    let code = line.trim_start();
+    !code.starts_with("//")
+        && code.match_indices("fn ").any(|(at, _)| {
+            (at == 0 || code[..at].ends_with(' '))
+                && code[at + 3..].starts_with(|c: char| c.is_alphabetic() || c == '_')
+        })
+}
+
+// Reads source code line by line, and keeps track of whether it is inside a comment, a string or a character.
+#[derive(Default)]
+struct Scanner {
+    state: State,
+}
+
+#[derive(Default, Clone, Copy, PartialEq)]
+enum State {
+    #[default]
+    Code,
+    // Block comments can be nested, so their depth is kept.
+    BlockComment(usize),
+    Str,
+    // A raw string, with the number of "#" that close it.
+    RawStr(usize),
+}
+
+impl Scanner {
+    // Returns the characters of the line that are code, leaving out comments, strings and characters.
+    fn code(&mut self, line: &str) -> Vec<char> {
+        let chars: Vec<char> = line.chars().collect();
+        let mut code = Vec::new();
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            let next = chars.get(i + 1).copied();
+            match self.state {
+                State::Code => match (c, next) {
+                    ('/', Some('/')) => break,
+                    ('/', Some('*')) => {
+                        self.state = State::BlockComment(1);
+                        i += 1;
+                    }
+                    ('"', _) => self.state = State::Str,
+                    ('r', Some('"' | '#')) if i == 0 || !is_identifier(chars[i - 1]) => {
+                        let hashes = chars[i + 1..].iter().take_while(|&&c| c == '#').count();
+                        if chars.get(i + 1 + hashes) == Some(&'"') {
+                            self.state = State::RawStr(hashes);
+                            i += hashes + 1;
+                        }
+                    }
+                    // A character such as '{' or '\'', which is told apart from a lifetime such as 'a by its closing quote.
+                    ('\'', Some('\\')) => {
+                        i += 2;
+                        while i < chars.len() && chars[i] != '\'' {
+                            i += 1;
+                        }
+                    }
+                    ('\'', Some(_)) if chars.get(i + 2) == Some(&'\'') => i += 2,
+                    _ => code.push(c),
+                },
+                State::BlockComment(depth) => match (c, next) {
+                    ('*', Some('/')) => {
+                        self.state = if depth == 1 { State::Code } else { State::BlockComment(depth - 1) };
+                        i += 1;
+                    }
+                    ('/', Some('*')) => {
+                        self.state = State::BlockComment(depth + 1);
+                        i += 1;
+                    }
+                    _ => {}
+                },
+                State::Str => match c {
+                    '\\' => i += 1,
+                    '"' => self.state = State::Code,
+                    _ => {}
+                },
+                State::RawStr(hashes) => {
+                    if c == '"' && chars[i + 1..].iter().take_while(|&&c| c == '#').count() >= hashes {
+                        self.state = State::Code;
+                        i += hashes;
+                    }
+                }
+            }
+            i += 1;
+        }
+        code
+    }
+}
+
+fn is_identifier(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+// The function that a lesson runs: its "_to" variant if it has one, and the lesson function itself otherwise.
+pub fn lesson_function(lesson_id: &str) -> Option<(&'static str, Span)> {
+    let read = |file: &str| source_of(file).map(str::to_string);
+    let (file, line) = source::locate_with(&format!("{}_to", lesson_id), read)
+        .or_else(|| source::locate_with(lesson_id, read))?;
+    let file = SOURCES.iter().find(|(path, _)| *path == file)?.0;
+    Some((file, function_span(source_of(file)?, line)?))
+}
+
+// Writes the lesson function with the output under the statements that wrote it.
+// Output that was written by other functions of the lesson crates (such as a helper that the lesson calls) is shown under those functions, after the lesson.
+// Every line of output is numbered, so that the order in which it was written can be followed across functions.
+pub fn explain(lesson_id: &str, chunks: &[Chunk]) -> String {
+    let mut sections: Vec<(&'static str, Span)> = lesson_function(lesson_id).into_iter().collect();
+    for location in chunks.iter().filter_map(|chunk| chunk.location.as_ref()) {
+        let shown = sections
+            .iter()
+            .any(|(file, span)| *file == location.file && span.contains(location.line));
+        if !shown
+            && let Some(span) = source_of(location.file).and_then(|source| enclosing_function(source, location.line))
+        {
+            sections.push((location.file, span));
+        }
+    }
+
+    let mut numbered: Vec<(Option<&Location>, usize, &str)> = Vec::new();
+    for chunk in chunks {
+        for text in chunk.text.lines() {
+            numbered.push((chunk.location.as_ref(), numbered.len() + 1, text));
+        }
+    }
+
+    let mut explanation = String::new();
+    for (index, (file, span)) in sections.iter().enumerate() {
+        if index > 0 {
+            explanation.push('\n');
+        }
+        let called = if index == 0 { "" } else { ", called by the lesson" };
+        let _ = writeln!(explanation, "-- {}:{}{}", file, span.start, called);
+        let lines: Vec<&str> = source_of(file).unwrap_or_default().lines().collect();
+        for number in span.comments_start..=span.end {
+            let _ = writeln!(explanation, "{:>4} | {}", number, lines.get(number - 1).unwrap_or(&""));
+            for (_, order, text) in numbered
+                .iter()
+                .filter(|(location, ..)| location.is_some_and(|location| location.file == *file && location.line == number))
+            {
+                let _ = writeln!(explanation, "     > [{}] {}", order, text);
+            }
+        }
+    }
+
+    let unplaced: Vec<_> = numbered
+        .iter()
+        .filter(|(location, ..)| {
+            !location.is_some_and(|location| {
+                sections
+                    .iter()
+                    .any(|(file, span)| *file == location.file && span.contains(location.line))
+            })
+        })
+        .collect();
+    if !unplaced.is_empty() {
+        explanation.push_str("-- output that could not be matched to a line of a lesson (is \"main\" built without debug info?)\n");
+        for (_, order, text) in unplaced {
+            let _ = writeln!(explanation, "     > [{}] {}", order, text);
+        }
+    }
+    explanation
+}
@@ -0,0 +1,209 @@
+// This file is the registry of every lesson in the workspace.
+// A lesson is any public function in a lesson crate that can be run on its own, such as "enums::shapes::enum_testing".
+// The id of a lesson is the full path of the function, written as "<crate>::<module>::<function>".
+// Lessons that print have a "_to" variant that writes to any "Write", which is what the registry runs so that the output can be captured.
+
+use std::io::{self, Write};
+
+use crate::curriculum;
+
+pub struct Lesson {
+    pub id: &'static str,
+    pub run: fn(&mut dyn Write) -> io::Result<()>,
+    // Lessons that panic on purpose declare a part of the panic message they are expected to panic with.
+    pub expected_panic: Option<&'static str>,
+}
+
+impl Lesson {
+    // The crate is always the first segment of the id.
+    pub fn crate_name(&self) -> &'static str {
+        self.id.split("::").next().unwrap_or(self.id)
+    }
+}
+
+// The "lesson!" macro turns a function path into a "Lesson", using the path itself as the id.
+// "lesson!(a::b => a::b_to)" registers the "_to" variant of a lesson that prints.
+// "lesson!(a::b)" registers a lesson that prints nothing, so it is wrapped to take (and ignore) the writer.
+// Lessons that are not plain functions (such as methods that need an instance first) pass the id and a closure explicitly.
+// Any of the forms can end with "panics = "<message>"" to declare that the lesson is expected to panic.
+// The macro is unused when "main" is built without any lesson crate.
+#[allow(unused_macros)]
+macro_rules! lesson {
+    (@panic $panic:literal) => {
+        Some($panic)
+    };
+    (@panic) => {
+        None
+    };
+    ($id:literal, $run:expr $(, panics = $panic:literal)?) => {
+        Lesson {
+            id: $id,
+            run: $run,
+            expected_panic: lesson!(@panic $($panic)?),
+        }
+    };
+    ($path:path => $writer:path $(, panics = $panic:literal)?) => {
+        Lesson {
+            id: stringify!($path),
+            run: $writer,
+            expected_panic: lesson!(@panic $($panic)?),
+        }
+    };
+    ($path:path $(, panics = $panic:literal)?) => {
+        Lesson {
+            id: stringify!($path),
+            run: |_| {
+                $path();
+                Ok(())
+            },
+            expected_panic: lesson!(@panic $($panic)?),
+        }
+    };
+}
+
+// The lessons are listed in the same order as the workspace members, and in file order within each crate.
+// Every lesson crate is an optional dependency behind a cargo feature with the same name, so the lessons of a crate are only registered when its feature is on.
+pub static LESSONS: &[Lesson] = &[
+    #[cfg(feature = "hello_world")]
+    lesson!(hello_world::hello => hello_world::hello_to),
+    #[cfg(feature = "hello_world")]
+    lesson!(hello_world::greeter::greeter_testing => hello_world::greeter::greeter_testing_to),
+    #[cfg(feature = "variables")]
+    lesson!(variables::vars),
+    #[cfg(feature = "variables")]
+    lesson!(variables::literals::literals_testing => variables::literals::literals_testing_to),
+    #[cfg(feature = "variables")]
+    lesson!(variables::floats::floats_testing => variables::floats::floats_testing_to),
+    #[cfg(feature = "ownership")]
+    lesson!(ownership::i_borrow_move::borrow_vs_moving_str => ownership::i_borrow_move::borrow_vs_moving_str_to),
+    #[cfg(feature = "ownership")]
+    lesson!(ownership::i_borrow_move::borrow_vs_moving_int => ownership::i_borrow_move::borrow_vs_moving_int_to),
+    #[cfg(feature = "ownership")]
+    lesson!(ownership::i_borrow_move::borrow_vs_moving_str_correction => ownership::i_borrow_move::borrow_vs_moving_str_correction_to),
+    #[cfg(feature = "ownership")]
+    lesson!(ownership::i_borrow_move::code_block => ownership::i_borrow_move::code_block_to),
+    #[cfg(feature = "ownership")]
+    lesson!(ownership::ii_value_vs_reference::demo_ownership => ownership::ii_value_vs_reference::demo_ownership_to),
+    #[cfg(feature = "ownership")]
+    lesson!(ownership::iii_mutable_references::demo_mut_borrow),
+    #[cfg(feature = "ownership")]
+    lesson!(ownership::iii_mutable_references::multi_mut_ref_error),
+    #[cfg(feature = "ownership")]
+    lesson!(ownership::iii_mutable_references::demo_mut_reference),
+    #[cfg(feature = "ownership")]
+    lesson!(ownership::iii_mutable_references::demo_read_with_write => ownership::iii_mutable_references::demo_read_with_write_to),
+    #[cfg(feature = "ownership")]
+    lesson!(ownership::iv_dangling_references::demo_dangle),
+    #[cfg(feature = "ownership")]
+    lesson!(ownership::v_memory_layout::memory_layout => ownership::v_memory_layout::memory_layout_to),
+    #[cfg(feature = "ownership")]
+    lesson!(ownership::vi_borrow_simulator::borrow_simulator => ownership::vi_borrow_simulator::borrow_simulator_to),
+    #[cfg(feature = "count_allocations")]
+    lesson!(ownership::vii_allocations::allocation_costs => ownership::vii_allocations::allocation_costs_to),
+    #[cfg(feature = "ownership")]
+    lesson!(ownership::viii_drop_order::drop_order => ownership::viii_drop_order::drop_order_to),
+    #[cfg(feature = "functions")]
+    lesson!(functions::func_testing),
+    #[cfg(feature = "structs")]
+    lesson!(structs::person::instantiate => structs::person::instantiate_to),
+    #[cfg(feature = "structs")]
+    lesson!(structs::tuples::instantiation_3d => structs::tuples::instantiation_3d_to),
+    #[cfg(feature = "enums")]
+    lesson!(enums::animal::enum_testing => enums::animal::enum_testing_to),
+    #[cfg(feature = "enums")]
+    lesson!(enums::shapes::enum_testing => enums::shapes::enum_testing_to),
+    #[cfg(feature = "enums")]
+    lesson!(enums::shapes::enum_testing_unnamed => enums::shapes::enum_testing_unnamed_to),
+    #[cfg(feature = "collections")]
+    lesson!(collections::tuples::tuple_testing => collections::tuples::tuple_testing_to),
+    #[cfg(feature = "collections")]
+    lesson!(collections::vectors::iter_over_vector_loops => collections::vectors::iter_over_vector_loops_to),
+    #[cfg(feature = "collections")]
+    lesson!(collections::vectors::specific_vector_element => collections::vectors::specific_vector_element_to),
+    #[cfg(feature = "collections")]
+    lesson!(collections::vectors::mapping_iteration_vector),
+    #[cfg(feature = "collections")]
+    lesson!(collections::vectors::vector_shorthand => collections::vectors::vector_shorthand_to),
+    #[cfg(feature = "collections")]
+    lesson!(collections::vectors::concat_vectors => collections::vectors::concat_vectors_to),
+    #[cfg(feature = "collections")]
+    lesson!(collections::hashmaps::functions_on_hashmaps => collections::hashmaps::functions_on_hashmaps_to),
+    #[cfg(feature = "collections")]
+    lesson!(collections::hashmaps::structs_in_hashmaps),
+    #[cfg(feature = "iters")]
+    lesson!(iters::iterating_over_vector => iters::iterating_over_vector_to),
+    #[cfg(feature = "optionals")]
+    lesson!(optionals::optionals => optionals::optionals_to),
+    #[cfg(feature = "error_handling")]
+    lesson!(error_handling::errors => error_handling::errors_to, panics = "This should've been an error"),
+    #[cfg(feature = "lifetimes")]
+    lesson!(lifetimes::generic_lifetimes::input_lifetime => lifetimes::generic_lifetimes::input_lifetime_to),
+    #[cfg(feature = "traits")]
+    lesson!(traits::traits::print_person => traits::traits::print_person_to),
+    #[cfg(feature = "traits")]
+    lesson!(traits::traits::new_person => traits::traits::new_person_to),
+    #[cfg(feature = "traits")]
+    lesson!(traits::traits::print_person_with_display => traits::traits::print_person_with_display_to),
+    #[cfg(feature = "traits")]
+    lesson!(traits::multiple_traits::create_animals),
+    #[cfg(feature = "traits")]
+    lesson!(traits::multiple_traits::animals),
+    #[cfg(feature = "pointers")]
+    lesson!(pointers::box_ptr::boxx => pointers::box_ptr::boxx_to),
+    #[cfg(feature = "pointers")]
+    lesson!(pointers::box_implementation::age_in_reg_struct => pointers::box_implementation::age_in_reg_struct_to),
+    #[cfg(feature = "pointers")]
+    lesson!(pointers::box_implementation::age_in_boxed_value => pointers::box_implementation::age_in_boxed_value_to),
+    #[cfg(feature = "pointers")]
+    lesson!(pointers::box_implementation::implicit_deref => pointers::box_implementation::implicit_deref_to),
+    #[cfg(feature = "pointers")]
+    lesson!(pointers::rc::rc_pointers => pointers::rc::rc_pointers_to),
+    #[cfg(feature = "pointers")]
+    lesson!(pointers::cell::cell_testing => pointers::cell::cell_testing_to),
+    #[cfg(feature = "pointers")]
+    lesson!(pointers::cell::ref_cell_testing, panics = "already mutably borrowed"),
+    #[cfg(feature = "generics")]
+    lesson!(generics::pre_generic_testing => generics::pre_generic_testing_to),
+    #[cfg(feature = "generics")]
+    lesson!(generics::generic_testing => generics::generic_testing_to),
+    #[cfg(feature = "generics")]
+    lesson!(generics::generic_testing_after_fix => generics::generic_testing_after_fix_to),
+    #[cfg(feature = "async_rust")]
+    lesson!(async_rust::futures::futures_testing => async_rust::futures::futures_testing_to),
+    #[cfg(feature = "async_rust")]
+    lesson!("async_rust::futures::futures_future_testing", |out| {
+        async_rust::tokio::TokioLib::new().run_future(async_rust::futures::futures_future_testing_to(out))
+    }),
+    #[cfg(feature = "async_rust")]
+    lesson!("async_rust::tokio::TokioLib::run_async_task", |out| {
+        async_rust::tokio::TokioLib::new().run_async_task_to(out)
+    }),
+];
+
+pub fn find(id: &str) -> Option<&'static Lesson> {
+    LESSONS.iter().find(|lesson| lesson.id == id)
+}
+
+// Returns all the lessons of a single crate, such as "ownership".
+pub fn in_crate(crate_name: &str) -> Vec<&'static Lesson> {
+    LESSONS
+        .iter()
+        .filter(|lesson| lesson.crate_name() == crate_name)
+        .collect()
+}
+
+// Whether the lessons of a crate were left out of this build of "main" by turning its feature off.
+pub fn is_left_out(crate_name: &str) -> bool {
+    curriculum::find(crate_name).is_some() && in_crate(crate_name).is_empty()
+}
+
+// Returns the name of every crate that has at least one lesson, in registry order.
+pub fn crate_names() -> Vec<&'static str> {
+    let mut names: Vec<&'static str> = Vec::new();
+    for lesson in LESSONS {
+        if !names.contains(&lesson.crate_name()) {
+            names.push(lesson.crate_name());
+        }
+    }
+    names
+}
@@ -0,0 +1,15 @@
+#![deny(clippy::all)]
+
+pub mod book;
+pub mod cli;
+pub mod curriculum;
+pub mod diagnostics;
+pub mod exercises;
+pub mod explain;
+pub mod lessons;
+pub mod playground;
+pub mod progress;
+pub mod quiz;
+pub mod runner;
+pub mod source;
+pub mod watch;
@@ -1,7 +1,639 @@
 #![deny(clippy::all)]
 
-fn main() {
-    // let s: String = "Hello".to_string();
-    // let i: u8 = 10;
-    async_rust::futures::futures_testing();
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, BufReader, Read};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use main::cli::{self, Command, CurriculumFormat, OutputFormat};
+use main::curriculum;
+use main::diagnostics;
+use main::exercises::{self, Exercise, Outcome};
+use main::explain::{self, Tracer};
+use main::lessons::{self, Lesson};
+use main::playground::{self, Outcome as PlaygroundOutcome};
+use main::progress::{self, Progress};
+use main::quiz;
+use main::runner::{self, Record, Report};
+use main::source;
+use main::watch::{self, Watcher};
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let command = match cli::parse(&args) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("error: {}\n\n{}", message, cli::USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    match command {
+        Command::List => {
+            for lesson in lessons::LESSONS {
+                match lesson.expected_panic {
+                    Some(_) => println!("{} (expected to panic)", lesson.id),
+                    None => println!("{}", lesson.id),
+                }
+            }
+        }
+        Command::Run(ids, format) => {
+            // Every id is checked before anything runs, so a typo does not leave the run half done.
+            let mut selected = Vec::new();
+            for id in &ids {
+                match lessons::find(id) {
+                    Some(lesson) => selected.push(lesson),
+                    None => {
+                        let crate_name = id.split("::").next().unwrap_or(id);
+                        if lessons::is_left_out(crate_name) {
+                            eprintln!("error: {}", left_out(crate_name));
+                        } else {
+                            eprintln!("error: no lesson with id \"{}\" (see \"main list\")", id);
+                        }
+                        return ExitCode::FAILURE;
+                    }
+                }
+            }
+            return run(&selected, format);
+        }
+        Command::RunCrate(name, format) => {
+            let selected = lessons::in_crate(&name);
+            if lessons::is_left_out(&name) {
+                eprintln!("error: {}", left_out(&name));
+                return ExitCode::FAILURE;
+            }
+            if selected.is_empty() {
+                eprintln!(
+                    "error: no crate named \"{}\", expected one of: {}",
+                    name,
+                    lessons::crate_names().join(", ")
+                );
+                return ExitCode::FAILURE;
+            }
+            return run(&selected, format);
+        }
+        Command::RunAll(format) => {
+            let selected: Vec<&Lesson> = lessons::LESSONS.iter().collect();
+            return run(&selected, format);
+        }
+        Command::Curriculum(format) => return curriculum(format),
+        Command::Check(Some(id)) => match exercises::find(&id) {
+            Some(exercise) => return check(exercise),
+            None => {
+                eprintln!("error: no exercise with id \"{}\" (see \"main check\")", id);
+                return ExitCode::FAILURE;
+            }
+        },
+        Command::Check(None) => return check_all(),
+        Command::Progress => return show_progress(),
+        Command::Next => return next(),
+        Command::Reset => return reset(),
+        Command::Watch(crate_name) => return watch(&crate_name),
+        Command::Quiz { crate_name, answers } => return run_quiz(&crate_name, answers.as_deref()),
+        Command::Playground { file, timeout_secs } => return playground(file.as_deref(), timeout_secs),
+        Command::Diagnose(file) => return diagnose(file.as_deref()),
+        Command::Simulate(file) => return simulate(file.as_deref()),
+        Command::Help => println!("{}", cli::USAGE),
+    }
+
+    ExitCode::SUCCESS
+}
+
+// Explains how to build the lessons of a crate whose feature was turned off.
+fn left_out(crate_name: &str) -> String {
+    format!(
+        "the lessons of \"{}\" are not built into this \"main\", add them with \"--features {}\"",
+        crate_name, crate_name
+    )
+}
+
+// Runs the lessons in order and reports every panic, whether it was expected or not.
+// The exit code is a failure if any lesson did not behave as declared in the registry.
+fn run(selected: &[&'static Lesson], format: OutputFormat) -> ExitCode {
+    let all_passed = match format {
+        OutputFormat::Text => run_as_text(selected),
+        OutputFormat::Json => run_as_json(selected),
+        OutputFormat::Explain => run_as_explain(selected),
+    };
+
+    if let Some((path, mut progress)) = load_progress() {
+        for lesson in selected {
+            progress.record(lesson.id, progress::now());
+        }
+        if let Err(error) = progress.save(&path) {
+            eprintln!("warning: failed to save the progress to {}: {}", path.display(), error);
+        }
+    }
+
+    if all_passed { ExitCode::SUCCESS } else { ExitCode::FAILURE }
+}
+
+fn run_as_text(selected: &[&'static Lesson]) -> bool {
+    let mut stdout = io::stdout();
+    let mut failed = Vec::new();
+
+    for &lesson in selected {
+        println!("== {} ==", lesson.id);
+        let report = runner::run(lesson, &mut stdout).expect("failed to write to stdout");
+        print_verdict(&report);
+        if !report.passed() {
+            failed.push(lesson.id);
+        }
+    }
+    print_summary(selected.len(), &failed)
+}
+
+// The output of every lesson is traced first, and then shown under the lines of the lesson that wrote it.
+fn run_as_explain(selected: &[&'static Lesson]) -> bool {
+    let mut failed = Vec::new();
+
+    for &lesson in selected {
+        println!("== {} ==", lesson.id);
+        let mut tracer = Tracer::default();
+        let report = runner::run(lesson, &mut tracer).expect("writing to memory cannot fail");
+        print!("{}", explain::explain(lesson.id, &tracer.chunks));
+        print_verdict(&report);
+        if !report.passed() {
+            failed.push(lesson.id);
+        }
+    }
+    print_summary(selected.len(), &failed)
+}
+
+fn print_verdict(report: &Report) {
+    if let Some(panic) = &report.panic {
+        let verdict = if report.passed() { "expected" } else { "NOT expected" };
+        println!(
+            "-- panicked at {}: {} ({})",
+            panic.location.as_deref().unwrap_or("<unknown location>"),
+            panic.message,
+            verdict
+        );
+    } else if let Some(expected) = report.lesson.expected_panic {
+        println!("-- did not panic, but was expected to panic with \"{}\"", expected);
+    }
+}
+
+// Returns whether every lesson passed.
+fn print_summary(ran: usize, failed: &[&str]) -> bool {
+    println!("\nran {} lesson(s): {} passed, {} failed", ran, ran - failed.len(), failed.len());
+    for id in failed {
+        println!("    failed: {}", id);
+    }
+    failed.is_empty()
+}
+
+// Only the JSON goes to stdout, so the output of every lesson is captured into its record instead.
+fn run_as_json(selected: &[&'static Lesson]) -> bool {
+    let records: Vec<Record> = selected
+        .iter()
+        .map(|&lesson| {
+            let mut output = Vec::new();
+            let report = runner::run(lesson, &mut output).expect("writing to memory cannot fail");
+            report.to_record(String::from_utf8_lossy(&output).into_owned())
+        })
+        .collect();
+
+    println!("{}", runner::to_json(&records));
+    records.iter().all(|record| record.passed)
+}
+
+fn curriculum(format: CurriculumFormat) -> ExitCode {
+    let ordered = match curriculum::ordered(curriculum::TOPICS) {
+        Ok(ordered) => ordered,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match format {
+        CurriculumFormat::Text => {
+            for (index, topic) in ordered.iter().enumerate() {
+                println!("{:>2}. {} ({})", index + 1, topic.title, topic.crate_name);
+                if !topic.prerequisites.is_empty() {
+                    println!("      after: {}", topic.prerequisites.join(", "));
+                }
+            }
+        }
+        CurriculumFormat::Dot => print!("{}", curriculum::to_dot(curriculum::TOPICS)),
+        CurriculumFormat::Json => match curriculum::to_json(curriculum::TOPICS) {
+            Ok(json) => println!("{}", json),
+            Err(error) => {
+                eprintln!("error: {}", error);
+                return ExitCode::FAILURE;
+            }
+        },
+    }
+
+    ExitCode::SUCCESS
+}
+
+// Checks a single exercise and shows why it is not solved yet.
+fn check(exercise: &Exercise) -> ExitCode {
+    println!("== {} ==\n{}\n", exercise.id, exercise.summary);
+    let outcome = match exercises::check_crate(exercise.crate_name()) {
+        Ok(outcome) => outcome,
+        Err(error) => {
+            eprintln!("error: failed to run cargo: {}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Outcome::DoesNotCompile(errors) = &outcome {
+        println!("{}\nthe exercises of \"{}\" do not compile yet", errors.trim_end(), exercise.crate_name());
+        return ExitCode::FAILURE;
+    }
+
+    let results = outcome.results_of(exercise);
+    for result in &results {
+        println!("{} {}", if result.passed { "passed" } else { "FAILED" }, result.name);
+    }
+    if let Outcome::Tested { output, .. } = &outcome {
+        for result in results.iter().filter(|result| !result.passed) {
+            if let Some(failure) = exercises::failure_output(output, &result.name) {
+                println!("\n-- {}\n{}", result.name, failure);
+            }
+        }
+    }
+
+    if outcome.solved(exercise) {
+        println!("\nsolved!");
+        ExitCode::SUCCESS
+    } else {
+        let passed = results.iter().filter(|result| result.passed).count();
+        println!("\nnot solved yet: {} of {} test(s) passed", passed, results.len());
+        ExitCode::FAILURE
+    }
+}
+
+// Checks every exercise, running the tests of each crate once.
+fn check_all() -> ExitCode {
+    let mut solved = 0;
+    let mut outcomes: HashMap<&str, Outcome> = HashMap::new();
+    for exercise in exercises::EXERCISES {
+        let crate_name = exercise.crate_name();
+        if !outcomes.contains_key(crate_name) {
+            match exercises::check_crate(crate_name) {
+                Ok(outcome) => outcomes.insert(crate_name, outcome),
+                Err(error) => {
+                    eprintln!("error: failed to run cargo: {}", error);
+                    return ExitCode::FAILURE;
+                }
+            };
+        }
+        let status = if outcomes[crate_name].solved(exercise) {
+            solved += 1;
+            "[x]"
+        } else {
+            "[ ]"
+        };
+        println!("{} {:<32} {}", status, exercise.id, exercise.summary);
+    }
+
+    println!("\nsolved {} of {} exercise(s)", solved, exercises::EXERCISES.len());
+    ExitCode::SUCCESS
+}
+
+// Runs the lessons of a crate, then runs them again after every change to its source files until it is stopped with Ctrl+C.
+// The first run shows the whole output, and the later runs only show the lines that changed.
+fn watch(crate_name: &str) -> ExitCode {
+    if lessons::is_left_out(crate_name) {
+        eprintln!("error: {}", left_out(crate_name));
+        return ExitCode::FAILURE;
+    }
+    if lessons::in_crate(crate_name).is_empty() {
+        eprintln!(
+            "error: no crate named \"{}\", expected one of: {}",
+            crate_name,
+            lessons::crate_names().join(", ")
+        );
+        return ExitCode::FAILURE;
+    }
+
+    let dir = source::workspace_root().join(crate_name).join("src");
+    let mut watcher = match Watcher::new(&dir) {
+        Ok(watcher) => watcher,
+        Err(error) => {
+            eprintln!("error: failed to watch {}: {}", dir.display(), error);
+            return ExitCode::FAILURE;
+        }
+    };
+    println!("watching {} with {} (press Ctrl+C to stop)", dir.display(), watcher.kind());
+
+    let mut previous: Option<String> = None;
+    loop {
+        println!("\n== building and running \"{}\" ==", crate_name);
+        match watch::rebuild_and_run(crate_name) {
+            Ok(watch::Run::Output(output)) => {
+                match &previous {
+                    None => print!("{}", output),
+                    Some(previous) => match watch::format_changes(&watch::diff(previous, &output)) {
+                        Some(changes) => print!("{}", changes),
+                        None => println!("the output did not change"),
+                    },
+                }
+                previous = Some(output);
+            }
+            Ok(watch::Run::BuildFailed(errors)) => println!("{}\nthe workspace does not build", errors.trim_end()),
+            Err(error) => {
+                eprintln!("error: failed to run cargo: {}", error);
+                return ExitCode::FAILURE;
+            }
+        }
+
+        if let Err(error) = watcher.wait() {
+            eprintln!("error: failed to watch {}: {}", dir.display(), error);
+            return ExitCode::FAILURE;
+        }
+    }
+}
+
+// Asks the questions of a crate in the terminal, or answers them from a file so that a whole quiz can be scripted.
+fn run_quiz(crate_name: &str, answers: Option<&str>) -> ExitCode {
+    let questions = match quiz::load(crate_name) {
+        Ok(questions) if !questions.is_empty() => questions,
+        Ok(_) => {
+            eprintln!("error: the quiz of \"{}\" has no questions", crate_name);
+            return ExitCode::FAILURE;
+        }
+        Err(error) if error.kind() == io::ErrorKind::NotFound => {
+            eprintln!(
+                "error: no quiz for \"{}\", expected one of: {}",
+                crate_name,
+                lessons::crate_names().join(", ")
+            );
+            return ExitCode::FAILURE;
+        }
+        Err(error) => {
+            eprintln!("error: failed to read the quiz: {}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut out = io::stdout();
+    let score = match answers {
+        Some(path) => match fs::File::open(path) {
+            Ok(file) => quiz::run(&questions, &mut BufReader::new(file), &mut out, true),
+            Err(error) => {
+                eprintln!("error: failed to read {}: {}", path, error);
+                return ExitCode::FAILURE;
+            }
+        },
+        None => quiz::run(&questions, &mut io::stdin().lock(), &mut out, false),
+    };
+
+    match score {
+        Ok(score) => {
+            println!("\nscore: {} of {}", score.correct, questions.len());
+            if score.answered < questions.len() {
+                println!("{} question(s) were not answered", questions.len() - score.answered);
+            }
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("error: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// The progress is only a convenience, so a run goes on without it if the progress file cannot be read.
+fn load_progress() -> Option<(PathBuf, Progress)> {
+    let Some(path) = progress::default_path() else {
+        eprintln!("warning: the progress is not recorded, because the data folder could not be found");
+        return None;
+    };
+    match Progress::load(&path) {
+        Ok(progress) => Some((path, progress)),
+        Err(error) => {
+            eprintln!("warning: the progress is not recorded: {}", error);
+            None
+        }
+    }
+}
+
+// Unlike "run", the progress commands fail if the progress cannot be read.
+fn required_progress() -> Result<(PathBuf, Progress), ExitCode> {
+    let Some(path) = progress::default_path() else {
+        eprintln!("error: the data folder could not be found, set XDG_DATA_HOME or HOME");
+        return Err(ExitCode::FAILURE);
+    };
+    match Progress::load(&path) {
+        Ok(progress) => Ok((path, progress)),
+        Err(error) => {
+            eprintln!("error: {}", error);
+            Err(ExitCode::FAILURE)
+        }
+    }
+}
+
+fn show_progress() -> ExitCode {
+    let (path, progress) = match required_progress() {
+        Ok(loaded) => loaded,
+        Err(code) => return code,
+    };
+
+    let summary = progress.summary();
+    for crate_summary in &summary {
+        let last_run = match crate_summary.last_run {
+            Some(time) => format!("last run {}", progress::format_time(time)),
+            None => String::new(),
+        };
+        let line = format!(
+            "{:<16} {:>2}/{:<2}  {}",
+            crate_summary.crate_name, crate_summary.done, crate_summary.total, last_run
+        );
+        println!("{}", line.trim_end());
+    }
+
+    let done: usize = summary.iter().map(|crate_summary| crate_summary.done).sum();
+    let total: usize = summary.iter().map(|crate_summary| crate_summary.total).sum();
+    println!("\nran {} of {} lesson(s), saved in {}", done, total, path.display());
+    ExitCode::SUCCESS
+}
+
+fn next() -> ExitCode {
+    let (_, progress) = match required_progress() {
+        Ok(loaded) => loaded,
+        Err(code) => return code,
+    };
+
+    match progress.next() {
+        Some(lesson) => {
+            let topic = curriculum::find(lesson.crate_name()).map_or(lesson.crate_name(), |topic| topic.title);
+            println!("next lesson: {} ({})", lesson.id, topic);
+            println!("run it with: cargo run -p main -- run {}", lesson.id);
+        }
+        None => println!("every lesson has been run, try the exercises with \"main check\""),
+    }
+    ExitCode::SUCCESS
+}
+
+fn reset() -> ExitCode {
+    let Some(path) = progress::default_path() else {
+        eprintln!("error: the data folder could not be found, set XDG_DATA_HOME or HOME");
+        return ExitCode::FAILURE;
+    };
+
+    match fs::remove_file(&path) {
+        Ok(()) => println!("cleared the progress in {}", path.display()),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => println!("there is no progress to clear"),
+        Err(error) => {
+            eprintln!("error: failed to remove {}: {}", path.display(), error);
+            return ExitCode::FAILURE;
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+// Reads a file, or stdin if there is no file.
+fn read_input(file: Option<&str>) -> io::Result<String> {
+    match file {
+        Some(path) => fs::read_to_string(path),
+        None => {
+            let mut text = String::new();
+            io::stdin().read_to_string(&mut text)?;
+            Ok(text)
+        }
+    }
+}
+
+fn playground(file: Option<&str>, timeout_secs: Option<u64>) -> ExitCode {
+    let snippet = match read_input(file) {
+        Ok(snippet) => snippet,
+        Err(error) => {
+            eprintln!("error: failed to read the snippet: {}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let timeout = timeout_secs.map(Duration::from_secs).unwrap_or(playground::DEFAULT_TIMEOUT);
+    match playground::run(&snippet, timeout) {
+        Ok(PlaygroundOutcome::Ran { stdout, stderr, status }) => {
+            print!("{}", stdout);
+            eprint!("{}", stderr);
+            if status.success() {
+                ExitCode::SUCCESS
+            } else {
+                println!("-- the snippet exited with {}", status);
+                ExitCode::FAILURE
+            }
+        }
+        Ok(PlaygroundOutcome::CompileFailed(diagnostics)) => {
+            eprint!("{}", diagnostics);
+            println!("-- the snippet does not compile");
+            ExitCode::FAILURE
+        }
+        Ok(PlaygroundOutcome::TimedOut { stdout, stderr }) => {
+            print!("{}", stdout);
+            eprint!("{}", stderr);
+            println!("-- the snippet was stopped after {} second(s)", timeout.as_secs());
+            ExitCode::FAILURE
+        }
+        Err(error) => {
+            eprintln!("error: failed to run the snippet: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// Meant for the output of "cargo build --message-format json" or "rustc --error-format json".
+fn diagnose(file: Option<&str>) -> ExitCode {
+    let json = match read_input(file) {
+        Ok(json) => json,
+        Err(error) => {
+            eprintln!("error: failed to read the diagnostics: {}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let diagnostics = diagnostics::parse(&json);
+    if diagnostics.is_empty() {
+        println!("no diagnostics were found, expected the JSON of \"cargo build --message-format json\"");
+        return ExitCode::SUCCESS;
+    }
+    print!("{}", diagnostics::explain(&diagnostics));
+    if diagnostics.iter().any(|diagnostic| diagnostic.level == "error") {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+// With a file, the whole program is run. Without one, every statement is run as soon as it is complete,
+// and a statement that fails is forgotten, so the next one can be typed as if it had never been there.
+#[cfg(feature = "ownership")]
+fn simulate(file: Option<&str>) -> ExitCode {
+    use ownership::vi_borrow_simulator::{self, Simulator};
+    use std::io::{BufRead, IsTerminal, Write};
+
+    let mut simulator = Simulator::new();
+    let mut failed = false;
+    let mut report = |simulator: &Simulator, outcome: vi_borrow_simulator::Outcome| {
+        for step in &outcome.steps {
+            print!("{}", step);
+        }
+        if let Some(error) = outcome.error {
+            print!("{}", error.render(simulator.source()));
+            if let Some(hint) = error.code.and_then(diagnostics::hint_for) {
+                println!("hint[{}]: {}\n  lesson: {}", hint.code, hint.hint, hint.see);
+            }
+            failed = true;
+        }
+    };
+
+    if file.is_some() {
+        let code = match read_input(file) {
+            Ok(code) => code,
+            Err(error) => {
+                eprintln!("error: failed to read the program: {}", error);
+                return ExitCode::FAILURE;
+            }
+        };
+        let outcome = simulator.run(&code);
+        report(&simulator, outcome);
+    } else {
+        let stdin = io::stdin();
+        let interactive = stdin.is_terminal();
+        let mut pending = String::new();
+        loop {
+            if interactive {
+                print!("{}", if pending.is_empty() { "> " } else { "... " });
+                let _ = io::stdout().flush();
+            }
+            let mut line = String::new();
+            match stdin.lock().read_line(&mut line) {
+                Ok(0) => break,
+                Ok(_) => pending.push_str(&line),
+                Err(error) => {
+                    eprintln!("error: failed to read the program: {}", error);
+                    return ExitCode::FAILURE;
+                }
+            }
+            if pending.trim().is_empty() {
+                pending.clear();
+            } else if vi_borrow_simulator::is_complete(&pending) {
+                let outcome = simulator.run(pending.trim_end());
+                report(&simulator, outcome);
+                pending.clear();
+            }
+        }
+        // Whatever is left at the end is run as it is, to show what is missing.
+        if !pending.trim().is_empty() {
+            let outcome = simulator.run(pending.trim_end());
+            report(&simulator, outcome);
+        }
+    }
+
+    if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS }
+}
+
+#[cfg(not(feature = "ownership"))]
+fn simulate(_file: Option<&str>) -> ExitCode {
+    eprintln!("error: {}", left_out("ownership"));
+    ExitCode::FAILURE
 }
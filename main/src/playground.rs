@@ -0,0 +1,214 @@
+// "main playground" compiles and runs a snippet of code, such as a block that is commented out in a lesson, without touching the lessons themselves.
+// The snippet is put into a temporary crate, which depends on the lesson crates it mentions, so "ownership::..." or "use lifetimes::..." work as expected.
+// A snippet without a "fn main" is treated as the body of one.
+// The temporary crate is built with the same cargo as the workspace, and is removed again once the snippet has run.
+
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::curriculum;
+use crate::diagnostics;
+use crate::source;
+
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+// Every playground of this process gets its own number, so that playgrounds running at the same time do not share a crate.
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug)]
+pub enum Outcome {
+    // The snippet compiled and ran until it exited.
+    Ran {
+        stdout: String,
+        stderr: String,
+        status: ExitStatus,
+    },
+    // The snippet did not compile, with the diagnostics of rustc and the lessons that explain them.
+    CompileFailed(String),
+    // The snippet was still running after the timeout and was stopped, with what it had printed until then.
+    TimedOut { stdout: String, stderr: String },
+}
+
+// The lesson crates that a snippet mentions, such as "ownership" in "ownership::borrowing::borrowing_testing()".
+pub fn used_crates(snippet: &str) -> Vec<&'static str> {
+    curriculum::TOPICS
+        .iter()
+        .map(|topic| topic.crate_name)
+        .filter(|crate_name| {
+            snippet.match_indices(crate_name).any(|(at, _)| {
+                let before = snippet[..at].chars().next_back();
+                let after = snippet[at + crate_name.len()..].chars().next();
+                !before.is_some_and(is_identifier) && !after.is_some_and(is_identifier)
+            })
+        })
+        .collect()
+}
+
+fn is_identifier(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+// The "main.rs" of the temporary crate.
+// The snippet keeps its own line numbers, so the line numbers in the diagnostics are the lines of the snippet.
+// Unused code is allowed, since a snippet is usually only a part of a lesson.
+pub fn main_rs(snippet: &str) -> String {
+    let has_main = snippet.lines().any(|line| {
+        let line = line.trim_start();
+        line.starts_with("fn main(") || line.starts_with("pub fn main(")
+    });
+    if has_main {
+        format!("#![allow(unused)] {}\n", snippet)
+    } else {
+        format!("#![allow(unused)] fn main() {{ {}\n}}\n", snippet)
+    }
+}
+
+// Removes the temporary crate when the playground is done, even if building or running the snippet failed.
+struct TempCrate {
+    dir: PathBuf,
+    name: String,
+}
+
+impl Drop for TempCrate {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.dir);
+        let _ = remove_build_files(&self.name);
+    }
+}
+
+// The snippets are built in a target folder of their own inside the workspace's "target", so the lesson crates (and tokio) are only compiled once.
+fn target_dir() -> PathBuf {
+    source::workspace_root().join("target").join("playground")
+}
+
+// Cargo names the build files of a crate after it, such as "playground_1_1", "playground_1_1.d" or "playground_1_1-<hash>".
+// Only those count: "playground_1_10" is the crate of another snippet.
+pub fn is_build_file_of(file_name: &str, name: &str) -> bool {
+    file_name
+        .strip_prefix(name)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with(['-', '.']))
+}
+
+// The dependencies stay in the shared target folder, but the files that belong to the snippet itself are removed.
+fn remove_build_files(name: &str) -> io::Result<()> {
+    let debug = target_dir().join("debug");
+    for dir in [debug.clone(), debug.join("deps"), debug.join(".fingerprint")] {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries {
+            let entry = entry?;
+            if is_build_file_of(&entry.file_name().to_string_lossy(), name) {
+                let path = entry.path();
+                let _ = if path.is_dir() { fs::remove_dir_all(&path) } else { fs::remove_file(&path) };
+            }
+        }
+    }
+    Ok(())
+}
+
+fn create_crate(snippet: &str) -> io::Result<TempCrate> {
+    let name = format!("playground_{}_{}", std::process::id(), NEXT_ID.fetch_add(1, Ordering::Relaxed));
+    let temp = TempCrate {
+        dir: env::temp_dir().join(format!("rust-lessons-{}", name)),
+        name,
+    };
+    fs::create_dir_all(temp.dir.join("src"))?;
+
+    let root = source::workspace_root();
+    let mut manifest = format!(
+        "[package]\nname = \"{}\"\nversion = \"0.1.0\"\nedition = \"2024\"\n\n[dependencies]\n",
+        temp.name
+    );
+    for crate_name in used_crates(snippet) {
+        manifest.push_str(&format!("{} = {{ path = {:?} }}\n", crate_name, root.join(crate_name)));
+    }
+    // The empty workspace keeps cargo from looking for a workspace above the temporary folder.
+    manifest.push_str("\n[workspace]\n");
+    fs::write(temp.dir.join("Cargo.toml"), manifest)?;
+    fs::write(temp.dir.join("src").join("main.rs"), main_rs(snippet))?;
+
+    // The lock file of the workspace is reused, so the snippet gets the same versions of the dependencies (and does not need to download anything new).
+    if let Err(error) = fs::copy(root.join("Cargo.lock"), temp.dir.join("Cargo.lock"))
+        && error.kind() != io::ErrorKind::NotFound
+    {
+        return Err(error);
+    }
+    Ok(temp)
+}
+
+// Builds and runs a snippet, and stops it if it runs longer than "timeout".
+// An error is only returned if the temporary crate could not be created, or cargo or the program could not be started.
+pub fn run(snippet: &str, timeout: Duration) -> io::Result<Outcome> {
+    let temp = create_crate(snippet)?;
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let build = Command::new(cargo)
+        .current_dir(&temp.dir)
+        .env("CARGO_TARGET_DIR", target_dir())
+        .env("CARGO_INCREMENTAL", "0")
+        .args(["build", "--quiet", "--color", "never", "--message-format", "json"])
+        .output()?;
+    if !build.status.success() {
+        // Errors that do not come from rustc (such as a dependency that cannot be found) are only printed by cargo itself.
+        let diagnostics = diagnostics::parse(&String::from_utf8_lossy(&build.stdout));
+        return Ok(Outcome::CompileFailed(if diagnostics.is_empty() {
+            String::from_utf8_lossy(&build.stderr).into_owned()
+        } else {
+            diagnostics::explain(&diagnostics)
+        }));
+    }
+
+    let program = target_dir().join("debug").join(format!("{}{}", temp.name, env::consts::EXE_SUFFIX));
+    run_with_timeout(&program, &temp.dir, timeout)
+}
+
+fn run_with_timeout(program: &Path, dir: &Path, timeout: Duration) -> io::Result<Outcome> {
+    let mut child = Command::new(program)
+        .current_dir(dir)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    // The output is read on threads of its own, so a program that prints a lot does not block on a full pipe while it is waited on.
+    let stdout = read_on_thread(child.stdout.take());
+    let stderr = read_on_thread(child.stderr.take());
+
+    let started = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break Some(status);
+        }
+        if started.elapsed() >= timeout {
+            child.kill()?;
+            child.wait()?;
+            break None;
+        }
+        thread::sleep(POLL_INTERVAL);
+    };
+
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+    Ok(match status {
+        Some(status) => Outcome::Ran { stdout, stderr, status },
+        None => Outcome::TimedOut { stdout, stderr },
+    })
+}
+
+fn read_on_thread(pipe: Option<impl Read + Send + 'static>) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut bytes = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut bytes);
+        }
+        String::from_utf8_lossy(&bytes).into_owned()
+    })
+}
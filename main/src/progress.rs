@@ -0,0 +1,175 @@
+// Remembers which lessons a learner has run, and when, in a JSON file in the data folder of the user:
+//      Linux       $XDG_DATA_HOME/rust-lessons/progress.json, or ~/.local/share/rust-lessons/progress.json
+//      macOS       ~/Library/Application Support/rust-lessons/progress.json
+//      Windows     %APPDATA%\rust-lessons\progress.json
+// The times are stored as seconds since the Unix epoch, so the file does not depend on the time zone of the learner.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::curriculum;
+use crate::lessons::{self, Lesson};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LessonRecord {
+    pub first_run: u64,
+    pub last_run: u64,
+    pub runs: u32,
+}
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Progress {
+    // Keyed by lesson id, such as "ownership::i_borrow_move::demo_ownership".
+    pub lessons: BTreeMap<String, LessonRecord>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct CrateSummary {
+    pub crate_name: &'static str,
+    pub done: usize,
+    pub total: usize,
+    // The last time any lesson of the crate was run.
+    pub last_run: Option<u64>,
+}
+
+impl Progress {
+    // A missing file means that nothing has been run yet.
+    pub fn load(path: &Path) -> io::Result<Progress> {
+        match fs::read_to_string(path) {
+            Ok(json) => serde_json::from_str(&json).map_err(|error| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{} is not a valid progress file ({}), \"main reset\" clears it", path.display(), error),
+                )
+            }),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Progress::default()),
+            Err(error) => Err(error),
+        }
+    }
+
+    // The file is written next to its final path first and then renamed, so an interrupted write cannot corrupt it.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(self).expect("the progress is always valid JSON");
+        let temporary = path.with_extension("json.tmp");
+        fs::write(&temporary, json + "\n")?;
+        fs::rename(&temporary, path)
+    }
+
+    pub fn record(&mut self, lesson_id: &str, now: u64) {
+        self.lessons
+            .entry(lesson_id.to_string())
+            .and_modify(|record| {
+                record.last_run = now;
+                record.runs += 1;
+            })
+            .or_insert(LessonRecord {
+                first_run: now,
+                last_run: now,
+                runs: 1,
+            });
+    }
+
+    pub fn has_run(&self, lesson_id: &str) -> bool {
+        self.lessons.contains_key(lesson_id)
+    }
+
+    // How many lessons of each crate have been run, in curriculum order.
+    // Lessons in the file that are no longer in the registry (such as renamed lessons) are not counted.
+    pub fn summary(&self) -> Vec<CrateSummary> {
+        curriculum_order()
+            .into_iter()
+            .map(|(crate_name, lessons)| {
+                let records: Vec<&LessonRecord> = lessons
+                    .iter()
+                    .filter_map(|lesson| self.lessons.get(lesson.id))
+                    .collect();
+                CrateSummary {
+                    crate_name,
+                    done: records.len(),
+                    total: lessons.len(),
+                    last_run: records.iter().map(|record| record.last_run).max(),
+                }
+            })
+            .collect()
+    }
+
+    // The first lesson that has not been run yet, following the curriculum and then the order of the registry.
+    pub fn next(&self) -> Option<&'static Lesson> {
+        curriculum_order()
+            .into_iter()
+            .flat_map(|(_, lessons)| lessons)
+            .find(|lesson| !self.has_run(lesson.id))
+    }
+}
+
+// The lessons grouped by crate, with the crates in curriculum order.
+fn curriculum_order() -> Vec<(&'static str, Vec<&'static Lesson>)> {
+    let topics = curriculum::ordered(curriculum::TOPICS).expect("the curriculum has no cycles");
+    topics
+        .into_iter()
+        .map(|topic| (topic.crate_name, lessons::in_crate(topic.crate_name)))
+        .filter(|(_, lessons)| !lessons.is_empty())
+        .collect()
+}
+
+// The data folder of the user, or None if it cannot be found (such as when HOME is not set).
+fn data_dir() -> Option<PathBuf> {
+    if let Some(dir) = env::var_os("XDG_DATA_HOME").filter(|dir| !dir.is_empty()) {
+        return Some(PathBuf::from(dir));
+    }
+    if cfg!(windows) {
+        return env::var_os("APPDATA").map(PathBuf::from);
+    }
+    let home = PathBuf::from(env::var_os("HOME")?);
+    if cfg!(target_os = "macos") {
+        Some(home.join("Library").join("Application Support"))
+    } else {
+        Some(home.join(".local").join("share"))
+    }
+}
+
+pub fn default_path() -> Option<PathBuf> {
+    data_dir().map(|dir| dir.join("rust-lessons").join("progress.json"))
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
+
+// Formats a time as "YYYY-MM-DD HH:MM UTC", without pulling in a date crate.
+// The date is worked out from the number of days since 1970-01-01 (the "civil from days" algorithm by Howard Hinnant).
+pub fn format_time(seconds: u64) -> String {
+    let days = (seconds / 86_400) as i64;
+    let minutes_of_day = seconds % 86_400 / 60;
+
+    let shifted = days + 719_468;
+    let era = shifted.div_euclid(146_097);
+    let day_of_era = shifted.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02} UTC",
+        year,
+        month,
+        day,
+        minutes_of_day / 60,
+        minutes_of_day % 60
+    )
+}
@@ -0,0 +1,261 @@
+// Every lesson crate has a question bank in "<crate>/quiz/*.toml", and "main quiz <crate>" asks its questions one by one.
+// A question is either a multiple-choice question:
+//      prompt = 'What does "..." do?'
+//      choices = ["...", "..."]
+//      answer = "b"
+// or a "does this compile?" question about a piece of code:
+//      code = '''<the code>'''
+//      compiles = false
+// Every question also has an "explanation" of the correct answer, and "see", the path of the lesson function that teaches it.
+// The code of the "does this compile?" questions is compiled by the tests of "main", so a wrong answer in a bank is caught.
+
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::source;
+
+pub const DEFAULT_COMPILES_PROMPT: &str = "Does this compile?";
+
+#[derive(Debug, PartialEq)]
+pub enum Kind {
+    // The index of the correct choice.
+    Choice { choices: Vec<String>, answer: usize },
+    Compiles(bool),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Question {
+    pub prompt: String,
+    pub code: Option<String>,
+    pub kind: Kind,
+    pub explanation: String,
+    pub see: String,
+}
+
+// The question as it is written in the TOML file, before it is checked.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawQuestion {
+    prompt: Option<String>,
+    code: Option<String>,
+    choices: Option<Vec<String>>,
+    answer: Option<String>,
+    compiles: Option<bool>,
+    explanation: String,
+    see: String,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Bank {
+    question: Vec<RawQuestion>,
+}
+
+impl RawQuestion {
+    fn check(self) -> Result<Question, String> {
+        let kind = match (self.choices, self.answer, self.compiles) {
+            (Some(choices), Some(answer), None) => {
+                if choices.len() < 2 || choices.len() > 26 {
+                    return Err(format!("needs between 2 and 26 choices, has {}", choices.len()));
+                }
+                let index = choice_index(&answer)
+                    .filter(|index| *index < choices.len())
+                    .ok_or(format!("answer \"{}\" is not one of the choices", answer))?;
+                Kind::Choice {
+                    choices,
+                    answer: index,
+                }
+            }
+            (None, None, Some(compiles)) => {
+                if self.code.is_none() {
+                    return Err("\"compiles\" needs the \"code\" to compile".to_string());
+                }
+                Kind::Compiles(compiles)
+            }
+            _ => return Err("needs either \"choices\" and \"answer\", or \"compiles\"".to_string()),
+        };
+
+        let prompt = match (&kind, self.prompt) {
+            (_, Some(prompt)) => prompt,
+            (Kind::Compiles(_), None) => DEFAULT_COMPILES_PROMPT.to_string(),
+            (Kind::Choice { .. }, None) => return Err("needs a \"prompt\"".to_string()),
+        };
+
+        Ok(Question {
+            prompt,
+            code: self.code.map(|code| code.trim_end().to_string()),
+            kind,
+            explanation: self.explanation.trim().to_string(),
+            see: self.see,
+        })
+    }
+}
+
+// Turns "a", "b", ... into 0, 1, ...
+fn choice_index(text: &str) -> Option<usize> {
+    match text.trim().to_ascii_lowercase().as_bytes() {
+        [letter @ b'a'..=b'z'] => Some((letter - b'a') as usize),
+        _ => None,
+    }
+}
+
+fn choice_letter(index: usize) -> char {
+    (b'a' + index as u8) as char
+}
+
+pub fn parse(toml_text: &str) -> Result<Vec<Question>, String> {
+    let bank: Bank = toml::from_str(toml_text).map_err(|error| error.to_string())?;
+    bank.question
+        .into_iter()
+        .enumerate()
+        .map(|(index, question)| question.check().map_err(|error| format!("question {}: {}", index + 1, error)))
+        .collect()
+}
+
+// Reads every question bank of a crate, with the files in alphabetical order.
+pub fn load(crate_name: &str) -> io::Result<Vec<Question>> {
+    let dir = source::workspace_root().join(crate_name).join("quiz");
+    let mut files: Vec<_> = fs::read_dir(&dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<io::Result<_>>()?;
+    files.retain(|path| path.extension().is_some_and(|extension| extension == "toml"));
+    files.sort();
+
+    let mut questions = Vec::new();
+    for file in files {
+        let text = fs::read_to_string(&file)?;
+        let parsed = parse(&text)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", file.display(), error)))?;
+        questions.extend(parsed);
+    }
+    Ok(questions)
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Score {
+    pub correct: usize,
+    // The number of questions that were answered, which is less than the number of questions if the answers ran out.
+    pub answered: usize,
+}
+
+impl Question {
+    // Returns whether the answer is correct, or None if it is not an answer to this question at all.
+    fn is_correct(&self, answer: &str) -> Option<bool> {
+        match &self.kind {
+            Kind::Choice { choices, answer: correct } => choice_index(answer)
+                .filter(|index| *index < choices.len())
+                .map(|index| index == *correct),
+            Kind::Compiles(compiles) => match answer.trim().to_ascii_lowercase().as_str() {
+                "y" | "yes" => Some(*compiles),
+                "n" | "no" => Some(!*compiles),
+                _ => None,
+            },
+        }
+    }
+
+    fn expected_answers(&self) -> String {
+        match &self.kind {
+            Kind::Choice { choices, .. } => {
+                let letters: Vec<String> = (0..choices.len()).map(|index| choice_letter(index).to_string()).collect();
+                format!("{} or {}", letters[..letters.len() - 1].join(", "), letters[letters.len() - 1])
+            }
+            Kind::Compiles(_) => "yes or no".to_string(),
+        }
+    }
+
+    fn correct_answer(&self) -> String {
+        match &self.kind {
+            Kind::Choice { choices, answer } => format!("{}) {}", choice_letter(*answer), choices[*answer]),
+            Kind::Compiles(true) => "yes, it compiles".to_string(),
+            Kind::Compiles(false) => "no, it does not compile".to_string(),
+        }
+    }
+
+    fn write_to(&self, out: &mut dyn Write) -> io::Result<()> {
+        writeln!(out, "{}", self.prompt)?;
+        if let Some(code) = &self.code {
+            writeln!(out)?;
+            for line in code.lines() {
+                match line {
+                    "" => writeln!(out)?,
+                    line => writeln!(out, "    {}", line)?,
+                }
+            }
+        }
+        writeln!(out)?;
+        match &self.kind {
+            Kind::Choice { choices, .. } => {
+                for (index, choice) in choices.iter().enumerate() {
+                    writeln!(out, "  {}) {}", choice_letter(index), choice)?;
+                }
+            }
+            Kind::Compiles(_) => writeln!(out, "  yes / no")?,
+        }
+        Ok(())
+    }
+}
+
+// The place where the lesson behind a question is taught, such as "ownership/src/i_borrow_move.rs:17".
+fn see_location(see: &str) -> Option<String> {
+    let (file, line) = source::locate(see)?;
+    let relative = file.strip_prefix(source::workspace_root()).unwrap_or(&file).to_path_buf();
+    Some(format!("{}:{}", display_path(&relative), line))
+}
+
+fn display_path(path: &Path) -> String {
+    path.components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+// Asks the questions in order, reading one answer per line from "input".
+// Lines that are not an answer to the question (such as a typo) ask the same question again.
+// When the answers come from a file, "echo" writes each answer after the prompt, so the output reads like a session in the terminal.
+pub fn run(questions: &[Question], input: &mut dyn BufRead, out: &mut dyn Write, echo: bool) -> io::Result<Score> {
+    let mut score = Score {
+        correct: 0,
+        answered: 0,
+    };
+
+    for (number, question) in questions.iter().enumerate() {
+        writeln!(out, "\nQuestion {} of {}", number + 1, questions.len())?;
+        question.write_to(out)?;
+
+        let correct = loop {
+            write!(out, "> ")?;
+            out.flush()?;
+
+            let mut line = String::new();
+            if input.read_line(&mut line)? == 0 {
+                writeln!(out)?;
+                return Ok(score);
+            }
+            if echo {
+                writeln!(out, "{}", line.trim_end())?;
+            }
+            match question.is_correct(&line) {
+                Some(correct) => break correct,
+                None => writeln!(out, "please answer with {}", question.expected_answers())?,
+            }
+        };
+
+        score.answered += 1;
+        if correct {
+            score.correct += 1;
+            writeln!(out, "correct!")?;
+        } else {
+            writeln!(out, "not quite, the answer is {}", question.correct_answer())?;
+        }
+        writeln!(out, "{}", question.explanation)?;
+        match see_location(&question.see) {
+            Some(location) => writeln!(out, "see: {} ({})", question.see, location)?,
+            None => writeln!(out, "see: {}", question.see)?,
+        }
+    }
+
+    Ok(score)
+}
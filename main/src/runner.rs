@@ -0,0 +1,147 @@
+// Runs lessons one at a time and reports whether each one behaved as declared in the registry.
+// Some lessons panic on purpose (such as "pointers::cell::ref_cell_testing"), so every lesson runs inside "catch_unwind".
+// A panic is then just an outcome of the lesson: it passes if the lesson was declared to panic, and fails otherwise.
+// For scripts and editors, the reports can also be turned into JSON records with "to_json".
+
+use std::cell::{Cell, RefCell};
+use std::io::{self, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use crate::lessons::Lesson;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PanicDetails {
+    pub message: String,
+    // The location is written as "<file>:<line>:<column>".
+    pub location: Option<String>,
+}
+
+pub struct Report {
+    pub lesson: &'static Lesson,
+    pub panic: Option<PanicDetails>,
+    pub duration: Duration,
+}
+
+impl Report {
+    pub fn passed(&self) -> bool {
+        match (self.lesson.expected_panic, &self.panic) {
+            (None, None) => true,
+            (Some(expected), Some(panic)) => panic.message.contains(expected),
+            _ => false,
+        }
+    }
+
+    // "output" is what the lesson wrote while it ran.
+    pub fn to_record(&self, output: String) -> Record {
+        Record {
+            id: self.lesson.id,
+            crate_name: self.lesson.crate_name(),
+            duration_ms: self.duration.as_secs_f64() * 1000.0,
+            stdout: output,
+            panic: self.panic.clone(),
+            expected_panic: self.lesson.expected_panic,
+            passed: self.passed(),
+        }
+    }
+}
+
+// One lesson in the output of "main run --format json".
+#[derive(Debug, Serialize)]
+pub struct Record {
+    pub id: &'static str,
+    #[serde(rename = "crate")]
+    pub crate_name: &'static str,
+    pub duration_ms: f64,
+    pub stdout: String,
+    pub panic: Option<PanicDetails>,
+    pub expected_panic: Option<&'static str>,
+    pub passed: bool,
+}
+
+#[derive(Serialize)]
+struct Records<'a> {
+    lessons: &'a [Record],
+    passed: usize,
+    failed: usize,
+}
+
+// The records of a whole run, together with how many lessons passed and failed.
+pub fn to_json(records: &[Record]) -> String {
+    let passed = records.iter().filter(|record| record.passed).count();
+    let run = Records {
+        lessons: records,
+        passed,
+        failed: records.len() - passed,
+    };
+    serde_json::to_string_pretty(&run).expect("the records are always valid JSON")
+}
+
+thread_local! {
+    // The panic hook runs on the thread that panicked, which is the thread running the lesson.
+    static RUNNING_LESSON: Cell<bool> = const { Cell::new(false) };
+    static LAST_PANIC: RefCell<Option<PanicDetails>> = const { RefCell::new(None) };
+}
+
+static INSTALL_HOOK: Once = Once::new();
+
+// The default hook would print the panic of a lesson to stderr, so a hook that records it instead is installed once.
+// Panics that happen outside of a lesson (or on other threads) are still handed to the previous hook.
+fn install_hook() {
+    INSTALL_HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !RUNNING_LESSON.with(Cell::get) {
+                return previous(info);
+            }
+            let details = PanicDetails {
+                message: payload_message(info.payload()),
+                location: info.location().map(|location| location.to_string()),
+            };
+            LAST_PANIC.with(|last| *last.borrow_mut() = Some(details));
+        }));
+    });
+}
+
+// Runs a lesson with its output going to "out".
+// An error is only returned if the output itself could not be written.
+pub fn run(lesson: &'static Lesson, out: &mut dyn Write) -> io::Result<Report> {
+    install_hook();
+    LAST_PANIC.with(|last| last.borrow_mut().take());
+
+    RUNNING_LESSON.with(|running| running.set(true));
+    let started = Instant::now();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| (lesson.run)(out)));
+    let duration = started.elapsed();
+    RUNNING_LESSON.with(|running| running.set(false));
+
+    let panic = match result {
+        Ok(written) => {
+            written?;
+            None
+        }
+        Err(payload) => Some(LAST_PANIC.with(|last| last.borrow_mut().take()).unwrap_or(PanicDetails {
+            message: payload_message(payload.as_ref()),
+            location: None,
+        })),
+    };
+
+    Ok(Report {
+        lesson,
+        panic,
+        duration,
+    })
+}
+
+fn payload_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "<non-string panic payload>".to_string()
+    }
+}
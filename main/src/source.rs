@@ -0,0 +1,170 @@
+// Helpers for reading the source files of the lesson crates.
+// The lessons keep code that intentionally does not compile inside "/* ... */" blocks (see the README).
+// Every such block is annotated with a line comment right above it that says what happens when the block is uncommented:
+//      "// compile_fail: E0382"        The block fails to compile with the listed error code(s), separated by commas.
+//      "// snippet: <reason>"          The block is not meant to be compiled on its own, for the given reason.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expectation {
+    CompileFail(Vec<String>),
+    Snippet(String),
+    Missing,
+}
+
+#[derive(Debug)]
+pub struct BrokenBlock {
+    // Both line numbers are 1-based and point at the lines holding "/*" and "*/".
+    pub open_line: usize,
+    pub close_line: usize,
+    pub expectation: Expectation,
+}
+
+impl BrokenBlock {
+    // Returns the source with only this block uncommented.
+    // The comment markers are replaced with empty lines, so the line numbers in compiler errors still match the original file.
+    pub fn uncomment(&self, source: &str) -> String {
+        source
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                let number = index + 1;
+                if number == self.open_line || number == self.close_line {
+                    ""
+                } else {
+                    line
+                }
+            })
+            .collect::<Vec<&str>>()
+            .join("\n")
+    }
+}
+
+// Finds every "/* ... */" block whose markers sit on lines of their own.
+pub fn broken_blocks(source: &str) -> Vec<BrokenBlock> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut blocks = Vec::new();
+    let mut index = 0;
+
+    while index < lines.len() {
+        if lines[index].trim() != "/*" {
+            index += 1;
+            continue;
+        }
+
+        let open = index;
+        let Some(close) = (open + 1..lines.len()).find(|&i| lines[i].trim_start().starts_with("*/")) else {
+            break;
+        };
+
+        let expectation = match open.checked_sub(1).map(|i| lines[i].trim()) {
+            Some(line) => parse_expectation(line),
+            None => Expectation::Missing,
+        };
+
+        blocks.push(BrokenBlock {
+            open_line: open + 1,
+            close_line: close + 1,
+            expectation,
+        });
+        index = close + 1;
+    }
+
+    blocks
+}
+
+fn parse_expectation(line: &str) -> Expectation {
+    if let Some(codes) = line.strip_prefix("// compile_fail:") {
+        let codes: Vec<String> = codes
+            .split(',')
+            .map(|code| code.trim().to_string())
+            .filter(|code| !code.is_empty())
+            .collect();
+        if !codes.is_empty() {
+            return Expectation::CompileFail(codes);
+        }
+    }
+    if let Some(reason) = line.strip_prefix("// snippet:") {
+        return Expectation::Snippet(reason.trim().to_string());
+    }
+    Expectation::Missing
+}
+
+// The root of the workspace, which is the parent folder of the "main" crate.
+pub fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the main crate lives inside the workspace")
+        .to_path_buf()
+}
+
+// Lists every ".rs" file under "<crate>/src", sorted so that the order is stable.
+pub fn lesson_files(crate_name: &str) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    collect_rs_files(&workspace_root().join(crate_name).join("src"), &mut files)?;
+    files.sort();
+    Ok(files)
+}
+
+fn collect_rs_files(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_rs_files(&path, files)?;
+        } else if path.extension().is_some_and(|extension| extension == "rs") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+// Finds where an item such as "ownership::i_borrow_move::borrow_vs_moving_str" is defined, as a file and a 1-based line.
+// The path can also go through a type, such as "enums::shapes::Shapes::calc_area", in which case the file is the one of the module.
+pub fn locate(item_path: &str) -> Option<(PathBuf, usize)> {
+    let root = workspace_root();
+    let (file, line) = locate_with(item_path, |relative| fs::read_to_string(root.join(relative)).ok())?;
+    Some((root.join(file), line))
+}
+
+// The same as "locate", but the files are read with "read", which gets their path relative to the workspace root, such as "enums/src/shapes.rs".
+// This is how lessons are found in sources that were embedded into "main" when it was built.
+pub fn locate_with(item_path: &str, read: impl Fn(&str) -> Option<String>) -> Option<(String, usize)> {
+    let segments: Vec<&str> = item_path.split("::").collect();
+    let (crate_name, rest) = segments.split_first()?;
+    let (item, modules) = rest.split_last()?;
+
+    // The longest prefix of the path that is a module file wins, and the rest of the path is inside that file.
+    let (file, source) = (0..=modules.len()).rev().find_map(|count| {
+        let file = match count {
+            0 => format!("{}/src/lib.rs", crate_name),
+            _ => format!("{}/src/{}.rs", crate_name, modules[..count].join("/")),
+        };
+        read(&file).map(|source| (file, source))
+    })?;
+
+    // Definitions inside the broken "/* */" blocks do not count, since they are not compiled.
+    let blocks = broken_blocks(&source);
+    let index = source.lines().enumerate().position(|(index, line)| {
+        let number = index + 1;
+        !line.trim_start().starts_with("//")
+            && !blocks
+                .iter()
+                .any(|block| block.open_line <= number && number <= block.close_line)
+            && defines(line, item)
+    })?;
+    Some((file, index + 1))
+}
+
+// Whether a line defines a function, struct, enum or trait with exactly the given name.
+fn defines(line: &str, item: &str) -> bool {
+    ["fn", "struct", "enum", "trait"].iter().any(|keyword| {
+        let pattern = format!("{} {}", keyword, item);
+        line.match_indices(&pattern).any(|(at, _)| {
+            let after = &line[at + pattern.len()..];
+            (at == 0 || line[..at].ends_with(' ')) && !after.starts_with(|c: char| c.is_alphanumeric() || c == '_')
+        })
+    })
+}
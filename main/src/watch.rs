@@ -0,0 +1,309 @@
+// "main watch <crate>" waits for the source files of a lesson crate to change, then rebuilds the workspace and runs the lessons of the crate again.
+// The lessons are compiled into "main" itself, so they are run by a fresh "cargo run -p main -- run --crate <crate>", which also does the rebuild.
+// On Linux the "src" folder is watched with inotify, so nothing runs until a file really changes.
+// Everywhere else (or if inotify is not available) the modification times of the files are checked twice a second instead.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use crate::lessons;
+use crate::source;
+
+// Editors often save a file in several steps (such as writing a temporary file and renaming it).
+// Changes that come within this time of each other are treated as a single change.
+const SETTLE_TIME: Duration = Duration::from_millis(200);
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+pub enum Watcher {
+    #[cfg(target_os = "linux")]
+    Inotify(inotify::Inotify),
+    Polling(Polling),
+}
+
+impl Watcher {
+    pub fn new(dir: &Path) -> io::Result<Watcher> {
+        #[cfg(target_os = "linux")]
+        if let Ok(inotify) = inotify::Inotify::new(dir) {
+            return Ok(Watcher::Inotify(inotify));
+        }
+        Polling::new(dir).map(Watcher::Polling)
+    }
+
+    // Blocks until something in the folder has changed.
+    pub fn wait(&mut self) -> io::Result<()> {
+        match self {
+            #[cfg(target_os = "linux")]
+            Watcher::Inotify(inotify) => inotify.wait(),
+            Watcher::Polling(polling) => polling.wait(),
+        }
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            #[cfg(target_os = "linux")]
+            Watcher::Inotify(_) => "inotify",
+            Watcher::Polling(_) => "polling",
+        }
+    }
+}
+
+pub struct Polling {
+    dir: PathBuf,
+    times: BTreeMap<PathBuf, SystemTime>,
+}
+
+impl Polling {
+    fn new(dir: &Path) -> io::Result<Polling> {
+        Ok(Polling {
+            dir: dir.to_path_buf(),
+            times: modification_times(dir)?,
+        })
+    }
+
+    fn wait(&mut self) -> io::Result<()> {
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            let times = modification_times(&self.dir)?;
+            if times != self.times {
+                // The files are read again after the change settles, so the next wait does not see the rest of the same save.
+                thread::sleep(SETTLE_TIME);
+                self.times = modification_times(&self.dir)?;
+                return Ok(());
+            }
+        }
+    }
+}
+
+// Every file under "dir", with the time it was last modified.
+fn modification_times(dir: &Path) -> io::Result<BTreeMap<PathBuf, SystemTime>> {
+    let mut times = BTreeMap::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        if metadata.is_dir() {
+            times.extend(modification_times(&entry.path())?);
+        } else {
+            times.insert(entry.path(), metadata.modified()?);
+        }
+    }
+    Ok(times)
+}
+
+// A small wrapper around the inotify calls of "libc", since only a handful of them are needed.
+#[cfg(target_os = "linux")]
+mod inotify {
+    use std::ffi::CString;
+    use std::fs;
+    use std::io;
+    use std::os::unix::ffi::OsStrExt;
+    use std::path::{Path, PathBuf};
+
+    use super::SETTLE_TIME;
+
+    const EVENTS: u32 = libc::IN_CLOSE_WRITE
+        | libc::IN_MODIFY
+        | libc::IN_CREATE
+        | libc::IN_DELETE
+        | libc::IN_MOVED_FROM
+        | libc::IN_MOVED_TO;
+
+    pub struct Inotify {
+        fd: libc::c_int,
+        dir: PathBuf,
+    }
+
+    impl Inotify {
+        pub fn new(dir: &Path) -> io::Result<Inotify> {
+            // SAFETY: "inotify_init1" only takes flags, and the returned descriptor is owned by "Inotify" from here on.
+            let fd = unsafe { libc::inotify_init1(libc::IN_CLOEXEC) };
+            if fd < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            let inotify = Inotify {
+                fd,
+                dir: dir.to_path_buf(),
+            };
+            inotify.watch_all(dir)?;
+            Ok(inotify)
+        }
+
+        // inotify does not watch sub-folders on its own, so every folder gets its own watch.
+        // Adding a watch to a folder that is already watched does nothing, so this can run again after every change to pick up new folders.
+        fn watch_all(&self, dir: &Path) -> io::Result<()> {
+            let path = CString::new(dir.as_os_str().as_bytes())?;
+            // SAFETY: "path" is a valid C string that lives until the call returns.
+            if unsafe { libc::inotify_add_watch(self.fd, path.as_ptr(), EVENTS) } < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            for entry in fs::read_dir(dir)? {
+                let entry = entry?;
+                if entry.file_type()?.is_dir() {
+                    self.watch_all(&entry.path())?;
+                }
+            }
+            Ok(())
+        }
+
+        pub fn wait(&mut self) -> io::Result<()> {
+            // The events themselves are not looked at: any event means that the lessons need to run again.
+            self.read_events(-1)?;
+            while self.read_events(SETTLE_TIME.as_millis() as libc::c_int)? {}
+            self.watch_all(&self.dir)
+        }
+
+        // Waits up to "timeout" milliseconds (or forever if it is negative) for events, and returns whether there were any.
+        fn read_events(&self, timeout: libc::c_int) -> io::Result<bool> {
+            let mut poll_fd = libc::pollfd {
+                fd: self.fd,
+                events: libc::POLLIN,
+                revents: 0,
+            };
+            // SAFETY: "poll_fd" is a single valid "pollfd", which matches the count of 1.
+            let ready = unsafe { libc::poll(&mut poll_fd, 1, timeout) };
+            if ready < 0 {
+                let error = io::Error::last_os_error();
+                return if error.kind() == io::ErrorKind::Interrupted { Ok(false) } else { Err(error) };
+            }
+            if ready == 0 {
+                return Ok(false);
+            }
+
+            let mut buffer = [0u8; 4096];
+            // SAFETY: the buffer is valid for writes of its whole length.
+            let read = unsafe { libc::read(self.fd, buffer.as_mut_ptr().cast(), buffer.len()) };
+            if read < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            Ok(true)
+        }
+    }
+
+    impl Drop for Inotify {
+        fn drop(&mut self) {
+            // SAFETY: the descriptor is owned by "Inotify" and is not used after this.
+            unsafe { libc::close(self.fd) };
+        }
+    }
+}
+
+pub enum Run {
+    // What "main run --crate <crate>" printed.
+    Output(String),
+    // The workspace did not build, with the errors from cargo.
+    BuildFailed(String),
+}
+
+// The features that this "main" was built with: its lesson crates, and the opt-in features that are left out of "default" (see "main/Cargo.toml").
+pub fn features() -> Vec<&'static str> {
+    let opt_in = [("count_allocations", cfg!(feature = "count_allocations"))];
+    let mut features = lessons::crate_names();
+    features.extend(opt_in.iter().filter(|(_, enabled)| *enabled).map(|(feature, _)| *feature));
+    features
+}
+
+// Rebuilds the workspace and runs every lesson of the crate in a new "main".
+// Cargo is the same one that runs "main" when it is started with "cargo run", and "cargo" from the PATH otherwise.
+// The new "main" is built with the same features as this one, so watching does not build the crates that were left out,
+// and keeps counting the allocations if this one does.
+pub fn rebuild_and_run(crate_name: &str) -> io::Result<Run> {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let features = features().join(",");
+    let output = Command::new(cargo)
+        .current_dir(source::workspace_root())
+        .args(["run", "--quiet", "--color", "never", "-p", "main", "--no-default-features"])
+        .args(["--features", &features])
+        .args(["--", "run", "--crate", crate_name])
+        .output()?;
+
+    // A finished run always ends with its summary, even when some lessons failed.
+    let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+    if stdout.contains("\nran ") {
+        Ok(Run::Output(stdout))
+    } else {
+        Ok(Run::BuildFailed(String::from_utf8_lossy(&output.stderr).into_owned()))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Line<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+// A line by line diff of two outputs, using the longest common subsequence of their lines.
+// Lesson outputs are short, so the simple quadratic table is more than fast enough.
+pub fn diff<'a>(old: &'a str, new: &'a str) -> Vec<Line<'a>> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    // "common[i][j]" is the length of the longest common subsequence of "old[i..]" and "new[j..]".
+    let mut common = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = if old[i] == new[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            lines.push(Line::Same(old[i]));
+            i += 1;
+            j += 1;
+        } else if common[i + 1][j] >= common[i][j + 1] {
+            lines.push(Line::Removed(old[i]));
+            i += 1;
+        } else {
+            lines.push(Line::Added(new[j]));
+            j += 1;
+        }
+    }
+    lines.extend(old[i..].iter().map(|line| Line::Removed(line)));
+    lines.extend(new[j..].iter().map(|line| Line::Added(line)));
+    lines
+}
+
+// Formats the changed lines as "- " and "+ " lines, with the "== <lesson> ==" header of the lesson they belong to.
+// Returns None if nothing changed.
+pub fn format_changes(lines: &[Line]) -> Option<String> {
+    let mut changes = String::new();
+    let mut header = None;
+    let mut header_shown = false;
+
+    for line in lines {
+        let (prefix, text) = match line {
+            Line::Same(text) => {
+                if text.starts_with("== ") {
+                    header = Some(*text);
+                    header_shown = false;
+                }
+                continue;
+            }
+            Line::Removed(text) => ("- ", text),
+            Line::Added(text) => ("+ ", text),
+        };
+        if let Some(header) = header.filter(|_| !header_shown) {
+            changes.push_str(header);
+            changes.push('\n');
+            header_shown = true;
+        }
+        changes.push_str(prefix);
+        changes.push_str(text);
+        changes.push('\n');
+    }
+
+    (!changes.is_empty()).then_some(changes)
+}
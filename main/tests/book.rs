@@ -0,0 +1,95 @@
+use std::fs;
+use std::path::Path;
+
+use main::book::{self, Format, Segment};
+use main::curriculum;
+use main::source::Expectation;
+
+const SOURCE: &str = "\
+// A lesson starts with some narration.
+// It can take several lines.
+pub fn lesson() {
+    // Comments inside a function stay with the code.
+    let x = 5;
+
+    // compile_fail: E0384
+    /*
+    x = 6;
+    */
+}
+";
+
+#[test]
+fn source_is_split_into_prose_code_and_broken_examples() {
+    assert_eq!(
+        book::segments(SOURCE),
+        vec![
+            Segment::Prose(vec![
+                "A lesson starts with some narration.".to_string(),
+                "It can take several lines.".to_string(),
+            ]),
+            Segment::Code(
+                "pub fn lesson() {\n    // Comments inside a function stay with the code.\n    let x = 5;".to_string()
+            ),
+            Segment::Broken {
+                code: "x = 6;".to_string(),
+                expectation: Expectation::CompileFail(vec!["E0384".to_string()]),
+            },
+            Segment::Code("}".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn highlighting_escapes_html_and_marks_tokens() {
+    let html = book::highlight("fn longest<'a>(x: &'a str) -> String { println!(\"<{}>\", 'c') } // done");
+
+    assert!(html.contains("<span class=\"keyword\">fn</span>"));
+    assert!(html.contains("&lt;<span class=\"lifetime\">'a</span>&gt;"));
+    assert!(html.contains("<span class=\"type\">String</span>"));
+    assert!(html.contains("<span class=\"macro\">println!</span>"));
+    assert!(html.contains("<span class=\"string\">&quot;&lt;{}&gt;&quot;</span>"));
+    assert!(html.contains("<span class=\"string\">'c'</span>"));
+    assert!(html.contains("<span class=\"comment\">// done</span>"));
+}
+
+// The pages follow the curriculum, and every broken example in the lessons ends up in a callout.
+#[test]
+fn pages_follow_the_curriculum_and_keep_every_broken_example() {
+    let pages = book::pages().unwrap();
+    let ordered = curriculum::ordered(curriculum::TOPICS).unwrap();
+
+    let mut topics: Vec<&str> = pages.iter().map(|page| page.topic.crate_name).collect();
+    topics.dedup();
+    let expected: Vec<&str> = ordered.iter().map(|topic| topic.crate_name).collect();
+    assert_eq!(topics, expected);
+
+    let page = pages
+        .iter()
+        .find(|page| page.id == "lifetimes::generic_lifetimes")
+        .unwrap();
+    let broken = page
+        .segments
+        .iter()
+        .filter(|segment| matches!(segment, Segment::Broken { .. }))
+        .count();
+    assert_eq!(broken, 3);
+}
+
+#[test]
+fn book_is_written_in_both_formats() {
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("book");
+    let _ = fs::remove_dir_all(&out_dir);
+
+    for format in [Format::Html, Format::Markdown] {
+        let written = book::write_book(format, &out_dir).unwrap();
+        assert!(written.iter().all(|path| path.exists()));
+    }
+
+    let index = fs::read_to_string(out_dir.join("index.html")).unwrap();
+    assert!(index.find("hello_world.html").unwrap() < index.find("async_rust__tokio.html").unwrap());
+
+    let page = fs::read_to_string(out_dir.join("ownership__i_borrow_move.md")).unwrap();
+    assert!(page.contains("> **This does not compile: E0382**"));
+    assert!(page.contains("[Contents](index.md)"));
+}
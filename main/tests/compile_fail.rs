@@ -0,0 +1,207 @@
+// Verifies that the intentionally broken "/* ... */" blocks in the lessons really fail to compile.
+// Every block is turned into its own case: a copy of the whole lesson crate, where only that block is uncommented.
+// The untouched copy of every crate must compile first, so that a case cannot fail for a reason that has nothing to do with its block.
+// The case is then compiled with the local rustc, and its error codes must be exactly the code(s) from the "// compile_fail:" annotation.
+// The generated cases are kept under "target/tmp/compile-fail" so that a failing case can be inspected by hand.
+
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use main::lessons;
+use main::source::{self, Expectation};
+
+struct Case {
+    name: String,
+    lesson_file: PathBuf,
+    line: usize,
+    expected: Vec<String>,
+}
+
+// The dependencies of every lesson crate have already been built for this test, next to the test binary itself.
+fn deps_dir() -> PathBuf {
+    std::env::current_exe()
+        .unwrap()
+        .parent()
+        .expect("test binaries live in target/<profile>/deps")
+        .to_path_buf()
+}
+
+// Reads the names of the "[dependencies]" of a lesson crate from its Cargo.toml.
+fn dependencies(crate_name: &str) -> Vec<String> {
+    let manifest = fs::read_to_string(source::workspace_root().join(crate_name).join("Cargo.toml")).unwrap();
+    let mut in_dependencies = false;
+    let mut names = Vec::new();
+    for line in manifest.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_dependencies = line == "[dependencies]";
+        } else if in_dependencies && let Some((name, _)) = line.split_once('=') {
+            names.push(name.trim().replace('-', "_"));
+        }
+    }
+    names
+}
+
+// Finds the most recently built rlib of an external crate, such as "libtokio-<hash>.rlib".
+fn find_rlib(name: &str) -> Option<PathBuf> {
+    let prefix = format!("lib{}-", name);
+    fs::read_dir(deps_dir())
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            file_name.starts_with(&prefix) && file_name.ends_with(".rlib")
+        })
+        .max_by_key(|entry| entry.metadata().and_then(|metadata| metadata.modified()).ok())
+        .map(|entry| entry.path())
+}
+
+// Returns the error codes, such as "E0382", reported by rustc.
+fn error_codes(stderr: &str) -> BTreeSet<String> {
+    stderr
+        .lines()
+        .filter_map(|line| line.strip_prefix("error[")?.split_once(']'))
+        .map(|(code, _)| code.to_string())
+        .collect()
+}
+
+// Copies everything in a lesson crate (the files that "include_str!" embeds as well as the sources), so that one file can be replaced.
+fn copy_dir(from: &Path, to: &Path) {
+    fs::create_dir_all(to).unwrap();
+    for entry in fs::read_dir(from).unwrap() {
+        let path = entry.unwrap().path();
+        let target = to.join(path.file_name().unwrap());
+        if path.is_dir() {
+            copy_dir(&path, &target);
+        } else {
+            fs::copy(&path, &target).unwrap();
+        }
+    }
+}
+
+// Copies a lesson crate into "case_dir/<name>", with "replaced" (a file relative to the crate) uncommented if given,
+// and type checks the copy from its "src/lib.rs", so that the "mod" declarations of the crate find their files.
+fn check_copy(crate_name: &str, case_dir: &Path, name: &str, replaced: Option<(&Path, String)>) -> Result<Output, String> {
+    let crate_copy = case_dir.join(name);
+    let _ = fs::remove_dir_all(&crate_copy);
+    copy_dir(&source::workspace_root().join(crate_name), &crate_copy);
+    if let Some((file, source)) = replaced {
+        fs::write(crate_copy.join(file), source).unwrap();
+    }
+
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let mut command = Command::new(rustc);
+    command
+        .args(["--edition", "2024", "--crate-type", "lib", "--emit", "metadata"])
+        .args(["--crate-name", crate_name, "-A", "warnings", "-o"])
+        .arg(case_dir.join(format!("{}.rmeta", name)))
+        .arg("-L")
+        .arg(format!("dependency={}", deps_dir().display()));
+    for dependency in dependencies(crate_name) {
+        let rlib = find_rlib(&dependency).ok_or(format!("the \"{}\" crate has not been built", dependency))?;
+        command.arg("--extern").arg(format!("{}={}", dependency, rlib.display()));
+    }
+
+    command
+        .arg(crate_copy.join("src").join("lib.rs"))
+        .output()
+        .map_err(|error| format!("could not run rustc: {}", error))
+}
+
+// The control: the crate, exactly as it is, must compile.
+fn check_untouched(crate_name: &str, case_dir: &Path) -> Result<(), String> {
+    let output = check_copy(crate_name, case_dir, &format!("{}__untouched", crate_name), None)?;
+    if output.status.success() {
+        return Ok(());
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let first_error = stderr.lines().find(|line| line.starts_with("error")).unwrap_or("");
+    Err(format!("the untouched \"{}\" crate does not compile: {}", crate_name, first_error))
+}
+
+fn compile(case: &Case, crate_name: &str, case_dir: &Path) -> Result<BTreeSet<String>, String> {
+    let source = fs::read_to_string(&case.lesson_file).unwrap();
+    let block = source::broken_blocks(&source)
+        .into_iter()
+        .find(|block| block.open_line == case.line)
+        .unwrap();
+
+    let relative = case.lesson_file.strip_prefix(source::workspace_root().join(crate_name)).unwrap();
+    let output = check_copy(crate_name, case_dir, &case.name, Some((relative, block.uncomment(&source))))?;
+    if output.status.success() {
+        return Err("the case compiled successfully".to_string());
+    }
+    Ok(error_codes(&String::from_utf8_lossy(&output.stderr)))
+}
+
+#[test]
+fn broken_examples_fail_with_the_annotated_error() {
+    let case_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("compile-fail");
+    fs::create_dir_all(&case_dir).unwrap();
+
+    let mut failures = Vec::new();
+    let mut checked = 0;
+    // Whether the untouched copy of each crate compiled.
+    let mut untouched: HashMap<&str, bool> = HashMap::new();
+
+    for crate_name in lessons::crate_names() {
+        for lesson_file in source::lesson_files(crate_name).unwrap() {
+            let source = fs::read_to_string(&lesson_file).unwrap();
+            let relative = lesson_file.strip_prefix(source::workspace_root()).unwrap().display().to_string();
+
+            for block in source::broken_blocks(&source) {
+                let expected = match block.expectation {
+                    Expectation::CompileFail(codes) => codes,
+                    Expectation::Snippet(_) => continue,
+                    Expectation::Missing => {
+                        failures.push(format!(
+                            "{}:{}: the block has no \"// compile_fail: <code>\" or \"// snippet: <reason>\" annotation right above it",
+                            relative, block.open_line
+                        ));
+                        continue;
+                    }
+                };
+
+                let case = Case {
+                    name: format!("{}_L{}", relative.trim_end_matches(".rs").replace(['/', '\\'], "__"), block.open_line),
+                    lesson_file: lesson_file.clone(),
+                    line: block.open_line,
+                    expected,
+                };
+
+                // A crate that does not compile as it is is reported once, and its cases are not checked.
+                if !untouched.contains_key(crate_name) {
+                    let control = check_untouched(crate_name, &case_dir);
+                    if let Err(reason) = &control {
+                        failures.push(reason.clone());
+                    }
+                    untouched.insert(crate_name, control.is_ok());
+                }
+                if !untouched[crate_name] {
+                    continue;
+                }
+
+                checked += 1;
+                let expected: BTreeSet<String> = case.expected.iter().cloned().collect();
+                match compile(&case, crate_name, &case_dir) {
+                    Ok(codes) if codes == expected => {}
+                    Ok(codes) => failures.push(format!(
+                        "{}:{}: expected {}, but rustc reported [{}]",
+                        relative,
+                        case.line,
+                        case.expected.join(", "),
+                        codes.into_iter().collect::<Vec<_>>().join(", ")
+                    )),
+                    Err(reason) => failures.push(format!("{}:{}: {}", relative, case.line, reason)),
+                }
+            }
+        }
+    }
+
+    // "ownership" always has broken blocks, while a build of only some crates may have none.
+    if cfg!(feature = "ownership") {
+        assert!(checked > 0, "no annotated compile_fail blocks were found");
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
@@ -0,0 +1,138 @@
+use main::curriculum::{self, CurriculumError, Topic};
+use main::lessons;
+
+fn position(ordered: &[&Topic], name: &str) -> usize {
+    ordered.iter().position(|topic| topic.crate_name == name).unwrap()
+}
+
+#[test]
+fn every_topic_comes_after_its_prerequisites() {
+    let ordered = curriculum::ordered(curriculum::TOPICS).unwrap();
+    assert_eq!(ordered.len(), curriculum::TOPICS.len());
+
+    for topic in &ordered {
+        for prerequisite in topic.prerequisites {
+            assert!(
+                position(&ordered, prerequisite) < position(&ordered, topic.crate_name),
+                "{} comes before its prerequisite {}",
+                topic.crate_name,
+                prerequisite
+            );
+        }
+    }
+}
+
+// Every crate in the lesson registry needs a topic, and every topic needs lessons.
+// A crate whose feature is turned off has no lessons in the registry, and is skipped.
+#[test]
+fn every_lesson_crate_has_a_topic() {
+    for name in lessons::crate_names() {
+        assert!(curriculum::find(name).is_some(), "{} has no topic in the curriculum", name);
+    }
+    for topic in curriculum::TOPICS {
+        if lessons::is_left_out(topic.crate_name) {
+            continue;
+        }
+        assert!(
+            !lessons::in_crate(topic.crate_name).is_empty(),
+            "{} has no lessons in the registry",
+            topic.crate_name
+        );
+    }
+}
+
+#[test]
+fn cycles_are_reported() {
+    let topics = [
+        Topic {
+            crate_name: "a",
+            title: "A",
+            order: 1,
+            tags: &[],
+            prerequisites: &["c"],
+        },
+        Topic {
+            crate_name: "b",
+            title: "B",
+            order: 2,
+            tags: &[],
+            prerequisites: &["a"],
+        },
+        Topic {
+            crate_name: "c",
+            title: "C",
+            order: 3,
+            tags: &[],
+            prerequisites: &["b"],
+        },
+    ];
+
+    assert_eq!(
+        curriculum::ordered(&topics).unwrap_err(),
+        CurriculumError::Cycle(vec!["a", "c", "b", "a"])
+    );
+}
+
+#[test]
+fn unknown_prerequisites_are_reported() {
+    let topics = [Topic {
+        crate_name: "a",
+        title: "A",
+        order: 1,
+        tags: &[],
+        prerequisites: &["missing"],
+    }];
+
+    assert_eq!(
+        curriculum::ordered(&topics).unwrap_err(),
+        CurriculumError::UnknownPrerequisite {
+            topic: "a",
+            prerequisite: "missing"
+        }
+    );
+}
+
+#[test]
+fn graph_exports_contain_every_edge() {
+    let dot = curriculum::to_dot(curriculum::TOPICS);
+    let json: serde_json::Value = serde_json::from_str(&curriculum::to_json(curriculum::TOPICS).unwrap()).unwrap();
+    let edges = json["edges"].as_array().unwrap();
+
+    for topic in curriculum::TOPICS {
+        for prerequisite in topic.prerequisites {
+            assert!(dot.contains(&format!("{} -> {};", prerequisite, topic.crate_name)));
+            assert!(
+                edges
+                    .iter()
+                    .any(|edge| edge["from"] == *prerequisite && edge["to"] == topic.crate_name)
+            );
+        }
+    }
+    assert_eq!(json["topics"][0]["crate"], "hello_world");
+}
+
+// Only with the default features, which build every lesson crate into "main".
+#[test]
+#[cfg(all(
+    feature = "hello_world",
+    feature = "variables",
+    feature = "ownership",
+    feature = "functions",
+    feature = "structs",
+    feature = "enums",
+    feature = "collections",
+    feature = "iters",
+    feature = "optionals",
+    feature = "error_handling",
+    feature = "lifetimes",
+    feature = "traits",
+    feature = "pointers",
+    feature = "generics",
+    feature = "async_rust"
+))]
+fn no_crate_is_left_out_by_default() {
+    for topic in curriculum::TOPICS {
+        assert!(!lessons::is_left_out(topic.crate_name), "{} is left out", topic.crate_name);
+    }
+    assert!(!lessons::is_left_out("not_a_crate"));
+}
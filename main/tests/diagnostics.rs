@@ -0,0 +1,87 @@
+use std::collections::HashSet;
+use std::fs;
+
+use main::diagnostics::{self, Target};
+use main::lessons;
+use main::source::{self, Expectation};
+
+// The lessons of a crate whose feature is turned off are not registered, so the hints that point at them are skipped.
+#[test]
+fn every_hint_points_at_a_lesson() {
+    let mut codes = HashSet::new();
+    for hint in diagnostics::HINTS {
+        assert!(codes.insert(hint.code), "{} has two hints", hint.code);
+        let crate_name = hint.see.split("::").next().unwrap();
+        if lessons::is_left_out(crate_name) {
+            continue;
+        }
+        assert!(diagnostics::target(hint.see).is_some(), "{} points at \"{}\", which does not exist", hint.code, hint.see);
+    }
+
+    #[cfg(feature = "ownership")]
+    assert_eq!(
+        diagnostics::target("ownership::iii_mutable_references::multi_mut_ref_error"),
+        Some(Target::Lesson("ownership::iii_mutable_references::multi_mut_ref_error"))
+    );
+    assert_eq!(
+        diagnostics::target("lifetimes::lifetimes_in_structs"),
+        Some(Target::Module("lifetimes/src/lifetimes_in_structs.rs".to_string()))
+    );
+    assert_eq!(diagnostics::target("generics"), Some(Target::Crate("generics")));
+}
+
+// A learner who uncomments a broken block of a lesson gets a hint for the error it fails with.
+#[test]
+fn every_annotated_error_has_a_hint() {
+    for crate_name in lessons::crate_names() {
+        for lesson_file in source::lesson_files(crate_name).unwrap() {
+            let source = fs::read_to_string(&lesson_file).unwrap();
+            for block in source::broken_blocks(&source) {
+                if let Expectation::CompileFail(codes) = block.expectation {
+                    for code in codes {
+                        assert!(
+                            diagnostics::hint_for(&code).is_some(),
+                            "{}:{} fails with {}, which has no hint",
+                            lesson_file.display(),
+                            block.open_line,
+                            code
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+const RUSTC_ERROR: &str = r#"{"$message_type":"diagnostic","message":"cannot borrow `x` as mutable more than once at a time","code":{"code":"E0499","explanation":"..."},"level":"error","spans":[],"children":[],"rendered":"error[E0499]: cannot borrow `x` as mutable more than once at a time\n"}"#;
+
+#[test]
+fn diagnostics_are_read_from_rustc_and_cargo() {
+    let cargo = format!(
+        "{}\n{{\"reason\":\"compiler-message\",\"message\":{}}}\nnot json\n{}\n",
+        r#"{"reason":"compiler-artifact","package_id":"hello_world"}"#,
+        r#"{"message":"unused variable: `y`","code":{"code":"unused_variables","explanation":null},"level":"warning","spans":[],"children":[],"rendered":null}"#,
+        r#"{"reason":"build-finished","success":false}"#
+    );
+    let from_cargo = diagnostics::parse(&cargo);
+    assert_eq!(from_cargo.len(), 1);
+    assert_eq!(from_cargo[0].code(), Some("unused_variables"));
+    assert_eq!(from_cargo[0].level, "warning");
+
+    let from_rustc = diagnostics::parse(RUSTC_ERROR);
+    assert_eq!(from_rustc.len(), 1);
+    assert_eq!(from_rustc[0].code(), Some("E0499"));
+}
+
+#[test]
+#[cfg(feature = "ownership")]
+fn hints_are_shown_once_under_the_first_error_of_their_code() {
+    let twice = diagnostics::parse(&format!("{}\n{}\n", RUSTC_ERROR, RUSTC_ERROR));
+    let explained = diagnostics::explain(&twice);
+
+    assert_eq!(explained.matches("error[E0499]").count(), 2);
+    assert_eq!(explained.matches("hint[E0499]").count(), 1);
+    assert!(explained.contains(
+        "  lesson: ownership::iii_mutable_references::multi_mut_ref_error, run it with \"main run ownership::iii_mutable_references::multi_mut_ref_error\""
+    ));
+}
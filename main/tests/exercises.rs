@@ -0,0 +1,83 @@
+use std::fs;
+
+use main::curriculum;
+use main::exercises::{self, TestResult};
+use main::source;
+
+// Every exercise needs a test module with its name in "<crate>/tests/exercises.rs", or it could never be solved.
+#[test]
+fn every_exercise_has_tests() {
+    for exercise in exercises::EXERCISES {
+        let path = source::workspace_root()
+            .join(exercise.crate_name())
+            .join("tests")
+            .join("exercises.rs");
+        let tests = fs::read_to_string(&path).unwrap();
+        assert!(
+            tests.contains(&format!("mod {} {{", exercise.name())),
+            "{} has no \"mod {}\" in {}",
+            exercise.id,
+            exercise.name(),
+            path.display()
+        );
+    }
+}
+
+#[test]
+fn every_topic_has_an_exercise() {
+    for topic in curriculum::TOPICS {
+        assert!(
+            exercises::EXERCISES
+                .iter()
+                .any(|exercise| exercise.crate_name() == topic.crate_name),
+            "{} has no exercise",
+            topic.crate_name
+        );
+    }
+}
+
+const OUTPUT: &str = "
+running 3 tests
+test triangle_area::other_shapes_still_work ... ok
+test triangle_area::triangle_area_is_half_of_base_times_height ... FAILED
+test other::unrelated ... ok
+
+failures:
+
+---- triangle_area::triangle_area_is_half_of_base_times_height stdout ----
+
+thread 'triangle_area::triangle_area_is_half_of_base_times_height' panicked at enums/src/exercises.rs:24:50:
+not yet implemented
+
+
+failures:
+    triangle_area::triangle_area_is_half_of_base_times_height
+
+test result: FAILED. 2 passed; 1 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s
+";
+
+#[test]
+fn test_output_is_parsed() {
+    assert_eq!(
+        exercises::parse_results(OUTPUT),
+        vec![
+            TestResult {
+                name: "triangle_area::other_shapes_still_work".to_string(),
+                passed: true,
+            },
+            TestResult {
+                name: "triangle_area::triangle_area_is_half_of_base_times_height".to_string(),
+                passed: false,
+            },
+            TestResult {
+                name: "other::unrelated".to_string(),
+                passed: true,
+            },
+        ]
+    );
+
+    let failure =
+        exercises::failure_output(OUTPUT, "triangle_area::triangle_area_is_half_of_base_times_height").unwrap();
+    assert!(failure.trim_start().starts_with("thread"));
+    assert!(failure.ends_with("not yet implemented"));
+}
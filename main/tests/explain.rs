@@ -0,0 +1,84 @@
+use main::explain::{self, Tracer};
+use main::lessons;
+use main::runner;
+
+// Only the tests of the crates that are built use it.
+#[allow(dead_code)]
+fn explain(id: &str) -> String {
+    let lesson = lessons::find(id).unwrap();
+    let mut tracer = Tracer::default();
+    runner::run(lesson, &mut tracer).unwrap();
+    explain::explain(id, &tracer.chunks)
+}
+
+#[test]
+#[cfg(feature = "enums")]
+fn lesson_sources_are_embedded() {
+    let source = explain::source_of("enums/src/shapes.rs").expect("the sources of enums are embedded");
+    assert!(source.contains("fn enum_testing_to"));
+}
+
+// Tests are built with debug info, so every line of output is matched to the statement that wrote it.
+#[test]
+#[cfg(feature = "enums")]
+fn output_is_shown_under_the_line_that_wrote_it() {
+    let explanation = explain("enums::shapes::enum_testing");
+    let lines: Vec<&str> = explanation.lines().collect();
+
+    assert!(lines[0].starts_with("-- enums/src/shapes.rs:"));
+    assert!(explanation.contains("| pub fn enum_testing_to(out: &mut dyn Write) -> io::Result<()> {"));
+    assert!(explanation.contains("// To compare enums with associated values"));
+    let output = lines
+        .iter()
+        .position(|line| line.starts_with("     > [1] This shape is a Rectangle"))
+        .expect("the output of the first writeln! is shown");
+    assert!(lines[output - 1].contains("writeln!(out, \"This shape is a Rectangle"));
+    assert!(!explanation.contains("could not be matched"));
+}
+
+#[test]
+#[cfg(feature = "async_rust")]
+fn output_of_helper_functions_is_shown_under_them() {
+    let explanation = explain("async_rust::futures::futures_future_testing");
+    let helper = explanation
+        .find(", called by the lesson")
+        .expect("get_name_future is shown after the lesson");
+    let helper_start = explanation[..helper].rfind("-- ").unwrap();
+    assert!(explanation[helper_start..helper].starts_with("-- async_rust/src/futures.rs:"));
+
+    assert!(explanation[..helper].contains("fn futures_future_testing_to"));
+    assert!(explanation[..helper].contains("> [5] 1 second passed"));
+
+    // The lines that "get_name_future()" writes when it is called are under the writes in its own code.
+    let lines: Vec<&str> = explanation[helper..].lines().collect();
+    assert!(lines.iter().any(|line| line.contains("fn get_name_future(out: &mut dyn Write)")));
+    for (index, text) in [(1, "print: before async creation"), (2, "print: After async creation")] {
+        let output = lines
+            .iter()
+            .position(|line| line.trim_start() == format!("> [{}] {}", index, text))
+            .unwrap_or_else(|| panic!("\"{}\" is not shown under get_name_future", text));
+        assert!(lines[output - 1].contains(&format!("writeln!(out, \"{}\")", text)), "{}", lines[output - 1]);
+    }
+}
+
+#[test]
+fn braces_in_strings_and_comments_do_not_end_a_function() {
+    let source = "\
+// A comment above the function.
+fn lesson() {
+    let text = \"}}\";
+    let raw = r#\"}\"#;
+    let brace = '}';
+    // }
+    /* } /* } */ } */
+}
+
+fn next<'a>(text: &'a str) -> &'a str {
+    text
+}
+";
+    let span = explain::function_span(source, 2).unwrap();
+    assert_eq!((span.comments_start, span.start, span.end), (1, 2, 8));
+    assert_eq!(explain::enclosing_function(source, 11).unwrap().start, 10);
+    assert_eq!(explain::enclosing_function(source, 9), None);
+}
@@ -0,0 +1,84 @@
+use std::env;
+use std::fs;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use main::playground::{self, Outcome};
+
+// The snippets run one at a time, so that the check for left over temporary crates does not see the crate of another test.
+static RUNNING: Mutex<()> = Mutex::new(());
+
+#[test]
+fn snippets_only_depend_on_the_crates_they_mention() {
+    assert_eq!(
+        playground::used_crates("ownership::borrowing::borrowing_testing();\nuse lifetimes::*;"),
+        ["ownership", "lifetimes"]
+    );
+    // "my_variables" and "enums_of_mine" are not the "variables" and "enums" crates.
+    assert!(playground::used_crates("let my_variables = 1; let enums_of_mine = 2;").is_empty());
+}
+
+#[test]
+fn snippets_keep_their_line_numbers() {
+    let wrapped = playground::main_rs("let x = 1;\nprintln!(\"{}\", x);");
+    assert_eq!(wrapped.lines().nth(1), Some("println!(\"{}\", x);"));
+    assert!(wrapped.starts_with("#![allow(unused)] fn main() { let x = 1;"));
+
+    let program = "fn main() {\n    println!(\"hi\");\n}";
+    assert_eq!(playground::main_rs(program), format!("#![allow(unused)] {}\n", program));
+}
+
+#[test]
+fn only_the_build_files_of_the_snippet_are_removed() {
+    for file_name in ["playground_1_1", "playground_1_1.d", "playground_1_1-0123abcd", "playground_1_1.pdb"] {
+        assert!(playground::is_build_file_of(file_name, "playground_1_1"), "{}", file_name);
+    }
+    for file_name in ["playground_1_10", "playground_1_12-0123abcd", "playground_1_1x"] {
+        assert!(!playground::is_build_file_of(file_name, "playground_1_1"), "{}", file_name);
+    }
+}
+
+#[test]
+fn snippets_run_against_the_lesson_crates() {
+    let _running = RUNNING.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let outcome = playground::run("hello_world::hello();\nprintln!(\"done\");", playground::DEFAULT_TIMEOUT).unwrap();
+    match outcome {
+        Outcome::Ran { stdout, status, .. } => {
+            assert!(status.success());
+            assert_eq!(stdout, "hello, world!!!!\ndone\n");
+        }
+        other => panic!("the snippet did not run: {:?}", other),
+    }
+}
+
+#[test]
+fn diagnostics_are_reported_when_a_snippet_does_not_compile() {
+    let _running = RUNNING.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let snippet = "let s = String::from(\"moved\");\nlet t = s;\nprintln!(\"{}\", s);";
+    match playground::run(snippet, playground::DEFAULT_TIMEOUT).unwrap() {
+        Outcome::CompileFailed(diagnostics) => {
+            assert!(diagnostics.contains("error[E0382]"), "{}", diagnostics);
+            assert!(diagnostics.contains("src/main.rs:3:"), "{}", diagnostics);
+            assert!(diagnostics.contains("lesson: ownership::i_borrow_move::borrow_vs_moving_str"), "{}", diagnostics);
+        }
+        other => panic!("the snippet should not compile: {:?}", other),
+    }
+}
+
+#[test]
+fn snippets_are_stopped_after_the_timeout() {
+    let _running = RUNNING.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let outcome = playground::run("println!(\"started\");\nloop {}", Duration::from_secs(1)).unwrap();
+    match outcome {
+        Outcome::TimedOut { stdout, .. } => assert_eq!(stdout, "started\n"),
+        other => panic!("the snippet should have timed out: {:?}", other),
+    }
+
+    // The temporary crates of this test process are all removed once they have run.
+    let prefix = format!("rust-lessons-playground_{}_", std::process::id());
+    let left = fs::read_dir(env::temp_dir())
+        .unwrap()
+        .filter(|entry| entry.as_ref().unwrap().file_name().to_string_lossy().starts_with(&prefix))
+        .count();
+    assert_eq!(left, 0);
+}
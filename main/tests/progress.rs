@@ -0,0 +1,116 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[cfg(feature = "hello_world")]
+use main::lessons;
+use main::progress::{self, Progress};
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("progress").join(name);
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn runs_are_recorded_with_their_times() {
+    let mut progress = Progress::default();
+    progress.record("ownership::ii_value_vs_reference::demo_ownership", 100);
+    progress.record("ownership::ii_value_vs_reference::demo_ownership", 250);
+
+    let record = progress.lessons["ownership::ii_value_vs_reference::demo_ownership"];
+    assert_eq!((record.first_run, record.last_run, record.runs), (100, 250, 2));
+    assert!(progress.has_run("ownership::ii_value_vs_reference::demo_ownership"));
+    assert!(!progress.has_run("hello_world::hello"));
+}
+
+#[test]
+#[cfg(all(feature = "hello_world", feature = "structs", feature = "lifetimes"))]
+fn summary_counts_the_lessons_of_every_crate() {
+    let mut progress = Progress::default();
+    for lesson in lessons::in_crate("structs") {
+        progress.record(lesson.id, 10);
+    }
+    progress.record("lifetimes::generic_lifetimes::input_lifetime", 20);
+    progress.record("a::lesson::that_was_removed", 30);
+
+    let summary = progress.summary();
+    assert_eq!(summary[0].crate_name, "hello_world");
+    assert_eq!(summary.iter().map(|crate_summary| crate_summary.total).sum::<usize>(), lessons::LESSONS.len());
+
+    let structs = summary.iter().find(|crate_summary| crate_summary.crate_name == "structs").unwrap();
+    assert_eq!((structs.done, structs.total, structs.last_run), (2, 2, Some(10)));
+    let lifetimes = summary.iter().find(|crate_summary| crate_summary.crate_name == "lifetimes").unwrap();
+    assert_eq!(lifetimes.done, 1);
+    assert_eq!(summary.iter().map(|crate_summary| crate_summary.done).sum::<usize>(), 3);
+}
+
+#[test]
+#[cfg(all(feature = "hello_world", feature = "variables"))]
+fn next_follows_the_curriculum() {
+    let mut progress = Progress::default();
+    assert_eq!(progress.next().unwrap().id, "hello_world::hello");
+
+    progress.record("hello_world::hello", 1);
+    assert_eq!(progress.next().unwrap().id, "hello_world::greeter::greeter_testing");
+
+    progress.record("hello_world::greeter::greeter_testing", 1);
+    assert_eq!(progress.next().unwrap().id, "variables::vars");
+
+    for lesson in lessons::LESSONS {
+        progress.record(lesson.id, 1);
+    }
+    assert!(progress.next().is_none());
+}
+
+#[test]
+fn progress_is_saved_and_loaded() {
+    let path = temp_dir("saved").join("nested").join("progress.json");
+    assert_eq!(Progress::load(&path).unwrap(), Progress::default());
+
+    let mut progress = Progress::default();
+    progress.record("hello_world::hello", 1_792_314_403);
+    progress.save(&path).unwrap();
+    assert_eq!(Progress::load(&path).unwrap(), progress);
+
+    fs::write(&path, "{ not json").unwrap();
+    let error = Progress::load(&path).unwrap_err();
+    assert!(error.to_string().contains("main reset"));
+}
+
+#[test]
+fn times_are_formatted_as_utc_dates() {
+    assert_eq!(progress::format_time(0), "1970-01-01 00:00 UTC");
+    assert_eq!(progress::format_time(951_782_400), "2000-02-29 00:00 UTC");
+    assert_eq!(progress::format_time(1_792_314_403), "2026-10-18 09:06 UTC");
+}
+
+// Runs the "main" binary with its data folder pointing at a temporary folder.
+#[cfg(feature = "hello_world")]
+fn main_command(data_dir: &Path, args: &[&str]) -> String {
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_main"))
+        .args(args)
+        .env("XDG_DATA_HOME", data_dir)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "main {:?} failed", args);
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+#[cfg(feature = "hello_world")]
+fn commands_record_show_and_reset_the_progress() {
+    let data_dir = temp_dir("commands");
+
+    main_command(&data_dir, &["run", "hello_world::hello"]);
+    assert!(data_dir.join("rust-lessons").join("progress.json").exists());
+
+    let summary = main_command(&data_dir, &["progress"]);
+    assert!(summary.lines().any(|line| line.starts_with("hello_world") && line.contains(" 1/2 ")));
+    assert!(summary.contains(&format!("ran 1 of {} lesson(s)", lessons::LESSONS.len())));
+    assert!(main_command(&data_dir, &["next"]).starts_with("next lesson: hello_world::greeter::greeter_testing"));
+
+    main_command(&data_dir, &["reset"]);
+    assert!(!data_dir.join("rust-lessons").join("progress.json").exists());
+    assert!(main_command(&data_dir, &["next"]).starts_with("next lesson: hello_world::hello"));
+}
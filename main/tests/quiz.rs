@@ -0,0 +1,138 @@
+// Checks the question banks of every crate, and that the answers they give are right.
+// The code of every "does this compile?" question is compiled with the local rustc under "target/tmp/quiz".
+
+use std::fs;
+use std::io::Cursor;
+use std::path::Path;
+use std::process::Command;
+
+use main::curriculum;
+use main::quiz::{self, Kind, Score};
+use main::source;
+
+#[test]
+fn every_topic_has_a_quiz() {
+    for topic in curriculum::TOPICS {
+        let questions = quiz::load(topic.crate_name).unwrap_or_else(|error| panic!("{}: {}", topic.crate_name, error));
+        assert!(questions.len() >= 3, "{} has only {} question(s)", topic.crate_name, questions.len());
+        assert!(
+            questions.iter().any(|question| matches!(question.kind, Kind::Compiles(_))),
+            "{} has no \"does this compile?\" question",
+            topic.crate_name
+        );
+    }
+}
+
+// Every explanation points to the function that teaches the answer, so the pointer has to lead somewhere.
+#[test]
+fn every_question_points_to_a_lesson() {
+    for topic in curriculum::TOPICS {
+        for question in quiz::load(topic.crate_name).unwrap() {
+            assert!(
+                question.see.starts_with(&format!("{}::", topic.crate_name)),
+                "\"{}\" is not in {}",
+                question.see,
+                topic.crate_name
+            );
+            assert!(source::locate(&question.see).is_some(), "\"{}\" could not be found", question.see);
+        }
+    }
+}
+
+#[test]
+fn compile_questions_have_the_right_answer() {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("quiz");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+
+    let mut wrong = Vec::new();
+    for topic in curriculum::TOPICS {
+        for (index, question) in quiz::load(topic.crate_name).unwrap().iter().enumerate() {
+            let Kind::Compiles(compiles) = question.kind else {
+                continue;
+            };
+            let file = dir.join(format!("{}_{}.rs", topic.crate_name, index + 1));
+            fs::write(&file, question.code.as_ref().unwrap()).unwrap();
+
+            let output = Command::new(&rustc)
+                .args(["--edition", "2024", "--crate-type", "lib", "--emit", "metadata", "-A", "warnings"])
+                .arg("--out-dir")
+                .arg(&dir)
+                .arg(&file)
+                .output()
+                .unwrap();
+            if output.status.success() != compiles {
+                wrong.push(format!(
+                    "{} says compiles = {}\n{}",
+                    file.display(),
+                    compiles,
+                    String::from_utf8_lossy(&output.stderr)
+                ));
+            }
+        }
+    }
+    assert!(wrong.is_empty(), "{}", wrong.join("\n"));
+}
+
+#[test]
+fn banks_are_checked_when_they_are_parsed() {
+    let error = quiz::parse("[[question]]\nprompt = \"?\"\nchoices = [\"a\", \"b\"]\nanswer = \"c\"\nexplanation = \"\"\nsee = \"x::y\"\n")
+        .unwrap_err();
+    assert!(error.contains("question 1: answer \"c\" is not one of the choices"), "{}", error);
+
+    let error = quiz::parse("[[question]]\ncompiles = true\nexplanation = \"\"\nsee = \"x::y\"\n").unwrap_err();
+    assert!(error.contains("needs the \"code\""), "{}", error);
+
+    let error = quiz::parse("[[question]]\ncode = \"\"\ncompiles = true\nanwser = \"a\"\nexplanation = \"\"\nsee = \"x::y\"\n")
+        .unwrap_err();
+    assert!(error.contains("anwser"), "{}", error);
+
+    let questions = quiz::parse("[[question]]\ncode = \"fn f() {}\"\ncompiles = true\nexplanation = \"\"\nsee = \"x::y\"\n").unwrap();
+    assert_eq!(questions[0].prompt, quiz::DEFAULT_COMPILES_PROMPT);
+}
+
+#[test]
+fn answers_are_scored_and_explained() {
+    let questions = quiz::load("collections").unwrap();
+    // The first answer is wrong, "x" is not an answer at all, the second one is right, and the rest are left out.
+    let mut answers = Cursor::new("a\nx\nyes\n");
+    let mut out = Vec::new();
+
+    let score = quiz::run(&questions, &mut answers, &mut out, true).unwrap();
+    assert_eq!(
+        score,
+        Score {
+            correct: 1,
+            answered: 2
+        }
+    );
+
+    let out = String::from_utf8(out).unwrap();
+    assert!(out.contains(&format!("Question 1 of {}", questions.len())));
+    assert!(out.contains("> a\nnot quite, the answer is c) It does not compile"));
+    assert!(out.contains("see: collections::hashmaps::functions_on_hashmaps_to (collections/src/hashmaps.rs:14)"));
+    assert!(out.contains("> x\nplease answer with yes or no\n> yes\ncorrect!"));
+    assert!(out.contains("Question 3 of"));
+}
+
+#[test]
+fn quiz_command_reads_answers_from_a_file() {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("quiz-command");
+    fs::create_dir_all(&dir).unwrap();
+    let answers = dir.join("answers.txt");
+    fs::write(&answers, "b\nno\nb\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_main"))
+        .args(["quiz", "hello_world", "--answers"])
+        .arg(&answers)
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(output.status.success(), "{}", stdout);
+    assert!(stdout.ends_with("score: 3 of 3\n"), "{}", stdout);
+
+    let output = Command::new(env!("CARGO_BIN_EXE_main")).args(["quiz", "nothing"]).output().unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr).unwrap().contains("no quiz for \"nothing\""));
+}
@@ -0,0 +1,66 @@
+use std::io;
+
+use main::lessons;
+use main::runner;
+
+// Every lesson must behave as declared in the registry: lessons marked with "panics = ..." panic with that message, and no other lesson panics.
+#[test]
+fn every_lesson_behaves_as_declared() {
+    for lesson in lessons::LESSONS {
+        let report = runner::run(lesson, &mut io::sink()).unwrap();
+        assert!(report.passed(), "{} did not behave as declared: {:?}", lesson.id, report.panic);
+    }
+}
+
+#[test]
+#[cfg(feature = "pointers")]
+fn expected_panics_are_reported_with_their_location() {
+    let lesson = lessons::find("pointers::cell::ref_cell_testing").unwrap();
+    let report = runner::run(lesson, &mut io::sink()).unwrap();
+
+    let panic = report.panic.expect("ref_cell_testing is expected to panic");
+    assert!(panic.message.contains("already mutably borrowed"));
+    assert!(panic.location.unwrap().starts_with("pointers/src/cell.rs:"));
+}
+
+#[test]
+#[cfg(feature = "pointers")]
+fn reports_become_json_records() {
+    let lesson = lessons::find("pointers::cell::ref_cell_testing").unwrap();
+    let report = runner::run(lesson, &mut io::sink()).unwrap();
+    let record = report.to_record(String::new());
+    assert_eq!((record.id, record.crate_name, record.passed), (lesson.id, "pointers", true));
+    assert_eq!(record.expected_panic, Some("already mutably borrowed"));
+
+    let json: serde_json::Value = serde_json::from_str(&runner::to_json(&[record])).unwrap();
+    assert_eq!(json["passed"], 1);
+    assert_eq!(json["failed"], 0);
+    assert_eq!(json["lessons"][0]["crate"], "pointers");
+    assert!(json["lessons"][0]["panic"]["message"].as_str().unwrap().contains("already mutably borrowed"));
+}
+
+// With "--format json", stdout must be nothing but the JSON, with the output of the lessons inside the records.
+#[test]
+#[cfg(all(feature = "pointers", feature = "hello_world"))]
+fn run_prints_json_records() {
+    use std::path::Path;
+    use std::process::Command;
+
+    let data_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("runner-json");
+    let output = Command::new(env!("CARGO_BIN_EXE_main"))
+        .args(["run", "--format", "json", "pointers::rc::rc_pointers", "hello_world::hello"])
+        .env("XDG_DATA_HOME", &data_dir)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let records = json["lessons"].as_array().unwrap();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0]["id"], "pointers::rc::rc_pointers");
+    assert_eq!(records[0]["stdout"], "\"Khan\"\n\"Khan\"\n");
+    assert!(records[0]["panic"].is_null());
+    assert!(records[0]["duration_ms"].as_f64().unwrap() >= 0.0);
+    assert_eq!(records[1]["stdout"], "hello, world!!!!\n");
+    assert_eq!(json["passed"], 2);
+}
@@ -0,0 +1,117 @@
+// Golden-output tests for every lesson in the registry.
+// Each lesson is run with its output captured, and the output is compared against "tests/snapshots/<lesson>.snap".
+// After an intentional change to what a lesson prints, regenerate the snapshots with:
+//      UPDATE_SNAPSHOTS=1 cargo test -p main --test snapshots
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use main::lessons::{self, Lesson};
+use main::runner;
+
+fn snapshot_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("snapshots")
+}
+
+fn snapshot_path(lesson: &Lesson) -> PathBuf {
+    snapshot_dir().join(format!("{}.snap", lesson.id.replace("::", "__")))
+}
+
+// Runs a lesson and returns everything it wrote.
+// A lesson that panics (such as "error_handling::errors") ends its snapshot with the panic message.
+// The location of the panic is left out, so that unrelated edits to a lesson file do not change the snapshot.
+fn capture(lesson: &'static Lesson) -> String {
+    let mut out: Vec<u8> = Vec::new();
+    let report = runner::run(lesson, &mut out).expect("writing to a Vec<u8> cannot fail");
+
+    let mut text = String::from_utf8(out).expect("lesson output is not valid UTF-8");
+    if let Some(panic) = report.panic {
+        text.push_str(&format!("--- panicked: {}\n", panic.message));
+    }
+    text
+}
+
+// The snapshots that are only checked on some builds, and are kept untouched on the others.
+// The lessons behind a feature that is off by default (see "main/Cargo.toml") are checked with the feature on:
+//      cargo test -p main --features count_allocations --test snapshots
+// "memory_layout" draws the regions of "/proc/self/maps" and the sizes of a 64-bit target, so it is only checked there.
+fn is_skipped(path: &Path) -> bool {
+    let checked_on = [
+        (
+            "ownership__vii_allocations__allocation_costs.snap",
+            cfg!(feature = "count_allocations"),
+        ),
+        (
+            "ownership__v_memory_layout__memory_layout.snap",
+            cfg!(all(target_os = "linux", target_pointer_width = "64")),
+        ),
+    ];
+    checked_on
+        .iter()
+        .any(|(file_name, checked)| !checked && path.file_name().is_some_and(|name| name == *file_name))
+}
+
+// Whether the snapshot belongs to a crate that is not built, such as "pointers" for "pointers__rc__rc_pointers.snap".
+fn is_left_out(path: &Path) -> bool {
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    file_name.split("__").next().is_some_and(lessons::is_left_out)
+}
+
+#[test]
+fn lesson_output_matches_snapshots() {
+    let update = std::env::var_os("UPDATE_SNAPSHOTS").is_some();
+    if update {
+        fs::create_dir_all(snapshot_dir()).unwrap();
+    }
+
+    let captured: Vec<(&Lesson, String)> = lessons::LESSONS
+        .iter()
+        .map(|lesson| (lesson, capture(lesson)))
+        .collect();
+
+    let mut failures = Vec::new();
+    for (lesson, actual) in &captured {
+        let path = snapshot_path(lesson);
+        if is_skipped(&path) {
+            continue;
+        }
+        if update {
+            fs::write(&path, actual).unwrap();
+            continue;
+        }
+
+        match fs::read_to_string(&path) {
+            Ok(expected) if expected == *actual => {}
+            Ok(expected) => failures.push(format!(
+                "{} changed its output\n--- expected ({})\n{}--- actual\n{}",
+                lesson.id,
+                path.display(),
+                expected,
+                actual
+            )),
+            Err(_) => failures.push(format!("{} has no snapshot at {}", lesson.id, path.display())),
+        }
+    }
+
+    // A snapshot without a lesson means a lesson was renamed or removed, and the snapshot was left behind.
+    // The lessons of a crate whose feature is turned off are not registered, and their snapshots are kept.
+    for entry in fs::read_dir(snapshot_dir()).unwrap() {
+        let path = entry.unwrap().path();
+        if !captured.iter().any(|(lesson, _)| snapshot_path(lesson) == path)
+            && !is_skipped(&path)
+            && !is_left_out(&path)
+        {
+            if update {
+                fs::remove_file(&path).unwrap();
+            } else {
+                failures.push(format!("{} does not belong to any lesson", path.display()));
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{}\n\nIf the change is intended, run: UPDATE_SNAPSHOTS=1 cargo test -p main --test snapshots",
+        failures.join("\n\n")
+    );
+}
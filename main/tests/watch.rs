@@ -0,0 +1,70 @@
+use std::fs;
+use std::path::Path;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use main::watch::{self, Line, Watcher};
+
+#[test]
+fn diff_keeps_the_common_lines() {
+    let old = "== a ==\none\ntwo\nthree\n";
+    let new = "== a ==\none\n2\nthree\nfour\n";
+    assert_eq!(
+        watch::diff(old, new),
+        [
+            Line::Same("== a =="),
+            Line::Same("one"),
+            Line::Removed("two"),
+            Line::Added("2"),
+            Line::Same("three"),
+            Line::Added("four"),
+        ]
+    );
+    assert!(watch::diff(old, old).iter().all(|line| matches!(line, Line::Same(_))));
+}
+
+#[test]
+fn changes_are_shown_under_their_lesson() {
+    let old = "== a ==\nsame\n== b ==\nold\n\nran 2 lesson(s): 2 passed, 0 failed\n";
+    let new = "== a ==\nsame\n== b ==\nnew\n\nran 2 lesson(s): 2 passed, 0 failed\n";
+    assert_eq!(
+        watch::format_changes(&watch::diff(old, new)).unwrap(),
+        "== b ==\n- old\n+ new\n"
+    );
+    assert_eq!(watch::format_changes(&watch::diff(old, old)), None);
+}
+
+#[test]
+fn watcher_wakes_up_when_a_file_changes() {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("watch");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("nested")).unwrap();
+    fs::write(dir.join("nested").join("lesson.rs"), "// before").unwrap();
+
+    let mut watcher = Watcher::new(&dir).unwrap();
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || sender.send(watcher.wait().map(|_| watcher.kind())));
+
+    // The watcher is given time to start waiting, and the change is written to a sub-folder to check that those are watched too.
+    thread::sleep(Duration::from_millis(100));
+    fs::write(dir.join("nested").join("lesson.rs"), "// after").unwrap();
+
+    let kind = receiver
+        .recv_timeout(Duration::from_secs(10))
+        .expect("the watcher did not notice the change")
+        .unwrap();
+    if cfg!(target_os = "linux") {
+        assert_eq!(kind, "inotify");
+    }
+}
+
+// A rebuild keeps the features of the running "main", including the opt-in ones.
+#[test]
+fn rebuilds_keep_the_features_of_this_main() {
+    let features = watch::features();
+    for crate_name in main::lessons::crate_names() {
+        assert!(features.contains(&crate_name), "{} is missing from {:?}", crate_name, features);
+    }
+    assert_eq!(features.contains(&"count_allocations"), cfg!(feature = "count_allocations"));
+}
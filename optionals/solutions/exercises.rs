@@ -0,0 +1,12 @@
+// The reference solutions of "src/exercises.rs" (see "Exercises" in the README).
+
+pub fn find_age(people: &[(&str, u32)], name: &str) -> Option<u32> {
+    people
+        .iter()
+        .find(|(person, _)| *person == name)
+        .map(|(_, age)| *age)
+}
+
+pub fn age_next_year(people: &[(&str, u32)], name: &str) -> Option<u32> {
+    find_age(people, name).map(|age| age + 1)
+}
@@ -0,0 +1,15 @@
+// The exercises of "optionals", checked with "cargo run -p main -- check optionals::find_age" (see "Exercises" in the README).
+
+#![allow(unused_variables)]
+
+// "people" is a list of (name, age) pairs.
+// Return the age of the person with the given name, or "None" if there is no such person.
+pub fn find_age(people: &[(&str, u32)], name: &str) -> Option<u32> {
+    todo!()
+}
+
+// Return the age that the person will be next year, or "None" if there is no such person.
+// Hint: "find_age()" already does most of the work, and "map()" works on an "Option" too.
+pub fn age_next_year(people: &[(&str, u32)], name: &str) -> Option<u32> {
+    todo!()
+}
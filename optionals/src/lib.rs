@@ -1,9 +1,17 @@
+// The exercises for this lesson (see "Exercises" in the README).
+#[cfg_attr(feature = "solution", path = "../solutions/exercises.rs")]
+pub mod exercises;
+
 // Optional is a datatype, an enum to be exact in its source code, in rust that can either hold a value or "None".
 // This is done because rust has no "Null value".
 // So the variable can hold some value, or hold no value.
 // Optionals can be represented for example as "Option<i32>" for ints.
 
-pub fn optionals() {
+use std::io::{self, Write};
+
+use bounded::Bounded;
+
+pub fn optionals_to(out: &mut dyn Write) -> io::Result<()> {
     let _value = Some(10);
     let _maybe_string = Some("maybe a string?".to_string());
     let _maybe_string2: Option<String> = None;
@@ -14,8 +22,8 @@ pub fn optionals() {
     // The ".clone()" in this specific example is used because the value of name will be moved in the unwrapping process, and it will be needed in the future.
     let name = Some("Khan".to_string());
     match name.clone() {
-        Some(name) => println!("Name is: {}.", name),
-        None => println!("There is no name."),
+        Some(name) => writeln!(out, "Name is: {}.", name)?,
+        None => writeln!(out, "There is no name.")?,
     }
 
     // A name can be unwrapped in an unsafe way without a compile error.
@@ -28,13 +36,14 @@ pub fn optionals() {
     let _unwrapped_value = name.clone().unwrap();
 
     // Optionals in rust can be mutable using the "mut" keyword.
-    let mut maybe_mut_age: Option<i8> = Some(20);
+    // The age is a "Bounded" rather than a plain "i8", so "+= 10" panics instead of wrapping around to a negative age in release builds (see the "bounded" crate).
+    let mut maybe_mut_age: Option<Bounded<i8, 0, 127>> = Some(Bounded::new(20));
     match maybe_mut_age.as_mut() {
         Some(maybe_mut_age) => *maybe_mut_age += 10,
         None => {}
     }
 
-    println!("age = {}.", maybe_mut_age.unwrap());
+    writeln!(out, "age = {}.", maybe_mut_age.unwrap())?;
 
     // Multiple optionals can be unwrapped with tuples.
     // All optionals must have a value.
@@ -45,8 +54,8 @@ pub fn optionals() {
     let age3 = Some(30);
 
     if let (Some(age_1), Some(age_2), Some(age_3)) = (age1, age2, age3) {
-        println!("{}, {}, {}", age_1, age_2, age_3);
-        println!("{}", age_1 + age_2 + age_3);
+        writeln!(out, "{}, {}, {}", age_1, age_2, age_3)?;
+        writeln!(out, "{}", age_1 + age_2 + age_3)?;
     }
 
     // Unwrapping optionals can have a set default option.
@@ -80,5 +89,11 @@ pub fn optionals() {
     // If the value had an actual value in it, then the closure will run.
     let value: Option<i32> = None;
     let mapped_value = value.map(|value| value * 2);
-    println!("{}", mapped_value.unwrap_or_default());
+    writeln!(out, "{}", mapped_value.unwrap_or_default())?;
+
+    Ok(())
+}
+
+pub fn optionals() {
+    optionals_to(&mut io::stdout()).expect("failed to write to stdout");
 }
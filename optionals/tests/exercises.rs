@@ -0,0 +1,28 @@
+// The tests of the exercises in "src/exercises.rs" (see "Exercises" in the README).
+
+mod find_age {
+    use optionals::exercises::{age_next_year, find_age};
+
+    const PEOPLE: &[(&str, u32)] = &[("Khan", 30), ("Khalili", 25)];
+
+    #[test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    fn finds_people_by_name() {
+        assert_eq!(find_age(PEOPLE, "Khan"), Some(30));
+        assert_eq!(find_age(PEOPLE, "Khalili"), Some(25));
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    fn unknown_people_have_no_age() {
+        assert_eq!(find_age(PEOPLE, "Nobody"), None);
+        assert_eq!(find_age(&[], "Khan"), None);
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    fn age_next_year_keeps_the_none() {
+        assert_eq!(age_next_year(PEOPLE, "Khan"), Some(31));
+        assert_eq!(age_next_year(PEOPLE, "Nobody"), None);
+    }
+}
@@ -0,0 +1,6 @@
+// The reference solutions of "src/exercises.rs" (see "Exercises" in the README).
+
+pub fn make_loud(value: &mut String) {
+    *value = value.to_uppercase();
+    value.push('!');
+}
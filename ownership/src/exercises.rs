@@ -0,0 +1,12 @@
+// The exercises of "ownership", checked with "cargo run -p main -- check ownership::make_loud" (see "Exercises" in the README).
+
+#![allow(unused_variables)]
+
+// Change the string behind the mutable reference so that it is in upper case and ends with a "!".
+// The caller keeps ownership of the string, so nothing is returned.
+// After "make_loud(&mut value)", a "value" of "hello" should be "HELLO!".
+// Clippy would take a "&mut str", but the answer needs a "String", since it makes the string longer.
+#[allow(clippy::ptr_arg)]
+pub fn make_loud(value: &mut String) {
+    todo!()
+}
@@ -2,6 +2,8 @@
 //  1) Borrowing: means that the value of the variable is just accessed without any transfer of ownership from one place to the other.
 //  2) Moving: means that the value of the variable has had its ownership transferred from one place to the other.
 
+use std::io::{self, Write};
+
 // The following function does NOT work. It even gives a compilation error, not a runtime one.
 // In rust, assigning "s1" to "s2" means that the ownership of the string in "s1" has been moved to "s2".
 // This basically means that after assigning "s1" to "s2", "s2" now owns the string.
@@ -12,52 +14,80 @@
 
 // A good illustration can be found at: https://doc.rust-lang.org/book/img/trpl04-02.svg
 
-pub fn borrow_vs_moving_str() {
+// "out" is only used by the block below, once it is uncommented.
+#[allow(unused_variables)]
+pub fn borrow_vs_moving_str_to(out: &mut dyn Write) -> io::Result<()> {
+    // compile_fail: E0382
     /*
     let s1 = String::from("Khalili");
     let s2 = s1;
 
-    println!("Hello, {}", s1);
-    println!("Hello, {}", s2);
+    writeln!(out, "Hello, {}", s1)?;
+    writeln!(out, "Hello, {}", s2)?;
     */
+
+    Ok(())
+}
+
+pub fn borrow_vs_moving_str() {
+    borrow_vs_moving_str_to(&mut io::stdout()).expect("failed to write to stdout");
 }
 
 // If the variables are changed to be integers, however, suddenly the code works...
 // This is because integers and some other variables are not dynamically allocated in the heap and instead are stored on the stack.
 // It allows rust to copy the value of the first variable into the second variable.
 // This means that both variables are totally independent from each other.
-pub fn borrow_vs_moving_int() {
+pub fn borrow_vs_moving_int_to(out: &mut dyn Write) -> io::Result<()> {
     let age1 = 10;
     let age2 = age1;
 
-    println!("You are {} years old", age1);
-    println!("You are {} years old", age2);
+    writeln!(out, "You are {} years old", age1)?;
+    writeln!(out, "You are {} years old", age2)?;
+
+    Ok(())
+}
+
+pub fn borrow_vs_moving_int() {
+    borrow_vs_moving_int_to(&mut io::stdout()).expect("failed to write to stdout");
 }
 
 // So how to make the "borrow_vs_moving_str()" function work?
 // It can be corrected by making "s2" a reference of "s1", such that now "s2" points to the structure of the string in "s1".
 // The reference "s2" actually sits in the stack.
 // See a good reference image at: https://doc.rust-lang.org/book/img/trpl04-06.svg
-pub fn borrow_vs_moving_str_correction() {
+pub fn borrow_vs_moving_str_correction_to(out: &mut dyn Write) -> io::Result<()> {
     let s1 = String::from("Khalili");
     let s2 = &s1;
 
-    println!("Hello, {}", s1);
-    println!("Hello, {}", s2);
+    writeln!(out, "Hello, {}", s1)?;
+    writeln!(out, "Hello, {}", s2)?;
+
+    Ok(())
 }
 
-pub fn code_block() {
+pub fn borrow_vs_moving_str_correction() {
+    borrow_vs_moving_str_correction_to(&mut io::stdout()).expect("failed to write to stdout");
+}
+
+pub fn code_block_to(out: &mut dyn Write) -> io::Result<()> {
     // Some code goes here for example
 
     // Sample Code Block:
     {
         let name = String::from("Khan");
-        println!("hello, {}, from code_block", name);
+        writeln!(out, "hello, {}, from code_block", name)?;
     }
     // "name" here, outside of the code block, gives a compile error because the code now has gone out of scope.
     // This means that "name" is no longer allocated.
 
+    // compile_fail: E0425
     /*
     name;
     */
+
+    Ok(())
+}
+
+pub fn code_block() {
+    code_block_to(&mut io::stdout()).expect("failed to write to stdout");
 }
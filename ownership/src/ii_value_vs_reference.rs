@@ -5,26 +5,47 @@
 // What happens in this case is that rust "moves" the ownership from the variable holding the data to the function that this data is being passed into.
 // Note: Traits will be discussed in a separate section..
 
-pub fn takes_ownership(s: String) {
+use std::io::{self, Write};
+
+pub fn takes_ownership_to(out: &mut dyn Write, s: String) -> io::Result<()> {
     // Remember that String and &str are different data types.
-    println!("This is a string with moved ownership: {}", s);
+    writeln!(out, "This is a string with moved ownership: {}", s)
+}
+
+pub fn takes_ownership(s: String) {
+    takes_ownership_to(&mut io::stdout(), s).expect("failed to write to stdout");
 }
 
 // When the ownership of a variable is "moved" to a function, the variable cannot be used after the function.
-pub fn demo_ownership() {
+pub fn demo_ownership_to(out: &mut dyn Write) -> io::Result<()> {
     let s = String::from("hello");
-    takes_ownership(s);
+    takes_ownership_to(out, s)?;
+    // compile_fail: E0382
     /*
-    println!("{}", s);      // error: value borrowed after move
+    writeln!(out, "{}", s)?;      // error: value borrowed after move
     */
+
+    Ok(())
+}
+
+pub fn demo_ownership() {
+    demo_ownership_to(&mut io::stdout()).expect("failed to write to stdout");
+}
+
+pub fn copied_to(out: &mut dyn Write, i: i32) -> io::Result<()> {
+    writeln!(out, "This is the copied value: {}", i)
 }
 
 pub fn copied(i: i32) {
-    println!("This is the copied value: {}", i)
+    copied_to(&mut io::stdout(), i).expect("failed to write to stdout");
 }
 
 // If a reference is passed, then rust just borrows the string as read only.
 // Note that this is an immutable reference.
+pub fn borrow_string_to(out: &mut dyn Write, s: &String) -> io::Result<()> {
+    writeln!(out, "This is a borrowed string: {}", s)
+}
+
 pub fn borrow_string(s: &String) {
-    println!("This is a borrowed string: {}", s);
+    borrow_string_to(&mut io::stdout(), s).expect("failed to write to stdout");
 }
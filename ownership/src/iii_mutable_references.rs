@@ -1,6 +1,8 @@
 // In rust a reference is by default immutable (read-only).
 // The keyword "mut" has to be used explicitly to allow the mutation of the reference.
 
+use std::io::{self, Write};
+
 // To overwrite on a pass by reference, make the reference mutable.
 // You can compare this with the function "borrow_string" in file "ii" of this module.
 pub fn mut_borrow_string(value: &mut String) {
@@ -15,12 +17,14 @@ pub fn demo_mut_borrow() {
     // Even when you add in the argument the "&mut" below, then it will still give a compilation error.
     // This is because "_s1" itself is not mutable.
 
+    // compile_fail: E0308
     /*
     mut_borrow_string(&_s1);
     */
 
     // Even though "s2" is mutable, the following code block gives an error because function expects a "&mut String" but only gets "&String".
 
+    // compile_fail: E0308
     /*
     mut_borrow_string(&s2);
     */
@@ -36,6 +40,7 @@ pub fn demo_mut_borrow() {
 // For example the following piece of code gives a compilation error.
 pub fn multi_mut_ref_error() {
 
+    // compile_fail: E0499
     /*
     let mut x = 5;
     let r1 = &mut x;
@@ -57,14 +62,17 @@ pub fn demo_mut_reference() {
 // This makes sure that:
 // 1) while something is mutably changing the data, no one else is allowed to even look at it.
 // 2) while others are reading it, no one is allowed to change it.
-pub fn demo_read_with_write() {
-    // The following piece of code gives an error
+pub fn demo_read_with_write_to(out: &mut dyn Write) -> io::Result<()> {
+    // The following piece of code gives an error, because "r1" is still used after "r2" is created.
+    // If "r1" was never used again, rust would end the immutable borrow right there, and the code would compile.
 
+    // compile_fail: E0502
     /*
     let mut s = String::from("hello");
 
     let r1 = &s;            // Immutable borrow
     let r2 = &mut s;        // Error: mutable borrow while immutable borrow is active
+    writeln!(out, "{} {}", r1, r2)?;
     */
 
     // The correct way of writing it is as follows.
@@ -78,5 +86,11 @@ pub fn demo_read_with_write() {
     // Now we can borrow mutably again, such as in this code, or the mut can be replaced with a read-only reference.
     let r2 = &mut s;
     r2.push_str("!");
-    println!("{}", s);
+    writeln!(out, "{}", s)?;
+
+    Ok(())
+}
+
+pub fn demo_read_with_write() {
+    demo_read_with_write_to(&mut io::stdout()).expect("failed to write to stdout");
 }
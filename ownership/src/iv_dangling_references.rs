@@ -1,6 +1,7 @@
 // Rust doesn't allow a reference to live past the lifetime of the variable itself.
 // Rust detects it and gives a compilation error such as the following piece of code.
 
+// compile_fail: E0106
 /*
 pub fn dangle() -> &String {
     let s = String::from("hello");
@@ -14,6 +15,7 @@ pub fn no_dangle() -> String {
 }
 
 pub fn demo_dangle() {
+    // snippet: needs the "dangle" function from the block above, which fails on its own with E0106
     /*
     let h = dangle();
     */
@@ -2,3 +2,13 @@ pub mod i_borrow_move;
 pub mod ii_value_vs_reference;
 pub mod iii_mutable_references;
 pub mod iv_dangling_references;
+pub mod v_memory_layout;
+pub mod vi_borrow_simulator;
+// Replaces the global allocator of the whole program, so it is only built when asked for.
+#[cfg(feature = "count_allocations")]
+pub mod vii_allocations;
+pub mod viii_drop_order;
+
+// The exercises for this lesson (see "Exercises" in the README).
+#[cfg_attr(feature = "solution", path = "../solutions/exercises.rs")]
+pub mod exercises;
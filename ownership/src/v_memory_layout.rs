@@ -0,0 +1,431 @@
+// The lessons describe where values live: a "String" is a pointer, a length and a capacity on the stack, with its bytes on the heap,
+// and a "&'static str" points to text that is stored in the read-only data of the program itself.
+// This module looks at real values to show it, and draws them like the figures of the book (https://doc.rust-lang.org/book/img/trpl04-01.svg):
+//      let s1 = String::from("hi");
+//      println!("{}", inspect("s1", &s1));
+// prints (with the real addresses):
+//      s1: String (24 bytes, aligned to 8) on the stack at 0x7ffc5a1e3b48      the heap at 0x5581c6a4fb10
+//      +----------+----------------+                                          +-------+-------+
+//      | name     | value          |                                          | index | value |
+//      +----------+----------------+                                          +-------+-------+
+//      | ptr      | 0x5581c6a4fb10 |----------------------------------------> | 0     | 'h'   |
+//      | len      | 2              |                                          | 1     | 'i'   |
+//      | capacity | 2              |                                          +-------+-------+
+//      +----------+----------------+
+// The region of an address (stack, heap, read-only data, ...) is found in "/proc/self/maps", which lists every memory mapping of the process on Linux.
+// On other systems, every region is "unknown memory".
+
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::mem;
+
+// How many elements of the heap (or read-only data) are drawn, so that a long string does not make a long diagram.
+const MAX_ITEMS: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Region {
+    // The stack of the thread that inspects the value.
+    Stack,
+    // "[heap]", or memory that was mapped without a file, which is where large allocations and the allocations of other threads go.
+    Heap,
+    // The parts of the program (or a library) that cannot be written to, where string literals are stored.
+    ReadOnlyData,
+    // The parts of the program that can be written to, where "static" variables are stored.
+    Data,
+    // The machine code of the program.
+    Code,
+    Unknown,
+}
+
+impl Region {
+    // Values are "on" the stack or the heap, but "in" the data of the program.
+    fn preposition(self) -> &'static str {
+        match self {
+            Region::Stack | Region::Heap => "on",
+            _ => "in",
+        }
+    }
+}
+
+impl fmt::Display for Region {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Region::Stack => "the stack",
+            Region::Heap => "the heap",
+            Region::ReadOnlyData => "the read-only data of the program",
+            Region::Data => "the static data of the program",
+            Region::Code => "the code of the program",
+            Region::Unknown => "unknown memory",
+        };
+        f.write_str(name)
+    }
+}
+
+// A line of "/proc/self/maps", such as:
+//      7ffc5a1c4000-7ffc5a1e5000 rw-p 00000000 00:00 0          [stack]
+struct Mapping {
+    start: usize,
+    end: usize,
+    writable: bool,
+    executable: bool,
+    // The file that is mapped, a name in brackets such as "[stack]", or nothing for memory that was mapped without a file.
+    path: String,
+}
+
+fn parse_mapping(line: &str) -> Option<Mapping> {
+    let mut parts = line.split_whitespace();
+    let (start, end) = parts.next()?.split_once('-')?;
+    let permissions = parts.next()?;
+    // The offset, the device and the inode are not needed.
+    let path = parts.nth(3).unwrap_or("").to_string();
+    Some(Mapping {
+        start: usize::from_str_radix(start, 16).ok()?,
+        end: usize::from_str_radix(end, 16).ok()?,
+        writable: permissions.contains('w'),
+        executable: permissions.contains('x'),
+        path,
+    })
+}
+
+fn read_mappings() -> Vec<Mapping> {
+    let maps = if cfg!(target_os = "linux") {
+        fs::read_to_string("/proc/self/maps").unwrap_or_default()
+    } else {
+        String::new()
+    };
+    maps.lines().filter_map(parse_mapping).collect()
+}
+
+// Finds the region of every address, with the mappings read once.
+struct Regions {
+    mappings: Vec<Mapping>,
+    // The address of a local variable, which is always on the stack of the current thread.
+    // The main thread has a "[stack]" mapping, but the stacks of other threads (such as the ones that run the tests) are mapped without a file.
+    stack: usize,
+}
+
+impl Regions {
+    #[inline(never)]
+    fn read() -> Regions {
+        let local = 0u8;
+        Regions {
+            mappings: read_mappings(),
+            stack: &local as *const u8 as usize,
+        }
+    }
+
+    fn of(&self, address: usize) -> Region {
+        let Some(mapping) = self.mappings.iter().find(|m| (m.start..m.end).contains(&address)) else {
+            return Region::Unknown;
+        };
+        if mapping.path == "[stack]" || (mapping.start..mapping.end).contains(&self.stack) {
+            Region::Stack
+        } else if mapping.path == "[heap]" || mapping.path.is_empty() {
+            Region::Heap
+        } else if mapping.path.starts_with('[') {
+            // "[vdso]", "[vvar]" and the like belong to the kernel.
+            Region::Unknown
+        } else if mapping.executable {
+            Region::Code
+        } else if mapping.writable {
+            Region::Data
+        } else {
+            Region::ReadOnlyData
+        }
+    }
+}
+
+// What a value holds: the value itself, or a pointer to elements that are stored somewhere else.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Contents {
+    Value(String),
+    Pointer {
+        address: usize,
+        len: usize,
+        // Only the types that own their elements ("String" and "Vec") have a capacity, a "&str" only borrows them.
+        capacity: Option<usize>,
+        // The first elements, written out (a string shows its bytes).
+        items: Vec<String>,
+    },
+}
+
+// Any type that can be inspected.
+// Every type could be, but only the types that the lessons use are implemented.
+pub trait Inspect {
+    fn contents(&self) -> Contents;
+}
+
+macro_rules! inspect_value {
+    ($($ty:ty),*) => {
+        $(
+            impl Inspect for $ty {
+                fn contents(&self) -> Contents {
+                    Contents::Value(format!("{:?}", self))
+                }
+            }
+        )*
+    };
+}
+
+inspect_value!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, bool, char);
+
+// A byte is written as the character it stands for, unless it is only a part of a character (such as the two bytes of "é").
+fn byte_item(byte: u8) -> String {
+    if byte.is_ascii_graphic() || byte == b' ' {
+        format!("{:?}", byte as char)
+    } else {
+        format!("{:#04x}", byte)
+    }
+}
+
+fn items<T: fmt::Debug>(elements: &[T]) -> Vec<String> {
+    elements.iter().take(MAX_ITEMS).map(|element| format!("{:?}", element)).collect()
+}
+
+impl Inspect for String {
+    fn contents(&self) -> Contents {
+        Contents::Pointer {
+            address: self.as_ptr() as usize,
+            len: self.len(),
+            capacity: Some(self.capacity()),
+            items: self.bytes().take(MAX_ITEMS).map(byte_item).collect(),
+        }
+    }
+}
+
+impl Inspect for &str {
+    fn contents(&self) -> Contents {
+        Contents::Pointer {
+            address: self.as_ptr() as usize,
+            len: self.len(),
+            capacity: None,
+            items: self.bytes().take(MAX_ITEMS).map(byte_item).collect(),
+        }
+    }
+}
+
+impl<T: fmt::Debug> Inspect for Vec<T> {
+    fn contents(&self) -> Contents {
+        Contents::Pointer {
+            address: self.as_ptr() as usize,
+            len: self.len(),
+            capacity: Some(self.capacity()),
+            items: items(self),
+        }
+    }
+}
+
+impl<T: fmt::Debug> Inspect for &[T] {
+    fn contents(&self) -> Contents {
+        Contents::Pointer {
+            address: self.as_ptr() as usize,
+            len: self.len(),
+            capacity: None,
+            items: items(self),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Layout {
+    pub name: String,
+    pub type_name: String,
+    pub size: usize,
+    pub align: usize,
+    // Where the value itself is, which for a "String" is where its pointer, length and capacity are.
+    pub address: usize,
+    pub region: Region,
+    pub contents: Contents,
+    // Where the elements are, for a value that points to them.
+    pub target: Option<Region>,
+}
+
+// Whether a diagram shows the addresses.
+// They change every time the program runs, so the lesson hides them to always print the same diagrams.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Addresses {
+    Shown,
+    Hidden,
+}
+
+// Inspects a value. "name" is only used in the diagram.
+pub fn inspect<T: Inspect>(name: &str, value: &T) -> Layout {
+    let regions = Regions::read();
+    let address = value as *const T as usize;
+    let contents = value.contents();
+    let target = match contents {
+        Contents::Pointer { address, .. } => Some(regions.of(address)),
+        Contents::Value(_) => None,
+    };
+    Layout {
+        name: name.to_string(),
+        type_name: short_type_name(std::any::type_name::<T>()),
+        size: mem::size_of::<T>(),
+        align: mem::align_of::<T>(),
+        address,
+        region: regions.of(address),
+        contents,
+        target,
+    }
+}
+
+// "alloc::vec::Vec<alloc::string::String>" is written as "Vec<String>".
+fn short_type_name(name: &str) -> String {
+    let mut short = String::new();
+    // Where the current path segment (such as "alloc" in "alloc::vec") starts in "short".
+    let mut segment = 0;
+    let mut chars = name.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == ':' && chars.peek() == Some(&':') {
+            chars.next();
+            short.truncate(segment);
+            continue;
+        }
+        short.push(c);
+        if !(c.is_alphanumeric() || c == '_') {
+            segment = short.len();
+        }
+    }
+    short
+}
+
+// Draws a table with a "name" or "index" column and a "value" column.
+fn table(key: &str, rows: &[(String, String)]) -> Vec<String> {
+    let key_width = rows.iter().map(|(k, _)| k.len()).chain([key.len()]).max().unwrap_or(0);
+    let value_width = rows.iter().map(|(_, v)| v.len()).chain(["value".len()]).max().unwrap_or(0);
+    let border = format!("+{}+{}+", "-".repeat(key_width + 2), "-".repeat(value_width + 2));
+    let row = |k: &str, v: &str| format!("| {:<key_width$} | {:<value_width$} |", k, v);
+
+    let mut lines = vec![border.clone(), row(key, "value"), border.clone()];
+    lines.extend(rows.iter().map(|(k, v)| row(k, v)));
+    lines.push(border);
+    lines
+}
+
+impl Layout {
+    pub fn diagram(&self, addresses: Addresses) -> String {
+        let at = |address: usize| match addresses {
+            Addresses::Shown => format!(" at {:#x}", address),
+            Addresses::Hidden => String::new(),
+        };
+        let bytes = if self.size == 1 { "byte" } else { "bytes" };
+        let title = format!(
+            "{}: {} ({} {}, aligned to {}) {} {}{}",
+            self.name,
+            self.type_name,
+            self.size,
+            bytes,
+            self.align,
+            self.region.preposition(),
+            self.region,
+            at(self.address)
+        );
+
+        let (rows, pointed) = match &self.contents {
+            Contents::Value(value) => (vec![("value".to_string(), value.clone())], None),
+            Contents::Pointer {
+                address,
+                len,
+                capacity,
+                items,
+            } => {
+                // An empty "String" or "Vec" has not allocated anything, so its pointer does not point to anything that could be drawn.
+                let ptr = match (len, addresses) {
+                    (0, _) => "(nothing)".to_string(),
+                    (_, Addresses::Shown) => format!("{:#x}", address),
+                    (_, Addresses::Hidden) => "(address)".to_string(),
+                };
+                let mut rows = vec![("ptr".to_string(), ptr), ("len".to_string(), len.to_string())];
+                if let Some(capacity) = capacity {
+                    rows.push(("capacity".to_string(), capacity.to_string()));
+                }
+                let pointed = (*len > 0).then(|| {
+                    let mut elements: Vec<(String, String)> =
+                        items.iter().enumerate().map(|(index, item)| (index.to_string(), item.clone())).collect();
+                    if *len > items.len() {
+                        elements.push(("...".to_string(), format!("{} more", len - items.len())));
+                    }
+                    let target = self.target.unwrap_or(Region::Unknown);
+                    (format!("{}{}", target, at(*address)), table("index", &elements))
+                });
+                (rows, pointed)
+            }
+        };
+
+        let mut left = vec![title];
+        left.extend(table("name", &rows));
+        let Some((target_title, target_table)) = pointed else {
+            return left.join("\n") + "\n";
+        };
+        let mut right = vec![target_title];
+        right.extend(target_table);
+
+        // The "ptr" row comes right after the title and the header of the table, and the arrow starts there.
+        let ptr_row = 4;
+        let width = left.iter().map(String::len).max().unwrap_or(0);
+        let mut lines = Vec::new();
+        for i in 0..left.len().max(right.len()) {
+            let l = left.get(i).map(String::as_str).unwrap_or("");
+            let r = right.get(i).map(String::as_str).unwrap_or("");
+            let line = if i == ptr_row {
+                format!("{}{}> {}", l, "-".repeat(width - l.len() + 4), r)
+            } else {
+                format!("{:<width$}      {}", l, r, width = width)
+            };
+            lines.push(line.trim_end().to_string());
+        }
+        lines.join("\n") + "\n"
+    }
+}
+
+impl fmt::Display for Layout {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.diagram(Addresses::Shown))
+    }
+}
+
+// The values of the earlier lessons, drawn.
+// The addresses are hidden here, but they can be seen by running the same code in the playground (see "Memory Layout" in the README).
+pub fn memory_layout_to(out: &mut dyn Write) -> io::Result<()> {
+    // An integer is copied around on the stack, and nothing points anywhere.
+    let age = 10;
+    let small = 22u8;
+    writeln!(out, "{}", inspect("age", &age).diagram(Addresses::Hidden))?;
+    writeln!(out, "{}", inspect("small", &small).diagram(Addresses::Hidden))?;
+
+    // A "String" is a pointer, a length and a capacity on the stack, and the bytes are on the heap.
+    let s1 = String::from("Khalili");
+    let s1_layout = inspect("s1", &s1);
+    writeln!(out, "{}", s1_layout.diagram(Addresses::Hidden))?;
+
+    // Moving "s1" into "s2" copies the pointer, the length and the capacity, but not the bytes on the heap.
+    // That is why "s1" cannot be used anymore: two owners of the same bytes would both free them.
+    let s2 = s1;
+    let s2_layout = inspect("s2", &s2);
+    let same_bytes = s1_layout.contents == s2_layout.contents;
+    writeln!(out, "after \"let s2 = s1;\", s2 points to the same bytes as s1 did: {}\n", same_bytes)?;
+
+    // A string literal is written into the program when it is compiled, so it is not on the heap.
+    let name: &'static str = "Khalili";
+    writeln!(out, "{}", inspect("name", &name).diagram(Addresses::Hidden))?;
+
+    // A slice of a "String" borrows its bytes, wherever they are.
+    let first: &str = &s2[..4];
+    writeln!(out, "{}", inspect("first", &first).diagram(Addresses::Hidden))?;
+
+    // A "Vec" is laid out like a "String", with elements of any type on the heap.
+    let mut numbers = Vec::with_capacity(4);
+    numbers.extend([1, 2, 3]);
+    writeln!(out, "{}", inspect("numbers", &numbers).diagram(Addresses::Hidden))?;
+
+    // An empty "String" has not allocated any bytes yet, but it is just as big.
+    let empty = String::new();
+    write!(out, "{}", inspect("empty", &empty).diagram(Addresses::Hidden))?;
+
+    Ok(())
+}
+
+pub fn memory_layout() {
+    memory_layout_to(&mut io::stdout()).expect("failed to write to stdout");
+}
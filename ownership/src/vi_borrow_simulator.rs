@@ -0,0 +1,1154 @@
+// The lessons of this crate explain moves, copies and borrows with comments and with code that is commented out because it does not compile.
+// This module is a tiny interpreter that follows the same rules, and shows the state of every variable after every statement.
+// It understands a small part of rust:
+//      let s1 = String::from("Khalili");       "let", "let mut", and "let r;" without a value
+//      let r = &s1;  let m = &mut x;           shared and mutable borrows
+//      x = 5;  *m += 1;                        assignments, also through a "&mut" reference
+//      takes_ownership(s1);                    function calls, which take their arguments by value
+//      println!("{} {}", r, x);                macros, which only borrow their arguments
+//      s.push_str(" world");                   methods, which borrow the value ("&mut" for the methods that change it)
+//      { ... }                                 blocks, which drop their variables at the closing brace
+// Integers and "&str" literals are "Copy", a "String" is not, and a function call returns "()".
+// Like the real borrow checker, a borrow only lasts until the last time it is used.
+// So a second "&mut x" does not fail by itself: it makes the first one unusable, and the error shows up if the first one is used again.
+
+use std::fmt;
+use std::io::{self, Write};
+
+// The methods that need "&mut self". Every other method only reads the value.
+const MUTATING_METHODS: &[&str] = &["push_str", "push", "clear", "insert", "remove", "pop", "truncate", "sort"];
+
+// A part of the source, from byte "start" to byte "end".
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    fn to(self, other: Span) -> Span {
+        Span {
+            start: self.start,
+            end: other.end,
+        }
+    }
+}
+
+// A note that points at a part of the source, such as "value moved here".
+// The primary label is where the error is, and is underlined with "^" instead of "-".
+#[derive(Debug, Clone, PartialEq)]
+pub struct Label {
+    pub span: Span,
+    pub text: String,
+    pub primary: bool,
+}
+
+// An error, with the same code and message as rustc gives for the same code.
+// Syntax errors have no code.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Error {
+    pub code: Option<&'static str>,
+    pub message: String,
+    pub labels: Vec<Label>,
+    pub note: Option<String>,
+}
+
+impl Error {
+    fn new(code: Option<&'static str>, message: String, span: Span, text: &str) -> Error {
+        Error {
+            code,
+            message,
+            labels: vec![Label {
+                span,
+                text: text.to_string(),
+                primary: true,
+            }],
+            note: None,
+        }
+    }
+
+    fn label(mut self, span: Span, text: &str) -> Error {
+        self.labels.push(Label {
+            span,
+            text: text.to_string(),
+            primary: false,
+        });
+        self
+    }
+
+    fn note(mut self, note: String) -> Error {
+        self.note = Some(note);
+        self
+    }
+
+    // Draws the error like rustc, with every label under its line of "source":
+    //      error[E0382]: borrow of moved value: `s1`
+    //       --> 3:23
+    //        |
+    //      2 | let s2 = s1;
+    //        |          -- value moved here
+    //      3 | println!("Hello, {}", s1);
+    //        |                       ^^ value borrowed here after move
+    pub fn render(&self, source: &str) -> String {
+        let position = |offset: usize| {
+            let before = &source[..offset.min(source.len())];
+            let line = before.matches('\n').count() + 1;
+            let column = before.chars().rev().take_while(|&c| c != '\n').count() + 1;
+            (line, column)
+        };
+
+        let mut labels: Vec<(usize, usize, &Label)> = self
+            .labels
+            .iter()
+            .map(|label| {
+                let (line, column) = position(label.span.start);
+                (line, column, label)
+            })
+            .collect();
+        labels.sort_by_key(|(line, column, _)| (*line, *column));
+        let width = labels.iter().map(|(line, _, _)| line.to_string().len()).max().unwrap_or(1);
+        let gutter = " ".repeat(width);
+
+        let mut text = match self.code {
+            Some(code) => format!("error[{}]: {}\n", code, self.message),
+            None => format!("error: {}\n", self.message),
+        };
+        if let Some(primary) = self.labels.iter().find(|label| label.primary) {
+            let (line, column) = position(primary.span.start);
+            text.push_str(&format!("{}--> {}:{}\n", gutter, line, column));
+        }
+        text.push_str(&format!("{} |\n", gutter));
+
+        let lines: Vec<&str> = source.lines().collect();
+        let mut previous = None;
+        for (line, column, label) in labels {
+            if previous != Some(line) {
+                let code = lines.get(line - 1).copied().unwrap_or("");
+                text.push_str(&format!("{:>width$} | {}\n", line, code, width = width));
+                previous = Some(line);
+            }
+            let marker = if label.primary { "^" } else { "-" };
+            let length = source[label.span.start..label.span.end].chars().count().max(1);
+            let underline = marker.repeat(length);
+            text.push_str(&format!("{} | {}{} {}\n", gutter, " ".repeat(column - 1), underline, label.text));
+        }
+        if let Some(note) = &self.note {
+            text.push_str(&format!("{} |\n{} = note: {}\n", gutter, gutter, note));
+        }
+        text
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.code {
+            Some(code) => write!(f, "error[{}]: {}", code, self.message),
+            None => write!(f, "error: {}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+// ------------------------------------------------------------------------------------------------------------------------------- //
+// Reading the code
+
+#[derive(Debug, Clone, PartialEq)]
+enum Tok {
+    Ident(String),
+    Int,
+    Str,
+    Punct(&'static str),
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    tok: Tok,
+    span: Span,
+}
+
+// The longer symbols come first, so that "+=" is not read as "+" and "=".
+const PUNCTUATION: &[&str] = &["::", "+=", "-=", "=", ";", "(", ")", "{", "}", "&", "*", ",", ".", "!"];
+
+fn tokenize(source: &str, offset: usize) -> Result<Vec<Token>, Error> {
+    let mut tokens = Vec::new();
+    let mut rest = source;
+    let mut at = offset;
+    loop {
+        let trimmed = rest.trim_start();
+        at += rest.len() - trimmed.len();
+        rest = trimmed;
+        // Comments are skipped.
+        if rest.starts_with("//") {
+            let end = rest.find('\n').unwrap_or(rest.len());
+            at += end;
+            rest = &rest[end..];
+            continue;
+        }
+        let Some(first) = rest.chars().next() else {
+            return Ok(tokens);
+        };
+
+        let (tok, length) = if first.is_alphabetic() || first == '_' {
+            let length = rest.find(|c: char| !(c.is_alphanumeric() || c == '_')).unwrap_or(rest.len());
+            (Tok::Ident(rest[..length].to_string()), length)
+        } else if first.is_ascii_digit() {
+            let length = rest.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_')).unwrap_or(rest.len());
+            (Tok::Int, length)
+        } else if first == '"' {
+            let Some(end) = rest[1..].find('"') else {
+                let span = Span { start: at, end: at + 1 };
+                return Err(Error::new(None, "unterminated double quote string".to_string(), span, "unterminated string"));
+            };
+            (Tok::Str, end + 2)
+        } else if let Some(punct) = PUNCTUATION.iter().find(|punct| rest.starts_with(**punct)) {
+            (Tok::Punct(punct), punct.len())
+        } else {
+            let span = Span {
+                start: at,
+                end: at + first.len_utf8(),
+            };
+            let message = format!("unknown start of token: {}", first);
+            return Err(Error::new(None, message, span, "not understood by the simulator"));
+        };
+        tokens.push(Token {
+            tok,
+            span: Span { start: at, end: at + length },
+        });
+        at += length;
+        rest = &rest[length..];
+    }
+}
+
+// Whether "code" can be run as it is: every brace and parenthesis is closed, and it ends with ";" or "}".
+// The "simulate" command of "main" reads lines until they form complete statements.
+pub fn is_complete(code: &str) -> bool {
+    // An unknown character is complete, so that running it shows the error, but an open string is not.
+    let Ok(tokens) = tokenize(code, 0) else {
+        return code.matches('"').count().is_multiple_of(2);
+    };
+    let depth = tokens.iter().fold(0i32, |depth, token| match token.tok {
+        Tok::Punct("{") | Tok::Punct("(") => depth + 1,
+        Tok::Punct("}") | Tok::Punct(")") => depth - 1,
+        _ => depth,
+    });
+    let ends = matches!(tokens.last(), None | Some(Token { tok: Tok::Punct(";") | Tok::Punct("}"), .. }));
+    depth <= 0 && ends
+}
+
+#[derive(Debug, Clone)]
+struct Name {
+    text: String,
+    span: Span,
+}
+
+#[derive(Debug, Clone)]
+enum ExprKind {
+    Int,
+    Str,
+    Var(Name),
+    Borrow { mutable: bool, name: Name },
+    Deref(Name),
+    Call { path: String, args: Vec<Expr>, is_macro: bool },
+    Method { receiver: Name, method: String, args: Vec<Expr> },
+}
+
+#[derive(Debug, Clone)]
+struct Expr {
+    kind: ExprKind,
+    span: Span,
+}
+
+#[derive(Debug, Clone)]
+enum Place {
+    Var(Name),
+    Deref(Name),
+}
+
+#[derive(Debug, Clone)]
+enum Stmt {
+    Let {
+        mutable: bool,
+        name: Name,
+        value: Option<Expr>,
+        span: Span,
+    },
+    Assign {
+        place: Place,
+        // "+=" and "-=" also read the old value.
+        compound: bool,
+        value: Expr,
+        span: Span,
+    },
+    Expr(Expr, Span),
+    Block {
+        stmts: Vec<Stmt>,
+        close: Span,
+    },
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    at: usize,
+    // Where the source ends, for the errors about a missing token at the end.
+    end: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Tok> {
+        self.tokens.get(self.at).map(|token| &token.tok)
+    }
+
+    fn span(&self) -> Span {
+        self.tokens.get(self.at).map(|token| token.span).unwrap_or(Span {
+            start: self.end,
+            end: self.end,
+        })
+    }
+
+    fn previous_span(&self) -> Span {
+        self.tokens[self.at.saturating_sub(1)].span
+    }
+
+    fn eat(&mut self, punct: &str) -> bool {
+        if self.peek() == Some(&Tok::Punct(punct_of(punct))) {
+            self.at += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        if matches!(self.peek(), Some(Tok::Ident(ident)) if ident == keyword) {
+            self.at += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn found(&self) -> String {
+        match self.tokens.get(self.at) {
+            Some(token) => match &token.tok {
+                Tok::Ident(ident) => format!("`{}`", ident),
+                Tok::Punct(punct) => format!("`{}`", punct),
+                Tok::Int => "an integer".to_string(),
+                Tok::Str => "a string".to_string(),
+            },
+            None => "the end of the code".to_string(),
+        }
+    }
+
+    fn expect(&mut self, punct: &str) -> Result<(), Error> {
+        if self.eat(punct) {
+            return Ok(());
+        }
+        let message = format!("expected `{}`, found {}", punct, self.found());
+        Err(Error::new(None, message, self.span(), &format!("expected `{}`", punct)))
+    }
+
+    fn name(&mut self) -> Result<Name, Error> {
+        match self.tokens.get(self.at) {
+            Some(Token {
+                tok: Tok::Ident(ident),
+                span,
+            }) if !["let", "mut"].contains(&ident.as_str()) => {
+                let name = Name {
+                    text: ident.clone(),
+                    span: *span,
+                };
+                self.at += 1;
+                Ok(name)
+            }
+            _ => {
+                let message = format!("expected a variable name, found {}", self.found());
+                Err(Error::new(None, message, self.span(), "expected a name"))
+            }
+        }
+    }
+
+    fn stmts(&mut self, until_brace: bool) -> Result<Vec<Stmt>, Error> {
+        let mut stmts = Vec::new();
+        while let Some(tok) = self.peek() {
+            if until_brace && *tok == Tok::Punct("}") {
+                break;
+            }
+            stmts.push(self.stmt()?);
+        }
+        Ok(stmts)
+    }
+
+    fn stmt(&mut self) -> Result<Stmt, Error> {
+        let start = self.span();
+        if self.eat("{") {
+            let stmts = self.stmts(true)?;
+            let close = self.span();
+            self.expect("}")?;
+            return Ok(Stmt::Block { stmts, close });
+        }
+
+        if self.eat_keyword("let") {
+            let mutable = self.eat_keyword("mut");
+            let name = self.name()?;
+            let value = if self.eat("=") { Some(self.expr()?) } else { None };
+            self.expect(";")?;
+            return Ok(Stmt::Let {
+                mutable,
+                name,
+                value,
+                span: start.to(self.previous_span()),
+            });
+        }
+
+        // "x = ...", "x += ..." and "*x = ..." are assignments, anything else is an expression.
+        let place = if self.eat("*") {
+            Some(Place::Deref(self.name()?))
+        } else if matches!(self.peek(), Some(Tok::Ident(_)))
+            && matches!(self.tokens.get(self.at + 1).map(|token| &token.tok), Some(Tok::Punct("=" | "+=" | "-=")))
+        {
+            Some(Place::Var(self.name()?))
+        } else {
+            None
+        };
+        if let Some(place) = place {
+            let compound = if self.eat("=") {
+                false
+            } else if self.eat("+=") || self.eat("-=") {
+                true
+            } else {
+                let message = format!("expected `=`, found {}", self.found());
+                return Err(Error::new(None, message, self.span(), "expected `=`"));
+            };
+            let value = self.expr()?;
+            self.expect(";")?;
+            return Ok(Stmt::Assign {
+                place,
+                compound,
+                value,
+                span: start.to(self.previous_span()),
+            });
+        }
+
+        let expr = self.expr()?;
+        self.expect(";")?;
+        Ok(Stmt::Expr(expr, start.to(self.previous_span())))
+    }
+
+    fn expr(&mut self) -> Result<Expr, Error> {
+        let start = self.span();
+        if self.eat("&") {
+            let mutable = self.eat_keyword("mut");
+            let name = self.name()?;
+            let span = start.to(name.span);
+            return Ok(Expr {
+                kind: ExprKind::Borrow { mutable, name },
+                span,
+            });
+        }
+        if self.eat("*") {
+            let name = self.name()?;
+            let span = start.to(name.span);
+            return Ok(Expr {
+                kind: ExprKind::Deref(name),
+                span,
+            });
+        }
+        match self.peek() {
+            Some(Tok::Int) => {
+                self.at += 1;
+                return Ok(Expr { kind: ExprKind::Int, span: start });
+            }
+            Some(Tok::Str) => {
+                self.at += 1;
+                return Ok(Expr { kind: ExprKind::Str, span: start });
+            }
+            _ => {}
+        }
+
+        // A variable, a function such as "String::from", a macro such as "println!", or a method call.
+        let name = self.name()?;
+        let mut path = name.text.clone();
+        while self.eat("::") {
+            path.push_str("::");
+            path.push_str(&self.name()?.text);
+        }
+        let is_macro = self.eat("!");
+        if self.eat("(") {
+            let args = self.args()?;
+            return Ok(Expr {
+                kind: ExprKind::Call { path, args, is_macro },
+                span: start.to(self.previous_span()),
+            });
+        }
+        if is_macro {
+            return Err(Error::new(None, format!("expected `(`, found {}", self.found()), self.span(), "expected `(`"));
+        }
+        if self.eat(".") {
+            let method = self.name()?.text;
+            self.expect("(")?;
+            let args = self.args()?;
+            return Ok(Expr {
+                kind: ExprKind::Method {
+                    receiver: name,
+                    method,
+                    args,
+                },
+                span: start.to(self.previous_span()),
+            });
+        }
+        let span = name.span;
+        Ok(Expr {
+            kind: ExprKind::Var(name),
+            span,
+        })
+    }
+
+    // The arguments of a call, after the "(".
+    fn args(&mut self) -> Result<Vec<Expr>, Error> {
+        let mut args = Vec::new();
+        while !self.eat(")") {
+            args.push(self.expr()?);
+            if !self.eat(",") {
+                self.expect(")")?;
+                break;
+            }
+        }
+        Ok(args)
+    }
+}
+
+// The "&'static str" in "PUNCTUATION" that is equal to "punct", so that it can be compared with a "Tok::Punct".
+fn punct_of(punct: &str) -> &'static str {
+    PUNCTUATION.iter().find(|p| **p == punct).copied().unwrap_or("")
+}
+
+fn parse(source: &str, offset: usize) -> Result<Vec<Stmt>, Error> {
+    let mut parser = Parser {
+        tokens: tokenize(&source[offset..], offset)?,
+        at: 0,
+        end: source.len(),
+    };
+    let stmts = parser.stmts(false)?;
+    Ok(stmts)
+}
+
+// ------------------------------------------------------------------------------------------------------------------------------- //
+// Running the code
+
+#[derive(Debug, Clone, PartialEq)]
+enum Ty {
+    Int,
+    Str,
+    String,
+    Unit,
+    Ref { mutable: bool, inner: Box<Ty> },
+}
+
+impl Ty {
+    // A shared reference can be copied, but a "&mut" cannot, since there can only be one.
+    fn is_copy(&self) -> bool {
+        match self {
+            Ty::Int | Ty::Str | Ty::Unit => true,
+            Ty::Ref { mutable, .. } => !mutable,
+            Ty::String => false,
+        }
+    }
+}
+
+impl fmt::Display for Ty {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Ty::Int => f.write_str("i32"),
+            Ty::Str => f.write_str("&str"),
+            Ty::String => f.write_str("String"),
+            Ty::Unit => f.write_str("()"),
+            Ty::Ref { mutable: true, inner } => write!(f, "&mut {}", inner),
+            Ty::Ref { mutable: false, inner } => write!(f, "&{}", inner),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Access {
+    Read,
+    Borrow,
+    BorrowMut,
+    Write,
+    Move,
+    Drop,
+}
+
+#[derive(Debug, Clone)]
+enum State {
+    Valid,
+    Uninit,
+    Moved(Span),
+}
+
+#[derive(Debug, Clone)]
+struct Var {
+    name: String,
+    mutable: bool,
+    // None until the variable is given a value.
+    ty: Option<Ty>,
+    state: State,
+    declared: Span,
+}
+
+// A borrow that is kept in a variable, such as "r1" in "let r1 = &s;".
+#[derive(Debug, Clone)]
+struct Loan {
+    holder: usize,
+    owner: usize,
+    mutable: bool,
+    span: Span,
+    // The access to the owner that ended the borrow, such as a second "&mut".
+    // The borrow cannot be used after it, and using it is the error.
+    ended: Option<(Access, Span)>,
+}
+
+// A value that is about to be stored in a variable, with the borrow it holds.
+struct Value {
+    ty: Ty,
+    loan: Option<Loan>,
+}
+
+// How a variable is used, which only changes the message when it has been moved.
+#[derive(Clone, Copy, PartialEq)]
+enum Use {
+    Borrow,
+    Other,
+}
+
+// The code and the state of every variable after one statement (or after the "}" of a block).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Step {
+    pub code: String,
+    pub state: Vec<String>,
+}
+
+// Written as the code, with the state under it:
+//      let s2 = s1;
+//          => s1: String (moved), s2: String
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", self.code)?;
+        if self.state.is_empty() {
+            writeln!(f, "    => (no variables)")
+        } else {
+            writeln!(f, "    => {}", self.state.join(", "))
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Outcome {
+    pub steps: Vec<Step>,
+    pub error: Option<Error>,
+}
+
+// The simulator keeps its variables between calls of "run", so a program can be given one statement at a time.
+#[derive(Debug, Clone, Default)]
+pub struct Simulator {
+    source: String,
+    vars: Vec<Var>,
+    // The variables of every block that is open, the outermost first.
+    scopes: Vec<Vec<usize>>,
+    loans: Vec<Loan>,
+}
+
+impl Simulator {
+    pub fn new() -> Simulator {
+        Simulator {
+            scopes: vec![Vec::new()],
+            ..Simulator::default()
+        }
+    }
+
+    // All the code that was run, which the spans of the errors point into.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    // Runs more code after the code that already ran.
+    // A statement that fails is undone, so the simulator can go on with the next one.
+    pub fn run(&mut self, code: &str) -> Outcome {
+        if !self.source.is_empty() && !self.source.ends_with('\n') {
+            self.source.push('\n');
+        }
+        let offset = self.source.len();
+        self.source.push_str(code);
+
+        let stmts = match parse(&self.source, offset) {
+            Ok(stmts) => stmts,
+            Err(error) => {
+                return Outcome {
+                    steps: Vec::new(),
+                    error: Some(error),
+                };
+            }
+        };
+        let mut steps = Vec::new();
+        for stmt in &stmts {
+            let before = (self.vars.clone(), self.scopes.clone(), self.loans.clone());
+            if let Err(error) = self.stmt(stmt, &mut steps) {
+                (self.vars, self.scopes, self.loans) = before;
+                return Outcome {
+                    steps,
+                    error: Some(error),
+                };
+            }
+        }
+        Outcome { steps, error: None }
+    }
+
+    fn text(&self, span: Span) -> &str {
+        &self.source[span.start..span.end]
+    }
+
+    // The state of every variable that is still alive, in the order they were declared.
+    fn state(&self) -> Vec<String> {
+        self.scopes
+            .iter()
+            .flatten()
+            .map(|&id| {
+                let var = &self.vars[id];
+                let prefix = if var.mutable { "mut " } else { "" };
+                let Some(ty) = &var.ty else {
+                    return format!("{}{}: (not initialized)", prefix, var.name);
+                };
+                let mut notes = Vec::new();
+                if let State::Moved(_) = var.state {
+                    notes.push("moved".to_string());
+                }
+                if let Some(loan) = self.loans.iter().find(|loan| loan.holder == id) {
+                    let owner = &self.vars[loan.owner].name;
+                    match loan.ended {
+                        None => notes.push(format!("borrows {}", owner)),
+                        Some(_) => notes.push(format!("borrowed {}, can no longer be used", owner)),
+                    }
+                }
+                let borrowers: Vec<&str> = self
+                    .loans
+                    .iter()
+                    .filter(|loan| loan.owner == id && loan.ended.is_none())
+                    .map(|loan| self.vars[loan.holder].name.as_str())
+                    .collect();
+                if !borrowers.is_empty() {
+                    notes.push(format!("borrowed by {}", borrowers.join(" and ")));
+                }
+                let notes = if notes.is_empty() { String::new() } else { format!(" ({})", notes.join(", ")) };
+                format!("{}{}: {}{}", prefix, var.name, ty, notes)
+            })
+            .collect()
+    }
+
+    fn step(&self, span: Span, steps: &mut Vec<Step>) {
+        steps.push(Step {
+            code: self.text(span).to_string(),
+            state: self.state(),
+        });
+    }
+
+    fn lookup(&self, name: &Name) -> Result<usize, Error> {
+        self.scopes
+            .iter()
+            .flatten()
+            .rev()
+            .copied()
+            .find(|&id| self.vars[id].name == name.text)
+            .ok_or_else(|| {
+                let message = format!("cannot find value `{}` in this scope", name.text);
+                Error::new(Some("E0425"), message, name.span, "not found in this scope")
+            })
+    }
+
+    // Another access to "owner" ends the borrows of it that cannot exist at the same time:
+    // reading or borrowing it ends the "&mut" borrows, and anything else ends every borrow.
+    fn access(&mut self, owner: usize, access: Access, span: Span) {
+        for loan in self.loans.iter_mut().filter(|loan| loan.owner == owner && loan.ended.is_none()) {
+            let ends = match access {
+                Access::Read | Access::Borrow => loan.mutable,
+                Access::BorrowMut | Access::Write | Access::Move | Access::Drop => true,
+            };
+            if ends {
+                loan.ended = Some((access, span));
+            }
+        }
+    }
+
+    // Checks that "id" can be used: it has a value that was not moved, and the borrow it holds has not ended.
+    fn use_var(&self, id: usize, span: Span, how: Use) -> Result<(), Error> {
+        let var = &self.vars[id];
+        match (&var.ty, &var.state) {
+            (None, _) | (_, State::Uninit) => {
+                let message = format!("used binding `{}` isn't initialized", var.name);
+                let text = format!("`{}` used here but it isn't initialized", var.name);
+                return Err(Error::new(Some("E0381"), message, span, &text)
+                    .label(var.declared, "binding declared here but left uninitialized"));
+            }
+            (Some(ty), State::Moved(moved)) => {
+                let (message, text) = match how {
+                    Use::Borrow => ("borrow of moved value", "value borrowed here after move"),
+                    Use::Other => ("use of moved value", "value used here after move"),
+                };
+                let note = format!("move occurs because `{}` has type `{}`, which does not implement the `Copy` trait", var.name, ty);
+                return Err(Error::new(Some("E0382"), format!("{}: `{}`", message, var.name), span, text)
+                    .label(*moved, "value moved here")
+                    .note(note));
+            }
+            (Some(_), State::Valid) => {}
+        }
+
+        let Some(loan) = self.loans.iter().find(|loan| loan.holder == id) else {
+            return Ok(());
+        };
+        let Some((access, ended)) = loan.ended else {
+            return Ok(());
+        };
+        let owner = &self.vars[loan.owner].name;
+        let (kind, other) = if loan.mutable { ("mutable", "immutable") } else { ("immutable", "mutable") };
+        let error = match access {
+            Access::BorrowMut if loan.mutable => {
+                let message = format!("cannot borrow `{}` as mutable more than once at a time", owner);
+                Error::new(Some("E0499"), message, ended, "second mutable borrow occurs here")
+                    .label(loan.span, "first mutable borrow occurs here")
+                    .label(span, "first borrow later used here")
+            }
+            Access::Read | Access::Borrow | Access::BorrowMut => {
+                let message = format!("cannot borrow `{}` as {} because it is also borrowed as {}", owner, other, kind);
+                Error::new(Some("E0502"), message, ended, &format!("{} borrow occurs here", other))
+                    .label(loan.span, &format!("{} borrow occurs here", kind))
+                    .label(span, &format!("{} borrow later used here", kind))
+            }
+            Access::Write => {
+                let message = format!("cannot assign to `{}` because it is borrowed", owner);
+                let text = format!("`{}` is assigned to here but it was already borrowed", owner);
+                Error::new(Some("E0506"), message, ended, &text)
+                    .label(loan.span, &format!("`{}` is borrowed here", owner))
+                    .label(span, "borrow later used here")
+            }
+            Access::Move => {
+                let message = format!("cannot move out of `{}` because it is borrowed", owner);
+                Error::new(Some("E0505"), message, ended, &format!("move out of `{}` occurs here", owner))
+                    .label(loan.span, &format!("borrow of `{}` occurs here", owner))
+                    .label(span, "borrow later used here")
+            }
+            Access::Drop => {
+                let message = format!("`{}` does not live long enough", owner);
+                Error::new(Some("E0597"), message, loan.span, "borrowed value does not live long enough")
+                    .label(ended, &format!("`{}` dropped here while still borrowed", owner))
+                    .label(span, "borrow later used here")
+            }
+        };
+        Err(error)
+    }
+
+    // Evaluates an expression. A value that is only passed to a function ("temporary") is used right away,
+    // while a value that is stored keeps its borrow for as long as the variable uses it.
+    fn expr(&mut self, expr: &Expr, temporary: bool) -> Result<Value, Error> {
+        match &expr.kind {
+            ExprKind::Int => Ok(Value { ty: Ty::Int, loan: None }),
+            ExprKind::Str => Ok(Value { ty: Ty::Str, loan: None }),
+            ExprKind::Var(name) => {
+                let id = self.lookup(name)?;
+                self.use_var(id, name.span, Use::Other)?;
+                let ty = self.vars[id].ty.clone().unwrap_or(Ty::Unit);
+                let held = self.loans.iter().position(|loan| loan.holder == id);
+
+                if ty.is_copy() {
+                    self.access(id, Access::Read, name.span);
+                    // A copied "&T" borrows the same value as the original.
+                    let loan = held.map(|at| self.loans[at].clone());
+                    return Ok(Value { ty, loan });
+                }
+                // A "&mut" that is passed to a function is borrowed again for the call, rather than moved (a "reborrow").
+                if temporary && matches!(ty, Ty::Ref { .. }) {
+                    return Ok(Value { ty, loan: None });
+                }
+                self.access(id, Access::Move, name.span);
+                self.vars[id].state = State::Moved(name.span);
+                let loan = held.map(|at| self.loans.remove(at));
+                Ok(Value { ty, loan })
+            }
+            ExprKind::Borrow { mutable, name } => {
+                let id = self.lookup(name)?;
+                self.use_var(id, name.span, Use::Borrow)?;
+                let var = &self.vars[id];
+                if *mutable && !var.mutable {
+                    let message = format!("cannot borrow `{}` as mutable, as it is not declared as mutable", var.name);
+                    return Err(Error::new(Some("E0596"), message, expr.span, "cannot borrow as mutable")
+                        .label(var.declared, "help: consider changing this to be mutable: `mut`"));
+                }
+                let inner = var.ty.clone().unwrap_or(Ty::Unit);
+                let access = if *mutable { Access::BorrowMut } else { Access::Borrow };
+                self.access(id, access, expr.span);
+                Ok(Value {
+                    ty: Ty::Ref {
+                        mutable: *mutable,
+                        inner: Box::new(inner),
+                    },
+                    loan: Some(Loan {
+                        holder: usize::MAX,
+                        owner: id,
+                        mutable: *mutable,
+                        span: expr.span,
+                        ended: None,
+                    }),
+                })
+            }
+            ExprKind::Deref(name) => {
+                let id = self.lookup(name)?;
+                self.use_var(id, name.span, Use::Other)?;
+                let ty = self.vars[id].ty.clone().unwrap_or(Ty::Unit);
+                let Ty::Ref { inner, mutable } = ty else {
+                    let message = format!("type `{}` cannot be dereferenced", ty);
+                    return Err(Error::new(Some("E0614"), message, expr.span, "can't be dereferenced"));
+                };
+                if !inner.is_copy() {
+                    let kind = if mutable { "mutable" } else { "shared" };
+                    let message = format!("cannot move out of `*{}` which is behind a {} reference", name.text, kind);
+                    let text = format!(
+                        "move occurs because `*{}` has type `{}`, which does not implement the `Copy` trait",
+                        name.text, inner
+                    );
+                    return Err(Error::new(Some("E0507"), message, expr.span, &text));
+                }
+                Ok(Value { ty: *inner, loan: None })
+            }
+            ExprKind::Call { path, args, is_macro } => {
+                for arg in args {
+                    match (&arg.kind, is_macro) {
+                        // "println!" and the other macros only borrow the variables they are given.
+                        (ExprKind::Var(name), true) => {
+                            let id = self.lookup(name)?;
+                            self.use_var(id, name.span, Use::Borrow)?;
+                            self.access(id, Access::Borrow, name.span);
+                        }
+                        _ => {
+                            self.expr(arg, true)?;
+                        }
+                    }
+                }
+                let ty = match path.as_str() {
+                    "String::from" | "String::new" => Ty::String,
+                    _ => Ty::Unit,
+                };
+                Ok(Value { ty, loan: None })
+            }
+            ExprKind::Method { receiver, method, args } => {
+                let id = self.lookup(receiver)?;
+                let mutating = MUTATING_METHODS.contains(&method.as_str());
+                self.use_var(id, receiver.span, Use::Borrow)?;
+                let var = &self.vars[id];
+                let ty = var.ty.clone().unwrap_or(Ty::Unit);
+                let value_ty = match &ty {
+                    // A method called on a reference works on the value behind it.
+                    Ty::Ref { mutable, inner } => {
+                        if mutating && !mutable {
+                            let message = format!("cannot borrow `*{}` as mutable, as it is behind a `&` reference", var.name);
+                            let text = format!("`{}` is a `&` reference, so the data it refers to cannot be borrowed as mutable", var.name);
+                            return Err(Error::new(Some("E0596"), message, expr.span, &text));
+                        }
+                        (**inner).clone()
+                    }
+                    _ => {
+                        if mutating && !var.mutable {
+                            let message = format!("cannot borrow `{}` as mutable, as it is not declared as mutable", var.name);
+                            return Err(Error::new(Some("E0596"), message, expr.span, "cannot borrow as mutable")
+                                .label(var.declared, "help: consider changing this to be mutable: `mut`"));
+                        }
+                        let access = if mutating { Access::BorrowMut } else { Access::Borrow };
+                        self.access(id, access, receiver.span);
+                        ty
+                    }
+                };
+                for arg in args {
+                    self.expr(arg, true)?;
+                }
+                let ty = match method.as_str() {
+                    "clone" | "to_string" | "to_owned" if value_ty == Ty::Str => Ty::String,
+                    "clone" => value_ty,
+                    "len" => Ty::Int,
+                    _ => Ty::Unit,
+                };
+                Ok(Value { ty, loan: None })
+            }
+        }
+    }
+
+    // Gives "id" a new value. The borrow it held before (if any) is gone.
+    fn store(&mut self, id: usize, value: Value) {
+        self.loans.retain(|loan| loan.holder != id);
+        if let Some(mut loan) = value.loan {
+            loan.holder = id;
+            self.loans.push(loan);
+        }
+        self.vars[id].ty = Some(value.ty);
+        self.vars[id].state = State::Valid;
+    }
+
+    fn stmt(&mut self, stmt: &Stmt, steps: &mut Vec<Step>) -> Result<(), Error> {
+        match stmt {
+            Stmt::Let {
+                mutable,
+                name,
+                value,
+                span,
+            } => {
+                let value = value.as_ref().map(|value| self.expr(value, false)).transpose()?;
+                let id = self.vars.len();
+                self.vars.push(Var {
+                    name: name.text.clone(),
+                    mutable: *mutable,
+                    ty: None,
+                    state: State::Uninit,
+                    declared: name.span,
+                });
+                self.scopes.last_mut().expect("there is always a scope").push(id);
+                if let Some(value) = value {
+                    self.store(id, value);
+                }
+                self.step(*span, steps);
+            }
+            Stmt::Assign {
+                place: Place::Var(name),
+                compound,
+                value,
+                span,
+            } => {
+                // The errors point at "x = 5" without the ";".
+                let assigned = span.to(value.span);
+                let id = self.lookup(name)?;
+                if *compound {
+                    self.use_var(id, name.span, Use::Other)?;
+                }
+                let value = self.expr(value, false)?;
+                let var = &self.vars[id];
+                if var.ty.is_some() && !var.mutable {
+                    let message = format!("cannot assign twice to immutable variable `{}`", var.name);
+                    return Err(Error::new(Some("E0384"), message, assigned, "cannot assign twice to immutable variable")
+                        .label(var.declared, &format!("first assignment to `{}`", var.name)));
+                }
+                self.access(id, Access::Write, assigned);
+                if *compound {
+                    // "x += 1" keeps the type of "x".
+                    let ty = self.vars[id].ty.clone().unwrap_or(value.ty);
+                    self.vars[id].ty = Some(ty);
+                } else {
+                    self.store(id, value);
+                }
+                self.step(*span, steps);
+            }
+            Stmt::Assign {
+                place: Place::Deref(name),
+                compound: _,
+                value,
+                span,
+            } => {
+                let id = self.lookup(name)?;
+                self.use_var(id, name.span, Use::Other)?;
+                if !matches!(self.vars[id].ty, Some(Ty::Ref { mutable: true, .. })) {
+                    let message = format!("cannot assign to `*{}`, which is behind a `&` reference", name.text);
+                    let text = format!("`{}` is a `&` reference, so the data it refers to cannot be written", name.text);
+                    return Err(Error::new(Some("E0594"), message, span.to(value.span), &text));
+                }
+                self.expr(value, false)?;
+                self.step(*span, steps);
+            }
+            Stmt::Expr(expr, span) => {
+                self.expr(expr, true)?;
+                self.step(*span, steps);
+            }
+            Stmt::Block { stmts, close } => {
+                self.scopes.push(Vec::new());
+                for stmt in stmts {
+                    self.stmt(stmt, steps)?;
+                }
+                // The variables of the block are dropped in the opposite order to how they were declared.
+                let ended = self.scopes.pop().expect("the block pushed a scope");
+                for &id in ended.iter().rev() {
+                    self.loans.retain(|loan| loan.holder != id);
+                    self.access(id, Access::Drop, *close);
+                }
+                self.step(*close, steps);
+            }
+        }
+        Ok(())
+    }
+}
+
+// Runs a whole program from the start.
+pub fn simulate(code: &str) -> Outcome {
+    Simulator::new().run(code)
+}
+
+// ------------------------------------------------------------------------------------------------------------------------------- //
+// The lesson
+
+// The code of "borrow_vs_moving_str()", "multi_mut_ref_error()" and "demo_read_with_write()" in this crate, run one statement at a time.
+const PROGRAMS: &[(&str, &str)] = &[
+    (
+        "borrow_vs_moving_str",
+        r#"let s1 = String::from("Khalili");
+let s2 = s1;
+println!("Hello, {}", s1);
+println!("Hello, {}", s2);"#,
+    ),
+    (
+        "borrow_vs_moving_str_correction",
+        r#"let s1 = String::from("Khalili");
+let s2 = &s1;
+println!("Hello, {}", s1);
+println!("Hello, {}", s2);"#,
+    ),
+    (
+        "multi_mut_ref_error",
+        r#"let mut x = 5;
+let r1 = &mut x;
+let r2 = &mut x;
+*r1 += 1;
+*r2 += 1;"#,
+    ),
+    (
+        "demo_read_with_write (the error)",
+        r#"let mut s = String::from("hello");
+let r1 = &s;
+let r2 = &mut s;
+println!("{} {}", r1, r2);"#,
+    ),
+    (
+        "demo_read_with_write",
+        r#"let mut s = String::from("hello");
+{
+    let r1 = &mut s;
+    r1.push_str(" world");
+}
+let r2 = &mut s;
+r2.push_str("!");
+println!("{}", s);"#,
+    ),
+];
+
+pub fn borrow_simulator_to(out: &mut dyn Write) -> io::Result<()> {
+    for (i, (name, code)) in PROGRAMS.iter().enumerate() {
+        if i > 0 {
+            writeln!(out)?;
+        }
+        writeln!(out, "--- {}", name)?;
+        let mut simulator = Simulator::new();
+        let outcome = simulator.run(code);
+        for step in &outcome.steps {
+            write!(out, "{}", step)?;
+        }
+        if let Some(error) = outcome.error {
+            write!(out, "{}", error.render(simulator.source()))?;
+        }
+    }
+    Ok(())
+}
+
+pub fn borrow_simulator() {
+    borrow_simulator_to(&mut io::stdout()).expect("failed to write to stdout");
+}
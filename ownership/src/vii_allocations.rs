@@ -0,0 +1,203 @@
+// "ii_value_vs_reference" says that passing a "String" moves it without copying it, and that "&String" only borrows it.
+// This module proves it, by counting every allocation of the program with a global allocator.
+// It is only built with the "count_allocations" feature, since the allocator then counts the allocations of the whole program:
+//      cargo test -p ownership --features count_allocations
+// The counts are kept per thread, so that "measure()" only sees the allocations of the code it runs,
+// and not those of the other tests that run at the same time.
+// In release builds, the optimizer removes an allocation that is freed without being looked at.
+// So the values that are measured go through "black_box()", which hides them from the optimizer, and the count is the same in every build.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt;
+use std::hint::black_box;
+use std::io::{self, Write};
+use std::ops::Sub;
+
+use crate::ii_value_vs_reference::{borrow_string_to, copied_to, takes_ownership_to};
+
+// What was allocated and freed, in the scope given to "measure()".
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct AllocStats {
+    pub allocations: usize,
+    pub deallocations: usize,
+    // A "Vec" or "String" that grows asks for a bigger block, which may or may not move its elements.
+    pub reallocations: usize,
+    pub bytes_allocated: usize,
+    pub bytes_deallocated: usize,
+}
+
+impl AllocStats {
+    const ZERO: AllocStats = AllocStats {
+        allocations: 0,
+        deallocations: 0,
+        reallocations: 0,
+        bytes_allocated: 0,
+        bytes_deallocated: 0,
+    };
+
+    // How many more bytes are in use after the scope than before it.
+    pub fn net_bytes(&self) -> isize {
+        self.bytes_allocated as isize - self.bytes_deallocated as isize
+    }
+}
+
+// Only the counts that are not zero are written, such as "1 allocation (5 bytes), 1 deallocation (5 bytes)".
+impl fmt::Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let plural = |count: usize| if count == 1 { "" } else { "s" };
+        let mut parts = Vec::new();
+        if self.allocations > 0 {
+            parts.push(format!(
+                "{} allocation{} ({} bytes)",
+                self.allocations,
+                plural(self.allocations),
+                self.bytes_allocated
+            ));
+        }
+        if self.reallocations > 0 {
+            parts.push(format!("{} reallocation{}", self.reallocations, plural(self.reallocations)));
+        }
+        if self.deallocations > 0 {
+            parts.push(format!(
+                "{} deallocation{} ({} bytes)",
+                self.deallocations,
+                plural(self.deallocations),
+                self.bytes_deallocated
+            ));
+        }
+        if parts.is_empty() {
+            f.write_str("no allocations")
+        } else {
+            f.write_str(&parts.join(", "))
+        }
+    }
+}
+
+impl Sub for AllocStats {
+    type Output = AllocStats;
+
+    fn sub(self, before: AllocStats) -> AllocStats {
+        AllocStats {
+            allocations: self.allocations - before.allocations,
+            deallocations: self.deallocations - before.deallocations,
+            reallocations: self.reallocations - before.reallocations,
+            bytes_allocated: self.bytes_allocated - before.bytes_allocated,
+            bytes_deallocated: self.bytes_deallocated - before.bytes_deallocated,
+        }
+    }
+}
+
+// The counts only ever grow, so a scope is the difference between the counts at its end and at its start.
+// The "const" initializer matters: a thread local that allocated when it is first used would call the allocator from inside the allocator.
+thread_local! {
+    static COUNTS: Cell<AllocStats> = const { Cell::new(AllocStats::ZERO) };
+}
+
+fn count(update: impl FnOnce(&mut AllocStats)) {
+    // "try_with" fails while the thread is being torn down, and those allocations are simply not counted.
+    let _ = COUNTS.try_with(|counts| {
+        let mut current = counts.get();
+        update(&mut current);
+        counts.set(current);
+    });
+}
+
+// Every allocation still goes to the allocator of the system, and is only counted on the way.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count(|counts| {
+            counts.allocations += 1;
+            counts.bytes_allocated += layout.size();
+        });
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        count(|counts| {
+            counts.deallocations += 1;
+            counts.bytes_deallocated += layout.size();
+        });
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    // Without this, the default "realloc" would be counted as a new allocation and a deallocation.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count(|counts| {
+            counts.reallocations += 1;
+            counts.bytes_allocated += new_size;
+            counts.bytes_deallocated += layout.size();
+        });
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+// Runs "scope" and returns what it allocated and freed on this thread.
+// Everything that "scope" owns is dropped inside it, so its deallocations are counted too.
+pub fn measure(scope: impl FnOnce()) -> AllocStats {
+    measure_value(scope).1
+}
+
+// Like "measure()", but keeps the value that "scope" returns, so that it is not freed inside the scope.
+pub fn measure_value<T>(scope: impl FnOnce() -> T) -> (T, AllocStats) {
+    let before = COUNTS.with(Cell::get);
+    let value = black_box(scope());
+    let after = COUNTS.with(Cell::get);
+    (value, after - before)
+}
+
+// The functions of "ii_value_vs_reference", measured one by one.
+// They write to "io::sink()", since writing to "out" could grow it and would be counted as well.
+pub fn allocation_costs_to(out: &mut dyn Write) -> io::Result<()> {
+    let (s, created) = measure_value(|| String::from("hello"));
+    writeln!(out, "{:<40} {}", "let s = String::from(\"hello\");", created)?;
+    assert_eq!(created.allocations, 1, "a String puts its bytes on the heap");
+
+    // Borrowing does not touch the heap at all.
+    let borrowed = measure(|| borrow_string_to(&mut io::sink(), black_box(&s)).expect("writing to a sink cannot fail"));
+    writeln!(out, "{:<40} {}", "borrow_string(&s);", borrowed)?;
+    assert_eq!(borrowed, AllocStats::default(), "a borrow allocates nothing");
+
+    // A clone copies the bytes into a new block, which the function frees when it returns.
+    let cloned = measure(|| takes_ownership_to(&mut io::sink(), black_box(s.clone())).expect("writing to a sink cannot fail"));
+    writeln!(out, "{:<40} {}", "takes_ownership(s.clone());", cloned)?;
+    assert_eq!((cloned.allocations, cloned.deallocations), (1, 1), "a clone allocates once");
+
+    // A move only copies the pointer, the length and the capacity: the same block is freed by the function.
+    let moved = measure(|| takes_ownership_to(&mut io::sink(), black_box(s)).expect("writing to a sink cannot fail"));
+    writeln!(out, "{:<40} {}", "takes_ownership(s);", moved)?;
+    assert_eq!((moved.allocations, moved.deallocations), (0, 1), "a move allocates nothing");
+
+    // An "i32" lives on the stack, and is copied without the heap.
+    let copied = measure(|| copied_to(&mut io::sink(), black_box(5)).expect("writing to a sink cannot fail"));
+    writeln!(out, "{:<40} {}", "copied(5);", copied)?;
+    assert_eq!(copied, AllocStats::default(), "an i32 is copied on the stack");
+
+    // "optionals" clones "name" before every "match", which costs an allocation each time.
+    // "as_deref()" looks at the name through a reference instead.
+    let name = Some(String::from("Khalili"));
+    let matched = measure(|| match black_box(name.clone()) {
+        Some(name) => assert_eq!(name, "Khalili"),
+        None => unreachable!(),
+    });
+    writeln!(out, "{:<40} {}", "match name.clone() { ... }", matched)?;
+    assert_eq!(matched.allocations, 1, "cloning an Option<String> clones the String");
+
+    let looked = measure(|| match black_box(name.as_deref()) {
+        Some(name) => assert_eq!(name, "Khalili"),
+        None => unreachable!(),
+    });
+    writeln!(out, "{:<40} {}", "match name.as_deref() { ... }", looked)?;
+    assert_eq!(looked, AllocStats::default(), "as_deref() only borrows");
+
+    Ok(())
+}
+
+pub fn allocation_costs() {
+    allocation_costs_to(&mut io::stdout()).expect("failed to write to stdout");
+}
@@ -0,0 +1,304 @@
+// "i_borrow_move::code_block" says that "name" is freed at the end of its block, but a "String" is freed without a word.
+// "Traced<T>" wraps a value and writes to a "Timeline" when the value is created, moved and dropped, so the order can be seen:
+//      { code_block
+//          create greeting
+//          { block
+//              create name
+//              drop name
+//          }
+//          drop greeting
+//      }
+// A plain move ("let s2 = s1;") copies the bytes of the value and cannot be seen, so a move is made visible with "hand_off()".
+
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::io::{self, Write};
+use std::ops::{Deref, DerefMut};
+use std::rc::Rc;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EventKind {
+    Create(String),
+    Move { from: String, to: String },
+    Drop(String),
+    // The start and the end of a scope given to "Timeline::scope()".
+    Enter(String),
+    Exit(String),
+    Note(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Event {
+    // How many scopes were open.
+    pub depth: usize,
+    pub kind: EventKind,
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let indent = "    ".repeat(self.depth);
+        match &self.kind {
+            EventKind::Create(name) => write!(f, "{}create {}", indent, name),
+            EventKind::Move { from, to } => write!(f, "{}move {} -> {}", indent, from, to),
+            EventKind::Drop(name) => write!(f, "{}drop {}", indent, name),
+            EventKind::Enter(name) => write!(f, "{}{{ {}", indent, name),
+            EventKind::Exit(_) => write!(f, "{}}}", indent),
+            EventKind::Note(text) => write!(f, "{}// {}", indent, text),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Log {
+    events: RefCell<Vec<Event>>,
+    depth: Cell<usize>,
+}
+
+// Where the traced values write what happens to them. Cloning it gives another handle to the same timeline.
+#[derive(Debug, Clone, Default)]
+pub struct Timeline {
+    log: Rc<Log>,
+}
+
+impl Timeline {
+    pub fn new() -> Timeline {
+        Timeline::default()
+    }
+
+    fn record(&self, kind: EventKind) {
+        let depth = self.log.depth.get();
+        self.log.events.borrow_mut().push(Event { depth, kind });
+    }
+
+    pub fn traced<T>(&self, name: &str, value: T) -> Traced<T> {
+        self.record(EventKind::Create(name.to_string()));
+        Traced {
+            name: name.to_string(),
+            value: Some(value),
+            timeline: self.clone(),
+        }
+    }
+
+    // Runs "body" one level deeper, so that everything it creates and drops is indented under "name".
+    // The variables of "body" are dropped before it returns, so their drops are inside the scope too.
+    pub fn scope<R>(&self, name: &str, body: impl FnOnce() -> R) -> R {
+        self.record(EventKind::Enter(name.to_string()));
+        self.log.depth.set(self.log.depth.get() + 1);
+        let result = body();
+        self.log.depth.set(self.log.depth.get() - 1);
+        self.record(EventKind::Exit(name.to_string()));
+        result
+    }
+
+    // A comment in the timeline, such as where a statement ends.
+    pub fn note(&self, text: &str) {
+        self.record(EventKind::Note(text.to_string()));
+    }
+
+    pub fn events(&self) -> Vec<Event> {
+        self.log.events.borrow().clone()
+    }
+
+    // The names of the dropped values, in the order they were dropped.
+    pub fn drops(&self) -> Vec<String> {
+        self.log
+            .events
+            .borrow()
+            .iter()
+            .filter_map(|event| match &event.kind {
+                EventKind::Drop(name) => Some(name.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+// Every event on its own line, indented by the scopes around it.
+impl fmt::Display for Timeline {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for event in self.log.events.borrow().iter() {
+            writeln!(f, "{}", event)?;
+        }
+        Ok(())
+    }
+}
+
+// A value that writes to its timeline when it is dropped.
+// It can be used as the value it holds, since it derefs to it.
+pub struct Traced<T> {
+    name: String,
+    // Only None after "hand_off()", which took the value out, so that nothing is written when the empty shell is dropped.
+    value: Option<T>,
+    timeline: Timeline,
+}
+
+impl<T> Traced<T> {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    // Moves the value to a new owner called "to", and writes the move to the timeline.
+    // The old "Traced" is consumed, like a variable after a move, and only the new one writes a drop.
+    pub fn hand_off(mut self, to: &str) -> Traced<T> {
+        self.timeline.record(EventKind::Move {
+            from: self.name.clone(),
+            to: to.to_string(),
+        });
+        Traced {
+            name: to.to_string(),
+            value: self.value.take(),
+            timeline: self.timeline.clone(),
+        }
+    }
+
+    // Takes the value out, without dropping it. Nothing is written, since the value now belongs to the caller.
+    pub fn into_inner(mut self) -> T {
+        self.value.take().expect("a Traced always holds its value until it is consumed")
+    }
+}
+
+impl<T> Deref for Traced<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.value.as_ref().expect("a Traced always holds its value until it is consumed")
+    }
+}
+
+impl<T> DerefMut for Traced<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.value.as_mut().expect("a Traced always holds its value until it is consumed")
+    }
+}
+
+// "drop()" runs before the value inside is dropped, so a "Traced" is always written before the fields of the value it holds.
+impl<T> Drop for Traced<T> {
+    fn drop(&mut self) {
+        if self.value.is_some() {
+            self.timeline.record(EventKind::Drop(self.name.clone()));
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Traced<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Traced").field("name", &self.name).field("value", &self.value).finish()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------------------------------------- //
+// The demos
+
+// "i_borrow_move::code_block", with a variable outside of the block as well.
+// "name" is dropped at the "}" of its block, and "greeting" at the end of the function.
+pub fn code_block(timeline: &Timeline) {
+    timeline.scope("code_block", || {
+        let _greeting = timeline.traced("greeting", String::from("hello"));
+        timeline.scope("block", || {
+            let _name = timeline.traced("name", String::from("Khan"));
+        });
+    });
+}
+
+// "variables::vars" shadows "_name" twice. Shadowing does not drop the old value: it is only hidden, and lives until the end of the scope.
+// Variables are dropped in the opposite order to how they were declared, so the newest "_name" goes first.
+// Assigning to a variable is different: the old value is dropped right away, once the new one is in its place.
+pub fn shadowing(timeline: &Timeline) {
+    timeline.scope("shadowing", || {
+        let _name = timeline.traced("_name = \"Khan\"", "Khan");
+        let _name = timeline.traced("_name = \"Khalili\"", "Khalili");
+        timeline.note("both _name are still alive");
+
+        let mut _y = timeline.traced("_y = \"This is a string literal\"", "This is a string literal");
+        _y = timeline.traced("_y = \"Hello\"", "Hello");
+        timeline.note("the first _y was dropped by the assignment");
+    });
+}
+
+fn takes_ownership(s: Traced<String>) {
+    let _s = s.hand_off("s in takes_ownership");
+}
+
+// "ii_value_vs_reference::demo_ownership": the value is moved twice, and dropped only once, at the end of the function that owns it last.
+pub fn moves(timeline: &Timeline) {
+    timeline.scope("moves", || {
+        let s1 = timeline.traced("s1", String::from("Khalili"));
+        let s2 = s1.hand_off("s2");
+        timeline.scope("takes_ownership(s2)", || takes_ownership(s2));
+        timeline.note("s2 was moved, so nothing is left to drop here");
+    });
+}
+
+pub struct Person {
+    pub first_name: Traced<String>,
+    pub last_name: Traced<String>,
+}
+
+// The fields of a struct are dropped after the struct, in the order they are declared (not the order they were created in).
+pub fn struct_fields(timeline: &Timeline) {
+    timeline.scope("struct_fields", || {
+        let last_name = timeline.traced("last_name", String::from("Khalili"));
+        let first_name = timeline.traced("first_name", String::from("Mo"));
+        let _person = timeline.traced(
+            "person",
+            Person {
+                first_name: first_name.hand_off("person.first_name"),
+                last_name: last_name.hand_off("person.last_name"),
+            },
+        );
+    });
+}
+
+// The elements of a "Vec" are dropped after the "Vec", from the first to the last.
+// An element that is removed from the "Vec" belongs to whoever took it.
+pub fn vec_elements(timeline: &Timeline) {
+    timeline.scope("vec_elements", || {
+        let mut names = timeline.traced("names", Vec::new());
+        for name in ["a", "b", "c", "d"] {
+            names.push(timeline.traced(&format!("names[{}]", name), name.to_string()));
+        }
+        let removed = names.remove(1);
+        timeline.scope("drop(removed)", || drop(removed));
+    });
+}
+
+// The same function as "lifetimes::generic_lifetimes::longest".
+fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
+    if x.len() > y.len() { x } else { y }
+}
+
+// "lifetimes::generic_lifetimes::input_lifetime" calls "longest(&"Khannn".to_string(), &"Khalili".to_string())".
+// The two "String"s are temporaries, which are dropped at the end of the statement, newest first, so the result cannot be kept.
+// Giving them names with "let" keeps them until the end of the scope.
+pub fn temporaries(timeline: &Timeline) {
+    timeline.scope("temporaries", || {
+        let length = longest(
+            &timeline.traced("\"Khannn\".to_string()", "Khannn".to_string()),
+            &timeline.traced("\"Khalili\".to_string()", "Khalili".to_string()),
+        )
+        .len();
+        timeline.note(&format!("end of the statement, only the length ({}) was kept", length));
+
+        let x = timeline.traced("x", "Khannn".to_string());
+        let y = timeline.traced("y", "Khalili".to_string());
+        let longer = longest(&x, &y);
+        timeline.note(&format!("longer = {:?}, x and y are still alive", longer));
+    });
+}
+
+pub fn drop_order_to(out: &mut dyn Write) -> io::Result<()> {
+    let demos: [fn(&Timeline); 6] = [code_block, shadowing, moves, struct_fields, vec_elements, temporaries];
+    for (i, demo) in demos.iter().enumerate() {
+        if i > 0 {
+            writeln!(out)?;
+        }
+        let timeline = Timeline::new();
+        demo(&timeline);
+        write!(out, "{}", timeline)?;
+    }
+    Ok(())
+}
+
+pub fn drop_order() {
+    drop_order_to(&mut io::stdout()).expect("failed to write to stdout");
+}
@@ -0,0 +1,103 @@
+// The tests of "src/vii_allocations.rs".
+// They only run with the counting allocator:
+//      cargo test -p ownership --features count_allocations
+// Every measured value goes through "black_box()", since the optimizer of a release build removes the allocations that are never looked at.
+#![cfg(feature = "count_allocations")]
+
+use std::hint::black_box;
+use std::io;
+use std::process::Command;
+use std::thread;
+
+use ownership::ii_value_vs_reference::{borrow_string_to, takes_ownership_to};
+use ownership::vii_allocations::{AllocStats, measure, measure_value};
+
+#[test]
+fn a_move_allocates_nothing_and_a_clone_allocates_once() {
+    let s = String::from("hello");
+
+    let borrowed = measure(|| borrow_string_to(&mut io::sink(), black_box(&s)).unwrap());
+    assert_eq!(borrowed, AllocStats::default());
+
+    let cloned = measure(|| takes_ownership_to(&mut io::sink(), black_box(s.clone())).unwrap());
+    assert_eq!((cloned.allocations, cloned.deallocations, cloned.bytes_allocated), (1, 1, 5));
+    assert_eq!(cloned.net_bytes(), 0);
+
+    let moved = measure(|| takes_ownership_to(&mut io::sink(), black_box(s)).unwrap());
+    assert_eq!((moved.allocations, moved.deallocations, moved.bytes_deallocated), (0, 1, 5));
+    assert_eq!(moved.net_bytes(), -5);
+}
+
+#[test]
+fn a_value_kept_from_the_scope_is_not_freed_in_it() {
+    let (numbers, stats) = measure_value(|| vec![1u64, 2, 3]);
+    assert_eq!(stats.allocations, 1);
+    assert_eq!(stats.deallocations, 0);
+    assert_eq!(stats.net_bytes(), 24);
+
+    assert_eq!(measure(|| drop(black_box(numbers))).deallocations, 1);
+}
+
+#[test]
+fn growing_a_vec_is_counted_as_reallocations() {
+    let stats = measure(|| {
+        let mut numbers: Vec<u8> = Vec::with_capacity(1);
+        for number in 0..100 {
+            numbers.push(number);
+        }
+        black_box(numbers);
+    });
+    assert_eq!(stats.allocations, 1);
+    assert!(stats.reallocations >= 1, "{:?}", stats);
+    assert_eq!(stats.deallocations, 1);
+    assert_eq!(stats.net_bytes(), 0);
+}
+
+#[test]
+fn scopes_nest_and_other_threads_are_not_counted() {
+    let mut inner = AllocStats::default();
+    let outer = measure(|| {
+        let _first = black_box(String::from("first"));
+        inner = measure(|| {
+            let _second = black_box(String::from("second"));
+        });
+        // Another thread allocates while this one is measured.
+        thread::scope(|scope| {
+            scope.spawn(|| black_box(vec![0u8; 1000])).join().unwrap();
+        });
+    });
+    assert_eq!((inner.allocations, inner.deallocations), (1, 1));
+    assert!(outer.bytes_allocated < 1000, "{:?}", outer);
+    assert!(outer.allocations >= 2, "{:?}", outer);
+}
+
+#[test]
+fn stats_are_written_in_words() {
+    assert_eq!(AllocStats::default().to_string(), "no allocations");
+    let stats = AllocStats {
+        allocations: 2,
+        deallocations: 1,
+        reallocations: 1,
+        bytes_allocated: 10,
+        bytes_deallocated: 5,
+    };
+    assert_eq!(stats.to_string(), "2 allocations (10 bytes), 1 reallocation, 1 deallocation (5 bytes)");
+}
+
+// The same tests, built with optimizations, where a forgotten "black_box()" would lose allocations.
+// It uses its own target folder, since the "cargo test" that runs this test keeps the usual one locked.
+#[test]
+#[cfg(debug_assertions)]
+fn the_counts_are_the_same_in_release_builds() {
+    let target_dir = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("allocations-release");
+    let output = Command::new(env!("CARGO"))
+        .args(["test", "--release", "-p", "ownership", "--features", "count_allocations", "--test", "allocations"])
+        .env("CARGO_TARGET_DIR", &target_dir)
+        .output()
+        .expect("failed to run cargo");
+    assert!(
+        output.status.success(),
+        "the tests fail in release mode:\n{}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
@@ -0,0 +1,149 @@
+// The tests of "src/vi_borrow_simulator.rs".
+
+use ownership::vi_borrow_simulator::{Simulator, is_complete, simulate};
+
+// The code of the error, and the text of every label in the order of the source.
+fn error_of(code: &str) -> (Option<&'static str>, String, Vec<String>) {
+    let error = simulate(code).error.expect("the code has an error");
+    let mut labels = error.labels.clone();
+    labels.sort_by_key(|label| label.span.start);
+    let labels = labels.iter().map(|label| format!("{}: {}", &code[label.span.start..label.span.end], label.text)).collect();
+    (error.code, error.message, labels)
+}
+
+#[test]
+fn moving_a_string_makes_the_old_variable_unusable() {
+    let code = "let s1 = String::from(\"Khalili\");\nlet s2 = s1;\nprintln!(\"Hello, {}\", s1);";
+    let (code_, message, labels) = error_of(code);
+    assert_eq!(code_, Some("E0382"));
+    assert_eq!(message, "borrow of moved value: `s1`");
+    assert_eq!(labels, ["s1: value moved here", "s1: value borrowed here after move"]);
+
+    // Integers are copied, so both variables can be used.
+    let outcome = simulate("let x = 5;\nlet y = x;\nprintln!(\"{} {}\", x, y);");
+    assert_eq!(outcome.error, None);
+    assert_eq!(outcome.steps[2].state, ["x: i32", "y: i32"]);
+
+    // A function takes its argument by value.
+    let (code_, message, _) = error_of("let s = String::from(\"a\");\ntakes_ownership(s);\nlet t = s;");
+    assert_eq!((code_, message.as_str()), (Some("E0382"), "use of moved value: `s`"));
+}
+
+#[test]
+fn a_second_mutable_borrow_fails_only_if_the_first_is_used_again() {
+    let (code, message, labels) = error_of("let mut x = 5;\nlet r1 = &mut x;\nlet r2 = &mut x;\n*r1 += 1;");
+    assert_eq!(code, Some("E0499"));
+    assert_eq!(message, "cannot borrow `x` as mutable more than once at a time");
+    assert_eq!(
+        labels,
+        [
+            "&mut x: first mutable borrow occurs here",
+            "&mut x: second mutable borrow occurs here",
+            "r1: first borrow later used here"
+        ]
+    );
+
+    let outcome = simulate("let mut x = 5;\nlet r1 = &mut x;\n*r1 += 1;\nlet r2 = &mut x;\n*r2 += 1;");
+    assert_eq!(outcome.error, None);
+    assert_eq!(outcome.steps.len(), 5);
+}
+
+#[test]
+fn reading_while_writing_is_an_error() {
+    let (code, message, labels) = error_of("let mut s = String::from(\"hello\");\nlet r1 = &s;\nlet r2 = &mut s;\nprintln!(\"{} {}\", r1, r2);");
+    assert_eq!(code, Some("E0502"));
+    assert_eq!(message, "cannot borrow `s` as mutable because it is also borrowed as immutable");
+    assert_eq!(
+        labels,
+        ["&s: immutable borrow occurs here", "&mut s: mutable borrow occurs here", "r1: immutable borrow later used here"]
+    );
+
+    // Reading the owner while a "&mut" is still used is the same error the other way around.
+    let (_, message, _) = error_of("let mut s = String::from(\"hello\");\nlet r = &mut s;\nprintln!(\"{}\", s);\nr.push_str(\"!\");");
+    assert_eq!(message, "cannot borrow `s` as immutable because it is also borrowed as mutable");
+
+    // Two shared borrows are fine.
+    assert_eq!(simulate("let s = String::from(\"a\");\nlet r1 = &s;\nlet r2 = &s;\nprintln!(\"{} {}\", r1, r2);").error, None);
+}
+
+#[test]
+fn moving_assigning_and_dropping_a_borrowed_value_are_errors() {
+    let (code, message, _) = error_of("let s = String::from(\"a\");\nlet r = &s;\nlet t = s;\nprintln!(\"{}\", r);");
+    assert_eq!((code, message.as_str()), (Some("E0505"), "cannot move out of `s` because it is borrowed"));
+
+    let (code, message, labels) = error_of("let mut x = 1;\nlet r = &x;\nx = 2;\nprintln!(\"{}\", r);");
+    assert_eq!((code, message.as_str()), (Some("E0506"), "cannot assign to `x` because it is borrowed"));
+    assert_eq!(labels[1], "x = 2: `x` is assigned to here but it was already borrowed");
+
+    let (code, message, labels) = error_of("let r;\n{\n    let x = 5;\n    r = &x;\n}\nprintln!(\"{}\", r);");
+    assert_eq!((code, message.as_str()), (Some("E0597"), "`x` does not live long enough"));
+    assert_eq!(labels[1], "}: `x` dropped here while still borrowed");
+}
+
+#[test]
+fn mutability_and_names_are_checked() {
+    assert_eq!(error_of("let x = 1;\nlet r = &mut x;").0, Some("E0596"));
+    assert_eq!(error_of("let s = String::new();\ns.push_str(\"a\");").0, Some("E0596"));
+    assert_eq!(error_of("let x = 1;\nx = 2;").0, Some("E0384"));
+    assert_eq!(error_of("let mut x = 1;\nlet r = &x;\n*r = 2;").0, Some("E0594"));
+    assert_eq!(error_of("let r;\nprintln!(\"{}\", r);").0, Some("E0381"));
+    assert_eq!(error_of("println!(\"{}\", nothing);").0, Some("E0425"));
+    assert_eq!(error_of("let s = String::new();\nlet r = &s;\nlet t = *r;").0, Some("E0507"));
+
+    // "let x;" can be given its first value later, even without "mut".
+    assert_eq!(simulate("let x;\nx = 1;\nprintln!(\"{}\", x);").error, None);
+}
+
+#[test]
+fn the_state_is_shown_after_every_statement() {
+    let outcome = simulate("let mut s = String::from(\"a\");\n{\n    let r = &mut s;\n    r.push_str(\"b\");\n}\nlet t = s;");
+    let steps: Vec<(&str, String)> = outcome.steps.iter().map(|step| (step.code.as_str(), step.state.join(", "))).collect();
+    assert_eq!(
+        steps,
+        [
+            ("let mut s = String::from(\"a\");", "mut s: String".to_string()),
+            ("let r = &mut s;", "mut s: String (borrowed by r), r: &mut String (borrows s)".to_string()),
+            ("r.push_str(\"b\");", "mut s: String (borrowed by r), r: &mut String (borrows s)".to_string()),
+            ("}", "mut s: String".to_string()),
+            ("let t = s;", "mut s: String (moved), t: String".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn errors_are_drawn_like_rustc() {
+    let code = "let s1 = String::from(\"Khalili\");\nlet s2 = s1;\nprintln!(\"Hello, {}\", s1);";
+    let rendered = simulate(code).error.unwrap().render(code);
+    let expected = "\
+error[E0382]: borrow of moved value: `s1`
+ --> 3:23
+  |
+2 | let s2 = s1;
+  |          -- value moved here
+3 | println!(\"Hello, {}\", s1);
+  |                       ^^ value borrowed here after move
+  |
+  = note: move occurs because `s1` has type `String`, which does not implement the `Copy` trait
+";
+    assert_eq!(rendered, expected);
+
+    let syntax = simulate("let x = ;").error.unwrap();
+    assert_eq!((syntax.code, syntax.message.as_str()), (None, "expected a variable name, found `;`"));
+}
+
+#[test]
+fn a_simulator_keeps_its_variables_and_forgets_the_statements_that_fail() {
+    let mut simulator = Simulator::new();
+    assert_eq!(simulator.run("let s = String::from(\"a\");").error, None);
+    assert_eq!(simulator.run("let t = s;\nlet u = s;").error.map(|error| error.code), Some(Some("E0382")));
+    // "let t = s;" ran, but "let u = s;" was undone.
+    let outcome = simulator.run("let n = 1;");
+    assert_eq!(outcome.steps[0].state, ["s: String (moved)", "t: String", "n: i32"]);
+    assert!(simulator.source().ends_with("let u = s;\nlet n = 1;"));
+
+    assert!(is_complete("let x = 1;"));
+    assert!(is_complete("{\n    let x = 1;\n}"));
+    assert!(!is_complete("{\n    let x = 1;"));
+    assert!(!is_complete("let x = 1"));
+    assert!(!is_complete("println!(\"a;"));
+}
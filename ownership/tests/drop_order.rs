@@ -0,0 +1,89 @@
+// The tests of "src/viii_drop_order.rs".
+
+use ownership::viii_drop_order::{self, EventKind, Timeline};
+
+fn drops_of(demo: fn(&Timeline)) -> Vec<String> {
+    let timeline = Timeline::new();
+    demo(&timeline);
+    timeline.drops()
+}
+
+#[test]
+fn a_block_drops_its_variables_at_its_end() {
+    let timeline = Timeline::new();
+    viii_drop_order::code_block(&timeline);
+    let lines: Vec<String> = timeline.events().iter().map(ToString::to_string).collect();
+    assert_eq!(
+        lines,
+        [
+            "{ code_block",
+            "    create greeting",
+            "    { block",
+            "        create name",
+            "        drop name",
+            "    }",
+            "    drop greeting",
+            "}"
+        ]
+    );
+}
+
+#[test]
+fn shadowing_keeps_the_old_value_and_assignment_drops_it() {
+    assert_eq!(
+        drops_of(viii_drop_order::shadowing),
+        [
+            "_y = \"This is a string literal\"",
+            "_y = \"Hello\"",
+            "_name = \"Khalili\"",
+            "_name = \"Khan\""
+        ]
+    );
+}
+
+#[test]
+fn a_moved_value_is_dropped_once_by_its_last_owner() {
+    let timeline = Timeline::new();
+    viii_drop_order::moves(&timeline);
+    assert_eq!(timeline.drops(), ["s in takes_ownership"]);
+    let drop = timeline.events().into_iter().find(|event| matches!(event.kind, EventKind::Drop(_))).unwrap();
+    assert_eq!(drop.depth, 2, "the drop happens inside takes_ownership");
+}
+
+#[test]
+fn fields_and_elements_are_dropped_after_their_owner_in_order() {
+    assert_eq!(
+        drops_of(viii_drop_order::struct_fields),
+        ["person", "person.first_name", "person.last_name"]
+    );
+    assert_eq!(
+        drops_of(viii_drop_order::vec_elements),
+        ["names[b]", "names", "names[a]", "names[c]", "names[d]"]
+    );
+}
+
+#[test]
+fn temporaries_are_dropped_at_the_end_of_the_statement() {
+    assert_eq!(
+        drops_of(viii_drop_order::temporaries),
+        ["\"Khalili\".to_string()", "\"Khannn\".to_string()", "y", "x"]
+    );
+}
+
+#[test]
+fn a_traced_value_can_be_used_and_taken_back() {
+    let timeline = Timeline::new();
+    let mut numbers = timeline.traced("numbers", vec![1, 2]);
+    numbers.push(3);
+    assert_eq!(numbers.len(), 3);
+    assert_eq!(numbers.name(), "numbers");
+
+    // The value now belongs to the caller, so no drop is written.
+    let numbers = numbers.into_inner();
+    assert_eq!(numbers, [1, 2, 3]);
+    assert!(timeline.drops().is_empty());
+
+    let value = timeline.scope("outer", || timeline.scope("inner", || 5));
+    assert_eq!(value, 5);
+    assert_eq!(timeline.to_string(), "create numbers\n{ outer\n    { inner\n    }\n}\n");
+}
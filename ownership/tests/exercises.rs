@@ -0,0 +1,22 @@
+// The tests of the exercises in "src/exercises.rs" (see "Exercises" in the README).
+
+mod make_loud {
+    use ownership::exercises::make_loud;
+
+    #[test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    fn changes_the_borrowed_string() {
+        let mut value = String::from("hello");
+        make_loud(&mut value);
+        assert_eq!(value, "HELLO!");
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    fn can_be_called_more_than_once() {
+        let mut value = String::from("hey");
+        make_loud(&mut value);
+        make_loud(&mut value);
+        assert_eq!(value, "HEY!!");
+    }
+}
@@ -0,0 +1,113 @@
+// The tests of "src/v_memory_layout.rs".
+// The regions are read from "/proc/self/maps", so the tests that check them only run on Linux.
+
+use ownership::v_memory_layout::{Addresses, Contents, Region, inspect};
+
+#[test]
+fn sizes_and_alignments_match_the_types() {
+    let number = 10u8;
+    let layout = inspect("number", &number);
+    assert_eq!((layout.size, layout.align), (1, 1));
+    assert_eq!(layout.type_name, "u8");
+    assert_eq!(layout.contents, Contents::Value("10".to_string()));
+
+    let text = String::from("hello");
+    let layout = inspect("text", &text);
+    assert_eq!(layout.size, 3 * size_of::<usize>());
+    assert_eq!(layout.type_name, "String");
+
+    let slice: &str = "hello";
+    let layout = inspect("slice", &slice);
+    assert_eq!(layout.size, 2 * size_of::<usize>());
+    assert_eq!(layout.type_name, "&str");
+
+    let numbers: Vec<String> = Vec::new();
+    assert_eq!(inspect("numbers", &numbers).type_name, "Vec<String>");
+}
+
+#[test]
+fn pointers_length_and_capacity_are_read_from_the_value() {
+    let mut text = String::with_capacity(10);
+    text.push_str("héllo");
+    let Contents::Pointer {
+        address,
+        len,
+        capacity,
+        items,
+    } = inspect("text", &text).contents
+    else {
+        panic!("a String points to its bytes");
+    };
+    assert_eq!(address, text.as_ptr() as usize);
+    assert_eq!((len, capacity), (6, Some(10)));
+    assert_eq!(items, ["'h'", "0xc3", "0xa9", "'l'", "'l'", "'o'"]);
+
+    // A slice of the string points into the same bytes, and owns no capacity.
+    let end: &str = &text[3..];
+    let Contents::Pointer { address, capacity, .. } = inspect("end", &end).contents else {
+        panic!("a &str points to its bytes");
+    };
+    assert_eq!(address, text.as_ptr() as usize + 3);
+    assert_eq!(capacity, None);
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn values_are_found_in_their_regions() {
+    let number = 10;
+    assert_eq!(inspect("number", &number).region, Region::Stack);
+
+    let owned = String::from("Khalili");
+    let layout = inspect("owned", &owned);
+    assert_eq!(layout.region, Region::Stack);
+    assert_eq!(layout.target, Some(Region::Heap));
+
+    let literal: &'static str = "Khalili";
+    assert_eq!(inspect("literal", &literal).target, Some(Region::ReadOnlyData));
+
+    // A "String" inside a "Box" is itself on the heap.
+    let boxed = Box::new(String::from("boxed"));
+    assert_eq!(inspect("boxed", &*boxed).region, Region::Heap);
+
+    static GREETING: &str = "hello";
+    assert_eq!(inspect("GREETING", &GREETING).region, Region::ReadOnlyData);
+}
+
+// The sizes in the diagram are those of a 64-bit target.
+#[test]
+#[cfg(all(target_os = "linux", target_pointer_width = "64"))]
+fn diagrams_point_from_the_value_to_its_elements() {
+    let text = String::from("hi");
+    let diagram = inspect("text", &text).diagram(Addresses::Hidden);
+    let expected = [
+        "text: String (24 bytes, aligned to 8) on the stack      the heap",
+        "+----------+-----------+                                +-------+-------+",
+        "| name     | value     |                                | index | value |",
+        "+----------+-----------+                                +-------+-------+",
+        "| ptr      | (address) |------------------------------> | 0     | 'h'   |",
+        "| len      | 2         |                                | 1     | 'i'   |",
+        "| capacity | 2         |                                +-------+-------+",
+        "+----------+-----------+",
+    ];
+    assert_eq!(diagram.lines().collect::<Vec<_>>(), expected);
+
+    // With the addresses shown, the pointer is the address of the elements.
+    let shown = inspect("text", &text).diagram(Addresses::Shown);
+    assert!(shown.contains(&format!("the heap at {:#x}", text.as_ptr() as usize)), "{}", shown);
+    assert!(shown.contains(&format!("| ptr      | {:#x} |---", text.as_ptr() as usize)), "{}", shown);
+}
+
+#[test]
+fn long_values_only_draw_their_first_elements() {
+    let numbers: Vec<u32> = (0..20).collect();
+    let diagram = inspect("numbers", &numbers).diagram(Addresses::Hidden);
+    assert!(diagram.contains("| 7     | 7       |"), "{}", diagram);
+    assert!(!diagram.contains("| 8     |"), "{}", diagram);
+    assert!(diagram.contains("| ...   | 12 more |"), "{}", diagram);
+
+    // Nothing is allocated for an empty "Vec", so there is nothing to point to.
+    let empty: Vec<u32> = Vec::new();
+    let diagram = inspect("empty", &empty).diagram(Addresses::Hidden);
+    assert!(diagram.contains("| ptr      | (nothing) |"), "{}", diagram);
+    assert!(!diagram.contains("index"), "{}", diagram);
+}
@@ -0,0 +1,40 @@
+// The reference solutions of "src/exercises.rs" (see "Exercises" in the README).
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+pub struct SharedCounter {
+    count: Rc<Cell<u32>>,
+}
+
+impl SharedCounter {
+    pub fn new() -> Self {
+        Self {
+            count: Rc::new(Cell::new(0)),
+        }
+    }
+
+    pub fn share(&self) -> SharedCounter {
+        SharedCounter {
+            count: Rc::clone(&self.count),
+        }
+    }
+
+    pub fn increment(&self) {
+        self.count.set(self.count.get() + 1);
+    }
+
+    pub fn get(&self) -> u32 {
+        self.count.get()
+    }
+
+    pub fn handles(&self) -> usize {
+        Rc::strong_count(&self.count)
+    }
+}
+
+impl Default for SharedCounter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,6 +1,8 @@
 // This file is here to show how the box is implemented internally.
 // It also shows how custom boxes can be implemented.
 
+use std::io::{self, Write};
+
 // This creates a new struct that can take any value "T" and then puts it in "value".
 struct RegularStruct<T> {
     value: T,
@@ -12,12 +14,18 @@ impl<T> RegularStruct<T> {
     }
 }
 
-pub fn age_in_reg_struct() {
+pub fn age_in_reg_struct_to(out: &mut dyn Write) -> io::Result<()> {
     let age: RegularStruct<u8> = RegularStruct::new(21);
 
     // The value can be accessed totally fine.
     // But this is done without "deref()", which actually dereferences a pointer.
-    println!("Value is: {}" , age.value);
+    writeln!(out, "Value is: {}" , age.value)?;
+
+    Ok(())
+}
+
+pub fn age_in_reg_struct() {
+    age_in_reg_struct_to(&mut io::stdout()).expect("failed to write to stdout");
 }
 
 
@@ -44,11 +52,11 @@ impl <T> BoxedValue<T> {
     }
 }
 
-pub fn age_in_boxed_value() {
+pub fn age_in_boxed_value_to(out: &mut dyn Write) -> io::Result<()> {
     let age_box: BoxedValue<u8> = BoxedValue::new(21);
 
     // The value can be accessed through dereferencing.
-    println!("Value is: {}" , *age_box);
+    writeln!(out, "Value is: {}" , *age_box)?;
 
     // Try to call the ".deref()" function.
     // This will return a pointer to the "value" field in "BoxedValue" because the ".deref()" returns "&Self::Target", not the actual value.
@@ -56,7 +64,13 @@ pub fn age_in_boxed_value() {
 
     // Since "age_box.deref()" returns a reference, this entire expression can be dereferenced using an "*".
     // Basically "*age_box" is the short hand for writing "*(age_box.deref())"
-    println!("{} is the same as {}. The left value is the short hand for the right value." , *age_box , *(age_box.deref()))
+    writeln!(out, "{} is the same as {}. The left value is the short hand for the right value." , *age_box , *(age_box.deref()))?;
+
+    Ok(())
+}
+
+pub fn age_in_boxed_value() {
+    age_in_boxed_value_to(&mut io::stdout()).expect("failed to write to stdout");
 }
 
 // Rust is smart enough to dereference values when needed automatially.
@@ -64,15 +78,25 @@ pub fn age_in_boxed_value() {
 // It is called Implicit Deref Coercion
 // One of these cases is in functions.
 // Try to print a reference in the function.
+pub fn print_to(out: &mut dyn Write, reference: &u8) -> io::Result<()> {
+    writeln!(out, "{}" , reference)
+}
+
 pub fn print(reference: &u8) {
-    println!("{}" , reference);
+    print_to(&mut io::stdout(), reference).expect("failed to write to stdout");
 }
 
 // This can also work with "BoxedValue".
-pub fn implicit_deref() {
+pub fn implicit_deref_to(out: &mut dyn Write) -> io::Result<()> {
     let boxed = BoxedValue::new(22);
     
-    // Now by calling "print(&boxed)", rust implicitly calls the ".deref()" function and then gets a reference to the dereferenced value.
+    // Now by calling "print_to(out, &boxed)", rust implicitly calls the ".deref()" function and then gets a reference to the dereferenced value.
     // Rust does this to automate the process instead of having to dereference manually everytime.
-    print(&boxed);
+    print_to(out, &boxed)?;
+
+    Ok(())
+}
+
+pub fn implicit_deref() {
+    implicit_deref_to(&mut io::stdout()).expect("failed to write to stdout");
 }
\ No newline at end of file
@@ -1,6 +1,8 @@
+use std::io::{self, Write};
+
 // A "Box" is a datatype that stores values and data in the Heap.
 // Take the following example.
-pub fn boxx() {
+pub fn boxx_to(out: &mut dyn Write) -> io::Result<()> {
     // Ints are always initialized in the stack.
     let _a: u8 = 20;
 
@@ -10,5 +12,11 @@ pub fn boxx() {
 
     // To dereference a pointer, it's just like C++.
     let mult_by_2 = *x * 2;
-    println!("x times 2 = {}" , mult_by_2)
+    writeln!(out, "x times 2 = {}" , mult_by_2)?;
+
+    Ok(())
+}
+
+pub fn boxx() {
+    boxx_to(&mut io::stdout()).expect("failed to write to stdout");
 }
\ No newline at end of file
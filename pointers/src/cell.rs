@@ -3,12 +3,21 @@
 // "Cell" is intended for objects and datatypes that have the "copy" trait.
 // Because "Cell" is internally mutable, it is generally considered unsafe in rust as it can introduce the possibility of memory unsafety if misused.
 
+use std::io::{self, Write};
+
 // To use "Cell", it needs to be imported.
 use std::cell::Cell;
 
+use bounded::{Bounded, Saturate};
+
+// With a plain "u8", "increment_age()" would silently wrap from 255 to 0 in release builds.
+// "Saturate" stops the age at 150 instead, however many birthdays there are (see the "bounded" crate).
+// A "Bounded" is "Copy", so it can still be kept in a "Cell".
+type Age = Bounded<u8, 0, 150, Saturate>;
+
 struct Person {
     name: String,
-    age: Cell<u8>
+    age: Cell<Age>
 } 
 
 impl Person {
@@ -19,15 +28,21 @@ impl Person {
     }
 }
 
-pub fn cell_testing() {
+pub fn cell_testing_to(out: &mut dyn Write) -> io::Result<()> {
     let p1 = Person{
         name: "Khan".to_string(),
-        age: Cell::new(20)
+        age: Cell::new(Age::new(20))
     };
 
-    println!("{}" , p1.age.get());
+    writeln!(out, "{}" , p1.age.get())?;
     p1.increment_age();
-    println!("{}" , p1.age.get());
+    writeln!(out, "{}" , p1.age.get())?;
+
+    Ok(())
+}
+
+pub fn cell_testing() {
+    cell_testing_to(&mut io::stdout()).expect("failed to write to stdout");
 }
 
 
@@ -0,0 +1,43 @@
+// The exercises of "pointers", checked with "cargo run -p main -- check pointers::shared_counter" (see "Exercises" in the README).
+
+#![allow(unused_variables, dead_code)]
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+// A counter that can be shared: every handle returned by "share()" counts into the same number.
+// Use an "Rc" so that all the handles own the count together, and a "Cell" so that the count can change behind a shared reference.
+pub struct SharedCounter {
+    count: Rc<Cell<u32>>,
+}
+
+impl SharedCounter {
+    // A new counter starts at 0.
+    pub fn new() -> Self {
+        todo!()
+    }
+
+    // Returns another handle to the same count.
+    pub fn share(&self) -> SharedCounter {
+        todo!()
+    }
+
+    pub fn increment(&self) {
+        todo!()
+    }
+
+    pub fn get(&self) -> u32 {
+        todo!()
+    }
+
+    // Returns how many handles to the count exist, including this one.
+    pub fn handles(&self) -> usize {
+        todo!()
+    }
+}
+
+impl Default for SharedCounter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,4 +1,8 @@
 pub mod box_ptr;
 pub mod box_implementation;
 pub mod rc;
-pub mod cell;
\ No newline at end of file
+pub mod cell;
+
+// The exercises for this lesson (see "Exercises" in the README).
+#[cfg_attr(feature = "solution", path = "../solutions/exercises.rs")]
+pub mod exercises;
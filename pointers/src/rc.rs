@@ -9,10 +9,12 @@
 // "Rc" can have weak references assigned to it, but this is considered unsafe.
 // It is unsafe because weak references will be invalid once the original object is destroyed, and calling the weak references will cause the app to crash.
 
+use std::io::{self, Write};
+
 // "Rc" needs to be imported first.
 use std::rc::Rc;
 
-fn _rc_pointers() {
+pub fn rc_pointers_to(out: &mut dyn Write) -> io::Result<()> {
     // To create an "Rc" reference, create the object first, and then wrap it with "Rc" with "Rc::new()".
     let name1 = "Khan".to_string();
 
@@ -21,6 +23,9 @@ fn _rc_pointers() {
     // To get a weak reference, the ".downgrade()" method is called on the "Rc" object that was created.
     let weak_reference1 = Rc::downgrade(&reference1);
 
+    // Note that uncommenting this block as it is gives a compilation error before anything runs.
+    // This is because "reference1" is moved into "drop()" and then used again further below.
+    // compile_fail: E0382
     /*
     // If the original reference is dropped, then the weak references will fail.
     drop(reference1);
@@ -33,8 +38,8 @@ fn _rc_pointers() {
 
     // since the ".upgrade()" method returns an optional, it can be used in "match" statements.
     match weak_reference1.upgrade() {
-        Some(rc) => println!("{:?}", rc),
-        _ => println!("None"),
+        Some(rc) => writeln!(out, "{:?}", rc)?,
+        _ => writeln!(out, "None")?,
     }
 
     // The cloning is as written in the introduction of this file.
@@ -46,8 +51,14 @@ fn _rc_pointers() {
     // Dropping "reference1" and printing the value in "reference3" here will completely work fine.
     // This works because both references are not tied to each other.
     drop(reference1);
-    println!("{:?}", reference3);
+    writeln!(out, "{:?}", reference3)?;
 
     drop(reference3);
     drop(reference4);
+
+    Ok(())
+}
+
+pub fn rc_pointers() {
+    rc_pointers_to(&mut io::stdout()).expect("failed to write to stdout");
 }
@@ -0,0 +1,41 @@
+// The tests of the exercises in "src/exercises.rs" (see "Exercises" in the README).
+
+mod shared_counter {
+    use pointers::exercises::SharedCounter;
+
+    #[test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    fn a_new_counter_starts_at_zero() {
+        let counter = SharedCounter::new();
+        assert_eq!(counter.get(), 0);
+        assert_eq!(counter.handles(), 1);
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    fn shared_handles_count_together() {
+        let counter = SharedCounter::new();
+        let other = counter.share();
+
+        counter.increment();
+        other.increment();
+        other.increment();
+
+        assert_eq!(counter.get(), 3);
+        assert_eq!(other.get(), 3);
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    fn dropping_a_handle_keeps_the_count() {
+        let counter = SharedCounter::new();
+        let other = counter.share();
+        assert_eq!(counter.handles(), 2);
+
+        other.increment();
+        drop(other);
+
+        assert_eq!(counter.handles(), 1);
+        assert_eq!(counter.get(), 1);
+    }
+}
@@ -0,0 +1,23 @@
+// The reference solutions of "src/exercises.rs" (see "Exercises" in the README).
+
+pub struct Rectangle {
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Rectangle {
+    pub fn square(size: f64) -> Self {
+        Self {
+            width: size,
+            height: size,
+        }
+    }
+
+    pub fn area(&self) -> f64 {
+        self.width * self.height
+    }
+
+    pub fn can_hold(&self, other: &Rectangle) -> bool {
+        self.width >= other.width && self.height >= other.height
+    }
+}
@@ -0,0 +1,25 @@
+// The exercises of "structs", checked with "cargo run -p main -- check structs::rectangle" (see "Exercises" in the README).
+
+#![allow(unused_variables)]
+
+pub struct Rectangle {
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Rectangle {
+    // Create a rectangle whose width and height are both "size".
+    pub fn square(size: f64) -> Self {
+        todo!()
+    }
+
+    // Return the area of the rectangle.
+    pub fn area(&self) -> f64 {
+        todo!()
+    }
+
+    // Return true if "other" fits inside this rectangle without turning it.
+    pub fn can_hold(&self, other: &Rectangle) -> bool {
+        todo!()
+    }
+}
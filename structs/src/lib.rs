@@ -1,2 +1,6 @@
 pub mod person;
-pub mod tuples;
\ No newline at end of file
+pub mod tuples;
+
+// The exercises for this lesson (see "Exercises" in the README).
+#[cfg_attr(feature = "solution", path = "../solutions/exercises.rs")]
+pub mod exercises;
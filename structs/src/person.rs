@@ -1,3 +1,11 @@
+use std::io::{self, Write};
+
+use bounded::Bounded;
+
+// An age is not any "u8": nobody is 200 years old.
+// "Bounded" only holds values from 0 to 150, and panics when a calculation goes past them, in debug and release builds alike (see the "bounded" crate).
+type Age = Bounded<u8, 0, 150>;
+
 // Structs are written similar to C++.
 // Rust used to have classes but now they are no longer available.
 // Structs are written in PascalCase.
@@ -5,28 +13,29 @@
 struct Person {
     name: String,
     gender: bool, // male is false , female is true (just for the sake of ease)
-    age: u8,
+    age: Age,
 }
 
-pub fn instantiate() {
+pub fn instantiate_to(out: &mut dyn Write) -> io::Result<()> {
     // Creating an instance of the structure is as follows.
     // Instantiating while writing the "name" and "age" keywords (at least in this specific case) means that the order does not matter.
     let _man = Person {
         name: "Khann".to_string(),
         gender: false,
-        age: 22,
+        age: Age::new(22),
     };
     // access the variables using the regular dot notation.
-    println!("{} is {} years old.", _man.name, _man.age);
+    writeln!(out, "{} is {} years old.", _man.name, _man.age)?;
 
     // if the keywords and values have the same name such as:
+    // snippet: only the fields of a struct literal, not a full statement
     /*
         name: name,
         age: age,
     */
     // then the following "Field Init Shorthand" can be used. This following is an inline function but works as a regular function as well:
-    let _woman = |name: String, gender: bool, age: u8| Person { name, gender, age };
-    _woman("E".to_string(), true, 20);
+    let _woman = |name: String, gender: bool, age: Age| Person { name, gender, age };
+    _woman("E".to_string(), true, Age::new(20));
 
     // Sometimes, a struct has 20 or 30 fields.
     // Say, for example, that 2 instances need to be created in which all 30 fields need to be initialized.
@@ -36,7 +45,7 @@ pub fn instantiate() {
     let instance_a = Person {
         name: "Khalili".to_string(),
         gender: false,
-        age: 22,
+        age: Age::new(22),
     };
 
     // Instance B will then have someone who is also a male and 20 years old but he is someone else other than "Khalili".
@@ -53,4 +62,10 @@ pub fn instantiate() {
         gender: instance_a.gender,
         age: instance_a.age,
     };
+
+    Ok(())
+}
+
+pub fn instantiate() {
+    instantiate_to(&mut io::stdout()).expect("failed to write to stdout");
 }
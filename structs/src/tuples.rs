@@ -1,3 +1,5 @@
+use std::io::{self, Write};
+
 // A tuple struct is created and instantiated with parenthesis.
 struct Point3D(f64 , f64 , f64);
 
@@ -8,8 +10,8 @@ struct Point3D(f64 , f64 , f64);
 impl Point3D {
     // The "&self" reference in the "describe" and "multiply_by_2" functions is immutable.
     // The values in the instance referenced by "&self" cannot be modified.
-    fn describe(&self) {
-        println!("Point is at ({} , {} , {})" , self.0 , self.1 , self.2);
+    fn describe(&self, out: &mut dyn Write) -> io::Result<()> {
+        writeln!(out, "Point is at ({} , {} , {})" , self.0 , self.1 , self.2)
     }
 
     // This function multiplies each value in the point by 2 and returns a new point
@@ -37,14 +39,14 @@ impl Point3D {
     }
 }
 
-pub fn instantiation_3d(){
+pub fn instantiation_3d_to(out: &mut dyn Write) -> io::Result<()> {
     let origin = Point3D(0.0 , 0.0 , 0.0);
 
     // The values of each element in the tuple are accessed through the dot notation as well.
-    println!("x = {} , y = {} , z = {}" , origin.0 , origin.1 , origin.2);
+    writeln!(out, "x = {} , y = {} , z = {}" , origin.0 , origin.1 , origin.2)?;
 
     // since "origin" is of type "Point3D", functions from the implementation can be used.
-    origin.describe();
+    origin.describe(out)?;
 
     // The following segment instantiates a point, multiplies the values in it by 2, and takes the point returned into a new point.
     let point_a = Point3D(5.0 , 10.0 , 15.0);
@@ -52,11 +54,16 @@ pub fn instantiation_3d(){
 
     // The call to this function modifies the values in point_c without putting the values in another point.
     let mut point_c = Point3D(5.0 , 10.0 , 15.0);
-    point_c.describe();
+    point_c.describe(out)?;
     point_c.multiply_by_2_mut();
-    point_c.describe();
+    point_c.describe(out)?;
 
     // The following call instantiates a point with all values set to 0
     let _point_d = Point3D::zero();
 
+    Ok(())
+}
+
+pub fn instantiation_3d() {
+    instantiation_3d_to(&mut io::stdout()).expect("failed to write to stdout");
 }
\ No newline at end of file
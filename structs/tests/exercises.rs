@@ -0,0 +1,40 @@
+// The tests of the exercises in "src/exercises.rs" (see "Exercises" in the README).
+
+mod rectangle {
+    use structs::exercises::Rectangle;
+
+    #[test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    fn a_square_has_equal_sides() {
+        let square = Rectangle::square(3.0);
+        assert_eq!(square.width, 3.0);
+        assert_eq!(square.height, 3.0);
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    fn area_is_width_times_height() {
+        let rect = Rectangle {
+            width: 2.0,
+            height: 4.5,
+        };
+        assert_eq!(rect.area(), 9.0);
+        assert_eq!(Rectangle::square(3.0).area(), 9.0);
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    fn can_hold_smaller_rectangles_only() {
+        let big = Rectangle {
+            width: 10.0,
+            height: 5.0,
+        };
+        let small = Rectangle {
+            width: 4.0,
+            height: 5.0,
+        };
+        assert!(big.can_hold(&small));
+        assert!(!small.can_hold(&big));
+        assert!(!big.can_hold(&Rectangle::square(6.0)));
+    }
+}
@@ -0,0 +1,27 @@
+// The reference solutions of "src/exercises.rs" (see "Exercises" in the README).
+
+use std::fmt;
+
+pub trait Talk {
+    fn speak(&self) -> String;
+
+    fn speak_twice(&self) -> String {
+        format!("{} {}", self.speak(), self.speak())
+    }
+}
+
+pub struct Cow {
+    pub name: String,
+}
+
+impl Talk for Cow {
+    fn speak(&self) -> String {
+        "Moo!".to_string()
+    }
+}
+
+impl fmt::Display for Cow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} the cow", self.name)
+    }
+}
@@ -0,0 +1,33 @@
+// The exercises of "traits", checked with "cargo run -p main -- check traits::cow" (see "Exercises" in the README).
+
+#![allow(unused_variables)]
+
+use std::fmt;
+
+// This is the "Talk" trait from the lesson, with a default implementation for "speak_twice()".
+pub trait Talk {
+    fn speak(&self) -> String;
+
+    fn speak_twice(&self) -> String {
+        format!("{} {}", self.speak(), self.speak())
+    }
+}
+
+pub struct Cow {
+    pub name: String,
+}
+
+// A cow says "Moo!".
+// "speak_twice()" does not need to be written, because the trait already implements it using "speak()".
+impl Talk for Cow {
+    fn speak(&self) -> String {
+        todo!()
+    }
+}
+
+// A cow named "Bessie" is printed as "Bessie the cow".
+impl fmt::Display for Cow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        todo!()
+    }
+}
@@ -1,2 +1,6 @@
 pub mod traits;
-pub mod multiple_traits;
\ No newline at end of file
+pub mod multiple_traits;
+
+// The exercises for this lesson (see "Exercises" in the README).
+#[cfg_attr(feature = "solution", path = "../solutions/exercises.rs")]
+pub mod exercises;
@@ -1,3 +1,4 @@
+use std::io::{self, Write};
 
 // Traits can also be passed as arguments.
 // Take for example the following 2 structs: "Cat" and "Dog".
@@ -86,6 +87,6 @@ fn make_run(animal: &dyn CanRun) -> String {
     animal.run()
 }
 
-fn print_behavior<T>(animal: T) where T: CanRun + Talk {
-    println!("{} {}" , make_speak(&animal) , make_run(&animal));
+fn print_behavior<T>(out: &mut dyn Write, animal: T) -> io::Result<()> where T: CanRun + Talk {
+    writeln!(out, "{} {}" , make_speak(&animal) , make_run(&animal))
 }
@@ -5,24 +5,29 @@
 // "#[derive(<trait_name>)]"
 // multiple traits can be put within the same "derive" function separated by a comma ",".
 
+use std::io::{self, Write};
+
 // Say that a person struct is created and instanciated and the instance needs to be printed as debug values.
 // If the struct is created without deriving the debug trait, Rust will complain, such as in the following code-block
+// compile_fail: E0277
 /*
-struct Person {
+struct PersonWithoutDebug {
     _first_name: String,
     _last_name: String,
     _age: u8,
 }
 
-// Try to create a "Person" and print its values as debug values without deriving the "Debug" Trait.
-pub fn print_person(){
-    let p1 = Person{
+// Try to create a "PersonWithoutDebug" and print its values as debug values without deriving the "Debug" Trait.
+pub fn print_person_without_debug_to(out: &mut dyn Write) -> io::Result<()> {
+    let p1 = PersonWithoutDebug{
         _first_name: "Khann".to_string(),
         _last_name: "Khalili".to_string(),
         _age: 30
     };
 
-    println!("{:?}" , p1);
+    writeln!(out, "{:?}" , p1)?;
+
+    Ok(())
 }
 */
 
@@ -35,15 +40,21 @@ struct Person {
 }
 
 // Try to create a "Person" and print its values as debug values after deriving the "Debug" Trait.
-pub fn print_person() {
+pub fn print_person_to(out: &mut dyn Write) -> io::Result<()> {
     let p1 = Person {
         _first_name: "Khann".to_string(),
         _last_name: "Khalili".to_string(),
         _age: 30,
     };
 
-    println!("{:?}", p1);
-    println!("{}", p1.full_name())
+    writeln!(out, "{:?}", p1)?;
+    writeln!(out, "{}", p1.full_name())?;
+
+    Ok(())
+}
+
+pub fn print_person() {
+    print_person_to(&mut io::stdout()).expect("failed to write to stdout");
 }
 
 // Rust also allows the user to create custom traits.
@@ -80,10 +91,16 @@ impl InitWithFullName for Person {
     }
 }
 
-pub fn new_person() {
+pub fn new_person_to(out: &mut dyn Write) -> io::Result<()> {
     let p1 = Person::new_instance("Khannn Khalili");
 
-    println!("{:?}", p1);
+    writeln!(out, "{:?}", p1)?;
+
+    Ok(())
+}
+
+pub fn new_person() {
+    new_person_to(&mut io::stdout()).expect("failed to write to stdout");
 }
 
 // The "fmt::Display" trait defines how a type should be formatted for human readability.
@@ -103,14 +120,20 @@ impl fmt::Display for Person {
     }
 }
 
-pub fn print_person_with_display() {
+pub fn print_person_with_display_to(out: &mut dyn Write) -> io::Result<()> {
     let p1 = Person {
         _first_name: "Khan".to_string(),
         _last_name: "Khalili".to_string(),
         _age: 20,
     };
 
-    println!("{}", p1);
+    writeln!(out, "{}", p1)?;
+
+    Ok(())
+}
+
+pub fn print_person_with_display() {
+    print_person_with_display_to(&mut io::stdout()).expect("failed to write to stdout");
 }
 
 // Traits can implement another traits using the "where" Keyword.
@@ -0,0 +1,32 @@
+// The tests of the exercises in "src/exercises.rs" (see "Exercises" in the README).
+
+mod cow {
+    use traits::exercises::{Cow, Talk};
+
+    fn bessie() -> Cow {
+        Cow {
+            name: "Bessie".to_string(),
+        }
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    fn a_cow_says_moo() {
+        assert_eq!(bessie().speak(), "Moo!");
+        assert_eq!(bessie().speak_twice(), "Moo! Moo!");
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    fn a_cow_talks_through_a_trait_object() {
+        let animal: &dyn Talk = &bessie();
+        assert_eq!(animal.speak(), "Moo!");
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    fn a_cow_is_displayed_with_its_name() {
+        assert_eq!(bessie().to_string(), "Bessie the cow");
+        assert_eq!(format!("[{}]", bessie()), "[Bessie the cow]");
+    }
+}
@@ -0,0 +1,9 @@
+// The reference solutions of "src/exercises.rs" (see "Exercises" in the README).
+
+pub fn sum_up_to(n: u32) -> u32 {
+    let mut total = 0;
+    for number in 1..=n {
+        total += number;
+    }
+    total
+}
@@ -0,0 +1,10 @@
+// The exercises of "variables", checked with "cargo run -p main -- check variables::sum_up_to" (see "Exercises" in the README).
+
+#![allow(unused_variables)]
+
+// Add up every number from 1 to "n" (including "n") and return the sum.
+// Use a mutable variable to hold the running total, and a loop to add to it.
+// "sum_up_to(4)" should return 1 + 2 + 3 + 4 = 10, and "sum_up_to(0)" should return 0.
+pub fn sum_up_to(n: u32) -> u32 {
+    todo!()
+}
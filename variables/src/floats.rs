@@ -0,0 +1,349 @@
+// "vars()" declares "20.25" as an "f32", and lets "20000.21445" default to an "f64".
+// A float does not hold the decimal number that was written, but the closest number it can hold in binary, which is why the choice between them matters.
+// An "f32" has 32 bits, and an "f64" has 64 bits, split into three parts (IEEE 754):
+//      sign        1 bit, 0 for positive and 1 for negative.
+//      exponent    8 bits (f32) or 11 bits (f64), the power of 2 that the number is scaled by.
+//      mantissa    23 bits (f32) or 52 bits (f64), the binary digits after the leading "1.".
+// So 20.25 is stored as +1.0100010 (binary) x 2^4, which is exact, but 0.1 is not: its binary digits repeat forever, and are cut off.
+// This module takes floats apart, writes out the exact value that is stored, and measures how far apart two floats are.
+
+use std::fmt;
+use std::io::{self, Write};
+use std::num::ParseFloatError;
+use std::ops::{Add, Sub};
+
+// "f32" and "f64", so that every function works with both.
+// The bits are always passed around as a "u64", even for an "f32".
+pub trait Float: Copy + PartialEq + PartialOrd + fmt::Debug + Add<Output = Self> + Sub<Output = Self> {
+    const EXPONENT_BITS: u32;
+    const MANTISSA_BITS: u32;
+    const ZERO: Self;
+
+    fn bits(self) -> u64;
+    fn with_bits(bits: u64) -> Self;
+}
+
+impl Float for f32 {
+    const EXPONENT_BITS: u32 = 8;
+    const MANTISSA_BITS: u32 = 23;
+    const ZERO: f32 = 0.0;
+
+    fn bits(self) -> u64 {
+        self.to_bits() as u64
+    }
+
+    fn with_bits(bits: u64) -> f32 {
+        f32::from_bits(bits as u32)
+    }
+}
+
+impl Float for f64 {
+    const EXPONENT_BITS: u32 = 11;
+    const MANTISSA_BITS: u32 = 52;
+    const ZERO: f64 = 0.0;
+
+    fn bits(self) -> u64 {
+        self.to_bits()
+    }
+
+    fn with_bits(bits: u64) -> f64 {
+        f64::from_bits(bits)
+    }
+}
+
+fn sign_bit<F: Float>() -> u64 {
+    1 << (F::EXPONENT_BITS + F::MANTISSA_BITS)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Kind {
+    Zero,
+    // So close to zero that the leading "1." is gone, and the number is "0.mantissa x 2^(1 - bias)".
+    Subnormal,
+    Normal,
+    Infinite,
+    NaN,
+}
+
+// The three parts of a float, as they are stored.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Parts {
+    pub negative: bool,
+    // The stored exponent, which is the power of 2 plus the bias (127 for "f32", 1023 for "f64").
+    pub exponent: u64,
+    pub mantissa: u64,
+    pub kind: Kind,
+    exponent_bits: u32,
+    mantissa_bits: u32,
+}
+
+impl Parts {
+    // The power of 2 that the number is scaled by.
+    pub fn power(&self) -> i32 {
+        let bias = (1 << (self.exponent_bits - 1)) - 1;
+        match self.kind {
+            Kind::Subnormal => 1 - bias,
+            _ => self.exponent as i32 - bias,
+        }
+    }
+
+    // The mantissa with its leading "1" (which is not stored, since it is always there for a normal number).
+    pub fn significand(&self) -> u64 {
+        match self.kind {
+            Kind::Normal => self.mantissa | (1 << self.mantissa_bits),
+            _ => self.mantissa,
+        }
+    }
+}
+
+// Written as the bits of each part, such as "0 10000011 01000100000000000000000 (+1.01000100000000000000000 x 2^4)".
+impl fmt::Display for Parts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let exponent = format!("{:0width$b}", self.exponent, width = self.exponent_bits as usize);
+        let mantissa = format!("{:0width$b}", self.mantissa, width = self.mantissa_bits as usize);
+        write!(f, "{} {} {}", self.negative as u8, exponent, mantissa)?;
+        let sign = if self.negative { "-" } else { "+" };
+        match self.kind {
+            Kind::Normal => write!(f, " ({}1.{} x 2^{})", sign, mantissa, self.power()),
+            Kind::Subnormal => write!(f, " ({}0.{} x 2^{})", sign, mantissa, self.power()),
+            Kind::Zero => write!(f, " ({}0)", sign),
+            Kind::Infinite => write!(f, " ({}infinity)", sign),
+            Kind::NaN => write!(f, " (NaN)"),
+        }
+    }
+}
+
+pub fn decompose<F: Float>(value: F) -> Parts {
+    let bits = value.bits();
+    let mantissa = bits & ((1 << F::MANTISSA_BITS) - 1);
+    let exponent = (bits >> F::MANTISSA_BITS) & ((1 << F::EXPONENT_BITS) - 1);
+    let all_ones = (1 << F::EXPONENT_BITS) - 1;
+    let kind = match (exponent, mantissa) {
+        (0, 0) => Kind::Zero,
+        (0, _) => Kind::Subnormal,
+        (e, 0) if e == all_ones => Kind::Infinite,
+        (e, _) if e == all_ones => Kind::NaN,
+        _ => Kind::Normal,
+    };
+    Parts {
+        negative: bits & sign_bit::<F>() != 0,
+        exponent,
+        mantissa,
+        kind,
+        exponent_bits: F::EXPONENT_BITS,
+        mantissa_bits: F::MANTISSA_BITS,
+    }
+}
+
+// Puts the parts back together. Only the sign, the exponent and the mantissa are used.
+pub fn compose<F: Float>(parts: &Parts) -> F {
+    let sign = if parts.negative { sign_bit::<F>() } else { 0 };
+    F::with_bits(sign | (parts.exponent << F::MANTISSA_BITS) | parts.mantissa)
+}
+
+// A non-negative integer of any size, stored as digits in base 1_000_000_000 with the lowest first.
+// It is only as big as "exact_decimal" needs: an "f64" can need more than 700 digits.
+struct Decimal(Vec<u32>);
+
+impl Decimal {
+    const BASE: u64 = 1_000_000_000;
+
+    fn new(value: u64) -> Decimal {
+        let mut decimal = Decimal(vec![]);
+        let mut value = value;
+        while value > 0 {
+            decimal.0.push((value % Self::BASE) as u32);
+            value /= Self::BASE;
+        }
+        decimal
+    }
+
+    fn multiply(&mut self, factor: u32) {
+        let mut carry = 0;
+        for digit in &mut self.0 {
+            let product = *digit as u64 * factor as u64 + carry;
+            *digit = (product % Self::BASE) as u32;
+            carry = product / Self::BASE;
+        }
+        // The carry can be bigger than a single digit.
+        while carry > 0 {
+            self.0.push((carry % Self::BASE) as u32);
+            carry /= Self::BASE;
+        }
+    }
+
+    // Multiplies by "base" to the power of "times", a few factors at a time.
+    fn multiply_by_power(&mut self, base: u32, times: u32) {
+        // The largest power of "base" that fits in a "u32".
+        let (chunk, chunk_power) = std::iter::successors(Some((base, 1)), |(power, n)| {
+            power.checked_mul(base).map(|power| (power, n + 1))
+        })
+        .last()
+        .expect("the first power is always there");
+        for _ in 0..times / chunk_power {
+            self.multiply(chunk);
+        }
+        self.multiply(base.pow(times % chunk_power));
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Some((highest, rest)) = self.0.split_last() else {
+            return f.write_str("0");
+        };
+        write!(f, "{}", highest)?;
+        for digit in rest.iter().rev() {
+            write!(f, "{:09}", digit)?;
+        }
+        Ok(())
+    }
+}
+
+// The exact decimal value of a float, with every digit.
+// Every float is a whole number times a power of 2, and dividing by 2 always ends in decimal (1/2 = 0.5, 1/4 = 0.25, ...), so there always is an exact value.
+// So "0.1" is stored as 0.1000000000000000055511151231257827021181583404541015625 in an "f64".
+pub fn exact_decimal<F: Float>(value: F) -> String {
+    let parts = decompose(value);
+    let sign = if parts.negative { "-" } else { "" };
+    match parts.kind {
+        Kind::NaN => return "NaN".to_string(),
+        Kind::Infinite => return format!("{}inf", sign),
+        Kind::Zero => return format!("{}0", sign),
+        Kind::Normal | Kind::Subnormal => {}
+    }
+
+    // The value is "significand x 2^power2".
+    let power2 = parts.power() - F::MANTISSA_BITS as i32;
+    let mut digits = Decimal::new(parts.significand());
+    if power2 >= 0 {
+        digits.multiply_by_power(2, power2 as u32);
+        return format!("{}{}", sign, digits);
+    }
+
+    // Dividing by 2^n is the same as multiplying by 5^n and moving the decimal point n digits to the left.
+    let shift = power2.unsigned_abs() as usize;
+    digits.multiply_by_power(5, shift as u32);
+    let digits = format!("{:0>width$}", digits.to_string(), width = shift + 1);
+    let (whole, fraction) = digits.split_at(digits.len() - shift);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        format!("{}{}", sign, whole)
+    } else {
+        format!("{}{}.{}", sign, whole, fraction)
+    }
+}
+
+// Turns the bits into numbers that are in the same order as the floats, with -0.0 and 0.0 both at 0.
+fn ordered<F: Float>(value: F) -> i128 {
+    let bits = value.bits();
+    let magnitude = (bits & !sign_bit::<F>()) as i128;
+    if bits & sign_bit::<F>() != 0 { -magnitude } else { magnitude }
+}
+
+// How many floats of the type there are from "a" to "b", so two neighbours are 1 ULP ("unit in the last place") apart.
+// There is no distance to NaN.
+pub fn ulps_between<F: Float>(a: F, b: F) -> Option<u64> {
+    if decompose(a).kind == Kind::NaN || decompose(b).kind == Kind::NaN {
+        return None;
+    }
+    Some((ordered(a) - ordered(b)).unsigned_abs() as u64)
+}
+
+// The gap between "value" and the next float away from zero, which is how precise a float of that size can be.
+// It doubles with every power of 2: it is 2^-23 at 1.0 for an "f32", but 1.0 at 2^23.
+pub fn ulp<F: Float>(value: F) -> F {
+    let magnitude = F::with_bits(value.bits() & !sign_bit::<F>());
+    F::with_bits(magnitude.bits() + 1) - magnitude
+}
+
+// A decimal number, such as "20000.21445", read as an "f32" and as an "f64".
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Precision {
+    pub as_f32: f32,
+    pub as_f64: f64,
+    // How far the "f32" is from the "f64", which is the closer of the two.
+    pub absolute_error: f64,
+    pub relative_error: f64,
+    // The same distance, counted in the floats of an "f64".
+    pub ulps: u64,
+}
+
+pub fn compare(literal: &str) -> Result<Precision, ParseFloatError> {
+    let as_f32: f32 = literal.parse()?;
+    let as_f64: f64 = literal.parse()?;
+    let absolute_error = (as_f32 as f64 - as_f64).abs();
+    let relative_error = if as_f64 == 0.0 { 0.0 } else { absolute_error / as_f64.abs() };
+    Ok(Precision {
+        as_f32,
+        as_f64,
+        absolute_error,
+        relative_error,
+        ulps: ulps_between(as_f32 as f64, as_f64).unwrap_or(u64::MAX),
+    })
+}
+
+// Adds the values one after the other. Every addition rounds, and the rounding errors add up.
+pub fn naive_sum<F: Float>(values: impl IntoIterator<Item = F>) -> F {
+    values.into_iter().fold(F::ZERO, |sum, value| sum + value)
+}
+
+// Kahan summation: the part of each value that was lost when it was added to the sum is kept in "compensation", and added back with the next value.
+// The error of the sum no longer grows with the number of values.
+pub fn kahan_sum<F: Float>(values: impl IntoIterator<Item = F>) -> F {
+    let mut sum = F::ZERO;
+    let mut compensation = F::ZERO;
+    for value in values {
+        let corrected = value - compensation;
+        let next = sum + corrected;
+        // "(next - sum)" is what was really added, so this is what was lost (with the opposite sign).
+        compensation = (next - sum) - corrected;
+        sum = next;
+    }
+    sum
+}
+
+pub fn floats_testing_to(out: &mut dyn Write) -> io::Result<()> {
+    // "20.25" is 10100.01 in binary, so an "f32" holds it exactly.
+    let distance: f32 = 20.25;
+    writeln!(out, "{:?}: {}", distance, decompose(distance))?;
+    writeln!(out, "stored exactly as {}", exact_decimal(distance))?;
+
+    // "20000.21445" is not, and the "f32" is further away from it than the "f64".
+    let precision = compare("20000.21445").expect("the literal is a valid float");
+    writeln!(out, "20000.21445 as f32 is {}", exact_decimal(precision.as_f32))?;
+    writeln!(out, "20000.21445 as f64 is {}", exact_decimal(precision.as_f64))?;
+    writeln!(
+        out,
+        "the f32 is off by {:.3e} ({:.3e} relative, {} ULPs of an f64)",
+        precision.absolute_error, precision.relative_error, precision.ulps
+    )?;
+    writeln!(out, "an f32 near 20000 can only be {:?} apart, an f64 {:?}", ulp(20000.0f32), ulp(20000.0f64))?;
+
+    // "distance4" has no type, and becomes an "f32" because it is added to an "f32".
+    let distance3: f32 = 30.5;
+    let distance4 = 1.5;
+    writeln!(out, "{:?} + {:?} = {:?}, all f32 and exact", distance3, distance4, distance3 + distance4)?;
+
+    // 0.1 and 0.2 are both rounded, and so is their sum, which ends up one float away from the 0.3 that is stored for "0.3".
+    let sum = 0.1 + 0.2;
+    writeln!(out, "0.1 + 0.2 = {:?}, {} ULP away from 0.3", sum, ulps_between(sum, 0.3).unwrap_or(u64::MAX))?;
+
+    // Catastrophic cancellation: subtracting two numbers that are almost equal leaves only their rounding errors.
+    // "1 - cos(x)" for a small x is such a subtraction, "2 sin²(x/2)" is the same value without one.
+    let x = 1e-4f32;
+    let cancelled = (1.0 - x.cos()) / (x * x);
+    let stable = 2.0 * (x / 2.0).sin().powi(2) / (x * x);
+    writeln!(out, "(1 - cos x) / x² at x = 1e-4 in f32: {:?}, but it is {:?}", cancelled, stable)?;
+
+    // Adding 0.1 a million times, which should be 100000.
+    let tenths = std::iter::repeat_n(0.1f32, 1_000_000);
+    writeln!(out, "0.1 added 1000000 times in f32: {:?}", naive_sum(tenths.clone()))?;
+    writeln!(out, "with Kahan summation: {:?}", kahan_sum(tenths))?;
+
+    Ok(())
+}
+
+pub fn floats_testing() {
+    floats_testing_to(&mut io::stdout()).expect("failed to write to stdout");
+}
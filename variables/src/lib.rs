@@ -1,3 +1,13 @@
+// The exercises for this lesson (see "Exercises" in the README).
+#[cfg_attr(feature = "solution", path = "../solutions/exercises.rs")]
+pub mod exercises;
+
+// How the number literals below (such as "0xFF0000" or "22u8") are read by the compiler (see "literals.rs").
+pub mod literals;
+
+// How the floats below (such as "20.25" as an "f32") are stored, and how precise they are (see "floats.rs").
+pub mod floats;
+
 pub fn vars() {
     // immutable
     let _i: u32 = 6_000_000;
@@ -0,0 +1,363 @@
+// "vars()" writes numbers in many ways: "6_000_000", "0xFF0000", "0b1100_1001", "22u8" and "20000.21445".
+// This module reads those literals the way the compiler does, to show how each part of a literal is interpreted:
+//      "0x" "0o" "0b"          The base of an integer: hexadecimal, octal or binary. Without a prefix the number is decimal.
+//      "_"                     Underscores only make long numbers easier to read, and are ignored.
+//      "u8" "i64" "f32" ...    A suffix gives the literal its type.
+//      "." "e"                 A fraction or an exponent ("1.5e3" is 1500.0) makes the literal a float.
+// Without a suffix, the compiler picks the type from how the value is used, and falls back to "i32" for integers and "f64" for floats.
+// The parser does not know how a value is used, so it always picks those defaults.
+
+use std::fmt;
+use std::io::{self, Write};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Type {
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    Isize,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    Usize,
+    F32,
+    F64,
+}
+
+impl Type {
+    const ALL: [Type; 14] = [
+        Type::I8,
+        Type::I16,
+        Type::I32,
+        Type::I64,
+        Type::I128,
+        Type::Isize,
+        Type::U8,
+        Type::U16,
+        Type::U32,
+        Type::U64,
+        Type::U128,
+        Type::Usize,
+        Type::F32,
+        Type::F64,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Type::I8 => "i8",
+            Type::I16 => "i16",
+            Type::I32 => "i32",
+            Type::I64 => "i64",
+            Type::I128 => "i128",
+            Type::Isize => "isize",
+            Type::U8 => "u8",
+            Type::U16 => "u16",
+            Type::U32 => "u32",
+            Type::U64 => "u64",
+            Type::U128 => "u128",
+            Type::Usize => "usize",
+            Type::F32 => "f32",
+            Type::F64 => "f64",
+        }
+    }
+
+    pub fn from_suffix(suffix: &str) -> Option<Type> {
+        Type::ALL.into_iter().find(|ty| ty.name() == suffix)
+    }
+
+    pub fn is_float(self) -> bool {
+        matches!(self, Type::F32 | Type::F64)
+    }
+
+    pub fn is_signed(self) -> bool {
+        matches!(self, Type::I8 | Type::I16 | Type::I32 | Type::I64 | Type::I128 | Type::Isize)
+    }
+
+    // The largest value of an integer type, and the largest value that can follow a "-" (which is one more for signed types).
+    // "isize" and "usize" are as wide as a pointer on the machine that runs this code.
+    fn limits(self) -> (u128, u128) {
+        let max = match self {
+            Type::I8 => i8::MAX as u128,
+            Type::I16 => i16::MAX as u128,
+            Type::I32 => i32::MAX as u128,
+            Type::I64 => i64::MAX as u128,
+            Type::I128 => i128::MAX as u128,
+            Type::Isize => isize::MAX as u128,
+            Type::U8 => u8::MAX as u128,
+            Type::U16 => u16::MAX as u128,
+            Type::U32 => u32::MAX as u128,
+            Type::U64 => u64::MAX as u128,
+            Type::U128 => u128::MAX,
+            Type::Usize => usize::MAX as u128,
+            Type::F32 | Type::F64 => unreachable!("only integers have limits"),
+        };
+        let negative_max = if self.is_signed() { max + 1 } else { 0 };
+        (max, negative_max)
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Value {
+    // The value of a signed integer, such as "-5i8".
+    Int(i128),
+    // The value of an unsigned integer, which can be larger than any "i128" for "u128".
+    UInt(u128),
+    // An "f32" is kept as the "f64" with the same value, so it shows what was lost by rounding to "f32".
+    Float(f64),
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Int(value) => write!(f, "{}", value),
+            Value::UInt(value) => write!(f, "{}", value),
+            Value::Float(value) => write!(f, "{:?}", value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Literal {
+    pub value: Value,
+    pub ty: Type,
+    // True when there is no suffix, so the type is the default that the compiler falls back to ("i32" or "f64").
+    pub defaulted: bool,
+}
+
+// The messages are the same as the ones rustc gives for the same mistakes.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    Empty,
+    // A literal starts with a digit, "_1000" is a variable name.
+    NotANumber,
+    NoDigits,
+    InvalidDigit { digit: char, radix: u32 },
+    InvalidSuffix { suffix: String, float: bool },
+    FloatWithRadix(u32),
+    MissingExponent,
+    // The integer does not fit even in a "u128".
+    TooLarge,
+    OutOfRange(Type),
+    NegativeUnsigned(Type),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Empty => write!(f, "the literal is empty"),
+            Error::NotANumber => write!(f, "a number literal starts with a digit"),
+            Error::NoDigits => write!(f, "no valid digits found for number"),
+            Error::InvalidDigit { digit, radix } => write!(f, "invalid digit `{}` for a base {} literal", digit, radix),
+            Error::InvalidSuffix { suffix, float } => {
+                let kind = if *float { "float" } else { "number" };
+                write!(f, "invalid suffix `{}` for {} literal", suffix, kind)
+            }
+            Error::FloatWithRadix(radix) => {
+                let base = match radix {
+                    2 => "binary",
+                    8 => "octal",
+                    _ => "hexadecimal",
+                };
+                write!(f, "{} float literal is not supported", base)
+            }
+            Error::MissingExponent => write!(f, "expected at least one digit in exponent"),
+            Error::TooLarge => write!(f, "integer literal is too large"),
+            Error::OutOfRange(ty) => write!(f, "literal out of range for `{}`", ty),
+            Error::NegativeUnsigned(ty) => write!(f, "cannot apply unary operator `-` to type `{}`", ty),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+// Returns the part of "text" up to the first character that does not match, and the rest.
+fn take_while(text: &str, matches: impl Fn(char) -> bool) -> (&str, &str) {
+    let end = text.find(|c: char| !matches(c)).unwrap_or(text.len());
+    text.split_at(end)
+}
+
+// Parses a literal such as "0xFF_u8", "1_000", "2.5e-3f32" or "-128i8".
+// Strictly speaking, "-128i8" is the "-" operator applied to the literal "128i8", but the compiler checks the range of both together, and so does this.
+pub fn parse(literal: &str) -> Result<Literal, Error> {
+    let (negative, text) = match literal.strip_prefix('-') {
+        Some(text) => (true, text),
+        None => (false, literal),
+    };
+    match text.chars().next() {
+        None => return Err(Error::Empty),
+        Some(first) if !first.is_ascii_digit() => return Err(Error::NotANumber),
+        Some(_) => {}
+    }
+
+    let radix = match text.get(..2) {
+        Some("0x") => 16,
+        Some("0o") => 8,
+        Some("0b") => 2,
+        _ => 10,
+    };
+    if radix == 10 {
+        parse_decimal(negative, text)
+    } else {
+        parse_with_radix(negative, &text[2..], radix)
+    }
+}
+
+fn parse_with_radix(negative: bool, text: &str, radix: u32) -> Result<Literal, Error> {
+    // Binary and octal literals are read up to the last decimal digit, so that "0b102" is an invalid digit rather than a suffix.
+    let (digits, suffix) = if radix == 16 {
+        take_while(text, |c| c.is_ascii_hexdigit() || c == '_')
+    } else {
+        take_while(text, |c| c.is_ascii_digit() || c == '_')
+    };
+    if suffix.starts_with('.') && suffix[1..].starts_with(|c: char| c.is_ascii_digit()) {
+        return Err(Error::FloatWithRadix(radix));
+    }
+    // In hexadecimal, "e" and "f32" would be read as digits, so an exponent or a float suffix can only show up after binary and octal.
+    let exponent = suffix
+        .strip_prefix(['e', 'E'])
+        .map(|after_e| after_e.strip_prefix(['+', '-']).unwrap_or(after_e))
+        .is_some_and(|exponent| exponent.starts_with(|c: char| c.is_ascii_digit() || c == '_'));
+    if exponent || suffix == "f32" || suffix == "f64" {
+        return Err(Error::FloatWithRadix(radix));
+    }
+    integer(negative, digits, radix, suffix)
+}
+
+fn parse_decimal(negative: bool, text: &str) -> Result<Literal, Error> {
+    let is_digit = |c: char| c.is_ascii_digit() || c == '_';
+    let (whole, mut rest) = take_while(text, is_digit);
+    let mut is_float = false;
+
+    // "1." is a float, but in "1.max(2)" or "1..2" the "." is not part of the literal.
+    if let Some(after_dot) = rest.strip_prefix('.')
+        && !after_dot.starts_with(|c: char| c == '.' || c == '_' || c.is_alphabetic())
+    {
+        is_float = true;
+        rest = take_while(after_dot, is_digit).1;
+    }
+
+    if let Some(after_e) = rest.strip_prefix(['e', 'E']) {
+        is_float = true;
+        let after_sign = after_e.strip_prefix(['+', '-']).unwrap_or(after_e);
+        let (exponent, after_exponent) = take_while(after_sign, is_digit);
+        if !exponent.chars().any(|c| c.is_ascii_digit()) {
+            return Err(Error::MissingExponent);
+        }
+        rest = after_exponent;
+    }
+
+    let suffix = rest;
+    let number = &text[..text.len() - suffix.len()];
+    // A float suffix makes the literal a float, so "1f32" is "1.0".
+    let float_suffix = suffix == "f32" || suffix == "f64";
+    if !is_float && !float_suffix {
+        return integer(negative, whole, 10, suffix);
+    }
+
+    let (ty, defaulted) = match suffix {
+        "" => (Type::F64, true),
+        "f32" => (Type::F32, false),
+        "f64" => (Type::F64, false),
+        _ => {
+            return Err(Error::InvalidSuffix {
+                suffix: suffix.to_string(),
+                float: true,
+            });
+        }
+    };
+    let cleaned = format!("{}{}", if negative { "-" } else { "" }, number.replace('_', ""));
+    let value = if ty == Type::F32 {
+        cleaned.parse::<f32>().map(f64::from)
+    } else {
+        cleaned.parse::<f64>()
+    }
+    .expect("the parts of the literal were already checked");
+    if value.is_infinite() {
+        return Err(Error::OutOfRange(ty));
+    }
+    Ok(Literal {
+        value: Value::Float(value),
+        ty,
+        defaulted,
+    })
+}
+
+fn integer(negative: bool, digits: &str, radix: u32, suffix: &str) -> Result<Literal, Error> {
+    let (ty, defaulted) = match suffix {
+        "" => (Type::I32, true),
+        _ => match Type::from_suffix(suffix) {
+            Some(ty) if !ty.is_float() => (ty, false),
+            _ => {
+                return Err(Error::InvalidSuffix {
+                    suffix: suffix.to_string(),
+                    float: false,
+                });
+            }
+        },
+    };
+
+    let digits = digits.replace('_', "");
+    if digits.is_empty() {
+        return Err(Error::NoDigits);
+    }
+    if let Some(digit) = digits.chars().find(|digit| !digit.is_digit(radix)) {
+        return Err(Error::InvalidDigit { digit, radix });
+    }
+    let magnitude = u128::from_str_radix(&digits, radix).map_err(|_| Error::TooLarge)?;
+
+    if negative && !ty.is_signed() {
+        return Err(Error::NegativeUnsigned(ty));
+    }
+    let (max, negative_max) = ty.limits();
+    if magnitude > if negative { negative_max } else { max } {
+        return Err(Error::OutOfRange(ty));
+    }
+
+    let value = if !ty.is_signed() {
+        Value::UInt(magnitude)
+    } else if negative {
+        // "magnitude" can be one more than "i128::MAX" here, which is exactly what "0 - magnitude" needs to reach "i128::MIN".
+        Value::Int(0i128.wrapping_sub_unsigned(magnitude))
+    } else {
+        Value::Int(magnitude as i128)
+    };
+    Ok(Literal { value, ty, defaulted })
+}
+
+pub fn literals_testing_to(out: &mut dyn Write) -> io::Result<()> {
+    // These are the literals of "vars()", and a few more.
+    // Each one is shown with its value and the type that the compiler gives it.
+    // "(default)" means there is no suffix, so the type is the one the compiler falls back to when nothing else decides it.
+    let literals = [
+        "6_000_000", "0xFF0000", "0b1100_1001", "0o777", "22u8", "-128i8", "20000.21445", "1.5e3", "2.5f32", "0.1f32", "1f64",
+    ];
+    for literal in literals {
+        let parsed = parse(literal).expect("the literals of the lesson are valid");
+        let default = if parsed.defaulted { " (default)" } else { "" };
+        writeln!(out, "{} = {}: {}{}", literal, parsed.value, parsed.ty, default)?;
+    }
+
+    // The compiler rejects these literals, and so does the parser.
+    // A value that does not fit its type is an error, even without a suffix, since the default "i32" cannot hold it either.
+    let mistakes = ["256u8", "3_000_000_000", "-1u32", "0b102", "0x1.5", "1e", "1.5u8", "7km"];
+    for literal in mistakes {
+        let error = parse(literal).expect_err("the mistakes of the lesson are invalid");
+        writeln!(out, "{}: {}", literal, error)?;
+    }
+
+    Ok(())
+}
+
+pub fn literals_testing() {
+    literals_testing_to(&mut io::stdout()).expect("failed to write to stdout");
+}
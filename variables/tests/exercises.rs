@@ -0,0 +1,19 @@
+// The tests of the exercises in "src/exercises.rs" (see "Exercises" in the README).
+
+mod sum_up_to {
+    use variables::exercises::sum_up_to;
+
+    #[test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    fn sums_small_numbers() {
+        assert_eq!(sum_up_to(1), 1);
+        assert_eq!(sum_up_to(4), 10);
+        assert_eq!(sum_up_to(100), 5050);
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "solution"), ignore = "not solved yet")]
+    fn sum_up_to_zero_is_zero() {
+        assert_eq!(sum_up_to(0), 0);
+    }
+}
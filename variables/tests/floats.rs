@@ -0,0 +1,126 @@
+// The tests of "src/floats.rs".
+// Most of them are property tests: "proptest" runs each of them with hundreds of random floats, and shrinks any float that fails to the simplest one it can find.
+// The floats that failed before are kept in "floats.proptest-regressions", and always run first.
+// Some literals have more digits than the float can hold, which is what the tests are about.
+#![allow(clippy::excessive_precision)]
+
+use proptest::prelude::*;
+use variables::floats::{self, Kind};
+
+#[test]
+fn exact_values_are_written_out() {
+    let cases: &[(f64, &str)] = &[
+        (0.1, "0.1000000000000000055511151231257827021181583404541015625"),
+        (20.25, "20.25"),
+        (-2.5, "-2.5"),
+        (1e21, "1000000000000000000000"),
+        (2f64.powi(70), "1180591620717411303424"),
+        (-0.0, "-0"),
+        (f64::INFINITY, "inf"),
+        (f64::NAN, "NaN"),
+    ];
+    for (value, expected) in cases {
+        assert_eq!(floats::exact_decimal(*value), *expected, "{:?}", value);
+    }
+    assert_eq!(floats::exact_decimal(0.1f32), "0.100000001490116119384765625");
+    assert_eq!(floats::exact_decimal(20000.21445f32), "20000.21484375");
+
+    // The smallest subnormal "f64" is 2^-1074, which has 751 digits after the decimal point.
+    let smallest = floats::exact_decimal(f64::from_bits(1));
+    assert!(smallest.starts_with("0.000000000"));
+    assert!(smallest.ends_with("5625"));
+    assert_eq!(smallest.len(), 2 + 1074);
+}
+
+#[test]
+fn floats_are_taken_apart() {
+    let parts = floats::decompose(20.25f32);
+    assert_eq!((parts.negative, parts.exponent, parts.mantissa), (false, 131, 0b01000100000000000000000));
+    assert_eq!((parts.kind, parts.power(), parts.significand()), (Kind::Normal, 4, 0b101000100000000000000000));
+    assert_eq!(parts.to_string(), "0 10000011 01000100000000000000000 (+1.01000100000000000000000 x 2^4)");
+
+    assert_eq!(floats::decompose(-0.0f64).kind, Kind::Zero);
+    assert_eq!(floats::decompose(f32::from_bits(1)).kind, Kind::Subnormal);
+    assert_eq!(floats::decompose(f32::from_bits(1)).power(), -126);
+    assert_eq!(floats::decompose(f64::NEG_INFINITY).kind, Kind::Infinite);
+    assert_eq!(floats::decompose(f64::NAN).kind, Kind::NaN);
+}
+
+#[test]
+fn precision_of_f32_and_f64() {
+    let exact = floats::compare("20.25").unwrap();
+    assert_eq!((exact.absolute_error, exact.ulps), (0.0, 0));
+
+    let rounded = floats::compare("20000.21445").unwrap();
+    assert_eq!(rounded.as_f32, 20000.21484375);
+    assert!(rounded.relative_error < f32::EPSILON as f64);
+    assert!(floats::compare("twenty").is_err());
+
+    assert_eq!(floats::ulp(1.0f32), f32::EPSILON);
+    assert_eq!(floats::ulp(-1.0f64), f64::EPSILON);
+    assert_eq!(floats::ulps_between(0.1 + 0.2, 0.3), Some(1));
+    assert_eq!(floats::ulps_between(-0.0f32, 0.0), Some(0));
+    assert_eq!(floats::ulps_between(f32::from_bits(1), -f32::from_bits(1)), Some(2));
+    assert_eq!(floats::ulps_between(f64::NAN, 1.0), None);
+}
+
+#[test]
+fn kahan_summation_keeps_the_lost_digits() {
+    let tenths = vec![0.1f32; 1_000_000];
+    assert_eq!(floats::kahan_sum(tenths.iter().copied()), 100000.0);
+    assert!((floats::naive_sum(tenths.iter().copied()) - 100000.0).abs() > 900.0);
+
+    // A large value followed by many small ones: the small ones are each too small to change the sum on their own.
+    let values = std::iter::once(1e8f32).chain(std::iter::repeat_n(1.0, 10_000));
+    assert_eq!(floats::naive_sum(values.clone()), 1e8);
+    assert_eq!(floats::kahan_sum(values), 1e8 + 10_000.0);
+}
+
+proptest! {
+    #[test]
+    fn parts_compose_back_into_the_same_bits(bits: u64) {
+        let value = f64::from_bits(bits);
+        prop_assert_eq!(floats::compose::<f64>(&floats::decompose(value)).to_bits(), bits);
+        let value = f32::from_bits(bits as u32);
+        prop_assert_eq!(floats::compose::<f32>(&floats::decompose(value)).to_bits(), bits as u32);
+    }
+
+    // The exact value is the value that is stored, so reading it back gives the very same float.
+    #[test]
+    fn exact_decimals_read_back_into_the_same_float(bits: u64) {
+        let value = f64::from_bits(bits);
+        prop_assume!(value.is_finite());
+        let exact = floats::exact_decimal(value);
+        prop_assert_eq!(exact.parse::<f64>().unwrap().to_bits(), bits);
+
+        let value = f32::from_bits(bits as u32);
+        prop_assume!(value.is_finite());
+        prop_assert_eq!(floats::exact_decimal(value).parse::<f32>().unwrap().to_bits(), bits as u32);
+    }
+
+    #[test]
+    fn neighbours_are_one_ulp_apart(value in proptest::num::f64::NORMAL | proptest::num::f64::SUBNORMAL) {
+        prop_assert_eq!(floats::ulps_between(value, value), Some(0));
+        prop_assert_eq!(floats::ulps_between(value, value.next_up()), Some(1));
+        prop_assert_eq!(floats::ulps_between(value.next_down(), value.next_up()), Some(2));
+        prop_assert_eq!(floats::ulps_between(value, value.next_up()), floats::ulps_between(value.next_up(), value));
+        prop_assert_eq!(floats::ulp(value), value.abs().next_up() - value.abs());
+    }
+
+    // Rounding to the nearest "f32" is off by at most half of "f32::EPSILON", relative to the value.
+    #[test]
+    fn f32_is_within_half_an_epsilon(value in 1e-30f64..1e30) {
+        let precision = floats::compare(&value.to_string()).unwrap();
+        prop_assert!(precision.relative_error <= f32::EPSILON as f64 / 2.0, "{:?}", precision);
+    }
+
+    // The error of a Kahan sum is at most about 2 epsilon times the sum of the magnitudes, however many values there are.
+    // Each "f32" value is exact in an "f64", and the "f64" sum of a few hundred of them is precise enough to be the reference.
+    #[test]
+    fn kahan_sums_are_accurate(values in proptest::collection::vec(-1e4f32..1e4, 0..500)) {
+        let reference: f64 = values.iter().map(|&value| value as f64).sum();
+        let magnitude: f64 = values.iter().map(|&value| (value as f64).abs()).sum();
+        let error = (floats::kahan_sum(values.iter().copied()) as f64 - reference).abs();
+        prop_assert!(error <= 2.0 * f32::EPSILON as f64 * magnitude + 1e-30, "error {} for a magnitude of {}", error, magnitude);
+    }
+}
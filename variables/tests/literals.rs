@@ -0,0 +1,132 @@
+// The tests of the literal parser in "src/literals.rs".
+// Every row is a literal and what the parser should make of it, and every literal was checked against what rustc does with it.
+
+use variables::literals::{self, Error, Literal, Type, Value};
+
+fn int(value: i128, ty: Type, defaulted: bool) -> Literal {
+    Literal { value: Value::Int(value), ty, defaulted }
+}
+
+fn uint(value: u128, ty: Type) -> Literal {
+    Literal { value: Value::UInt(value), ty, defaulted: false }
+}
+
+fn float(value: f64, ty: Type, defaulted: bool) -> Literal {
+    Literal { value: Value::Float(value), ty, defaulted }
+}
+
+#[test]
+fn valid_literals() {
+    let cases = [
+        // Integers, with the default type.
+        ("0", int(0, Type::I32, true)),
+        ("5", int(5, Type::I32, true)),
+        ("6_000_000", int(6_000_000, Type::I32, true)),
+        ("1__0_", int(10, Type::I32, true)),
+        ("2147483647", int(i32::MAX as i128, Type::I32, true)),
+        ("-2147483648", int(i32::MIN as i128, Type::I32, true)),
+        // Prefixes.
+        ("0xFF0000", int(0xFF0000, Type::I32, true)),
+        ("0xff", int(255, Type::I32, true)),
+        ("0b1100_1001", int(0b1100_1001, Type::I32, true)),
+        ("0o777", int(0o777, Type::I32, true)),
+        ("0x_1", int(1, Type::I32, true)),
+        // "f32" is made of hexadecimal digits, so this is an "i32".
+        ("0x1f32", int(0x1f32, Type::I32, true)),
+        // Suffixes.
+        ("22u8", uint(22, Type::U8)),
+        ("255u8", uint(255, Type::U8)),
+        ("0xFF_u8", uint(255, Type::U8)),
+        ("0b1i64", int(1, Type::I64, false)),
+        ("-128i8", int(-128, Type::I8, false)),
+        ("127_i8", int(127, Type::I8, false)),
+        ("65535u16", uint(65535, Type::U16)),
+        ("4294967295u32", uint(u32::MAX as u128, Type::U32)),
+        ("5usize", uint(5, Type::Usize)),
+        ("-5isize", int(-5, Type::Isize, false)),
+        ("340282366920938463463374607431768211455u128", uint(u128::MAX, Type::U128)),
+        ("-170141183460469231731687303715884105728i128", int(i128::MIN, Type::I128, false)),
+        // Floats, with the default type.
+        ("20000.21445", float(20000.21445, Type::F64, true)),
+        ("1.", float(1.0, Type::F64, true)),
+        ("1.5e3", float(1500.0, Type::F64, true)),
+        ("1E-2", float(0.01, Type::F64, true)),
+        ("2e+2", float(200.0, Type::F64, true)),
+        ("1_000.000_1", float(1000.0001, Type::F64, true)),
+        ("1e_3", float(1000.0, Type::F64, true)),
+        ("-0.5", float(-0.5, Type::F64, true)),
+        // Float suffixes, which also turn an integer into a float.
+        ("2.5f32", float(2.5, Type::F32, false)),
+        ("1f64", float(1.0, Type::F64, false)),
+        ("1e3f32", float(1000.0, Type::F32, false)),
+        ("1_f32", float(1.0, Type::F32, false)),
+        // "0.1" cannot be stored exactly, and an "f32" rounds it more than an "f64".
+        ("0.1f32", float(0.1f32 as f64, Type::F32, false)),
+        ("3.4e38f32", float(3.4e38f32 as f64, Type::F32, false)),
+    ];
+    for (literal, expected) in cases {
+        assert_eq!(literals::parse(literal), Ok(expected), "{}", literal);
+    }
+}
+
+#[test]
+fn invalid_literals() {
+    let cases = [
+        ("", Error::Empty),
+        ("-", Error::Empty),
+        ("_1000", Error::NotANumber),
+        ("abc", Error::NotANumber),
+        (".5", Error::NotANumber),
+        ("0x", Error::NoDigits),
+        ("0b_", Error::NoDigits),
+        ("0xu8", Error::NoDigits),
+        ("0b102", Error::InvalidDigit { digit: '2', radix: 2 }),
+        ("0o8", Error::InvalidDigit { digit: '8', radix: 8 }),
+        ("7km", Error::InvalidSuffix { suffix: "km".to_string(), float: false }),
+        ("1u7", Error::InvalidSuffix { suffix: "u7".to_string(), float: false }),
+        ("0xFFg", Error::InvalidSuffix { suffix: "g".to_string(), float: false }),
+        // "1.f32" is the field "f32" of "1", not a literal.
+        ("1.f32", Error::InvalidSuffix { suffix: ".f32".to_string(), float: false }),
+        ("1.5u8", Error::InvalidSuffix { suffix: "u8".to_string(), float: true }),
+        ("1e3i32", Error::InvalidSuffix { suffix: "i32".to_string(), float: true }),
+        ("0x1.5", Error::FloatWithRadix(16)),
+        ("0b1.0", Error::FloatWithRadix(2)),
+        ("0o7f32", Error::FloatWithRadix(8)),
+        ("0b1e3", Error::FloatWithRadix(2)),
+        ("1e", Error::MissingExponent),
+        ("1e+", Error::MissingExponent),
+        ("1e_", Error::MissingExponent),
+        ("2.5Ef32", Error::MissingExponent),
+        ("340282366920938463463374607431768211456u128", Error::TooLarge),
+        ("0x1_0000_0000_0000_0000_0000_0000_0000_0000", Error::TooLarge),
+        ("256u8", Error::OutOfRange(Type::U8)),
+        ("128i8", Error::OutOfRange(Type::I8)),
+        ("-129i8", Error::OutOfRange(Type::I8)),
+        ("0x1_0000u16", Error::OutOfRange(Type::U16)),
+        ("3_000_000_000", Error::OutOfRange(Type::I32)),
+        ("-2147483649", Error::OutOfRange(Type::I32)),
+        ("1e39f32", Error::OutOfRange(Type::F32)),
+        ("1e309", Error::OutOfRange(Type::F64)),
+        ("-1u32", Error::NegativeUnsigned(Type::U32)),
+        ("-0usize", Error::NegativeUnsigned(Type::Usize)),
+    ];
+    for (literal, expected) in cases {
+        assert_eq!(literals::parse(literal), Err(expected), "{}", literal);
+    }
+}
+
+#[test]
+fn errors_read_like_the_compiler() {
+    let messages = [
+        ("0b102", "invalid digit `2` for a base 2 literal"),
+        ("7km", "invalid suffix `km` for number literal"),
+        ("1.5u8", "invalid suffix `u8` for float literal"),
+        ("0x1.5", "hexadecimal float literal is not supported"),
+        ("1e", "expected at least one digit in exponent"),
+        ("256u8", "literal out of range for `u8`"),
+        ("-1u32", "cannot apply unary operator `-` to type `u32`"),
+    ];
+    for (literal, message) in messages {
+        assert_eq!(literals::parse(literal).unwrap_err().to_string(), message, "{}", literal);
+    }
+}